@@ -0,0 +1,348 @@
+//! Bot command usage.
+//!
+//! Commands are read from the `bot_command` entities of incoming messages,
+//! so `/help` in the middle of a text counts as well as `/help@MyBot` in a
+//! group. Deep links arrive as `/start <payload>`; their payloads are counted
+//! separately, which shows which links people actually open.
+//!
+//! Once the bot's own username is known, `/command@OtherBot` is counted
+//! apart from the bot's commands, so groups with several bots don't show
+//! other bots' commands as unregistered ones.
+
+use std::collections::{HashMap, HashSet};
+
+use super::sorted_by_count;
+use super::timeline::ActivityTimeline;
+use crate::telegram::Message;
+use crate::telegram::content::{EntityKind, MessageEntity};
+
+/// One command found in a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandInvocation {
+    /// Command name without the slash, lowercased
+    pub command: String,
+    /// Bot the command was addressed to with `/command@bot`
+    pub bot_username: Option<String>,
+    /// Deep-link payload of a `/start <payload>` message
+    pub payload: Option<String>,
+}
+
+/// Returns the commands in a text, in order.
+///
+/// Entities that can't be located in the text are skipped.
+pub fn parse_commands(text: &str, entities: &[MessageEntity]) -> Vec<CommandInvocation> {
+    entities
+        .iter()
+        .filter(|entity| entity.kind == EntityKind::BotCommand)
+        .filter_map(|entity| {
+            let range = entity.byte_range(text).ok()?;
+            let command = text[range.clone()].strip_prefix('/')?;
+            let (command, bot_username) = match command.split_once('@') {
+                Some((command, bot)) => (command, Some(bot.to_string())),
+                None => (command, None),
+            };
+            let command = command.to_lowercase();
+            // Deep links open the chat with "/start <payload>" as the whole text
+            let payload = (command == "start" && entity.offset == 0)
+                .then(|| text[range.end..].trim())
+                .filter(|payload| !payload.is_empty())
+                .map(str::to_string);
+            Some(CommandInvocation {
+                command,
+                bot_username,
+                payload,
+            })
+        })
+        .collect()
+}
+
+/// Usage of one command.
+#[derive(Debug, Clone, Default)]
+pub struct CommandStats {
+    /// Number of times the command was sent
+    pub count: usize,
+    /// IDs of the users who sent it
+    pub users: HashSet<i64>,
+    /// Uses per chat ID
+    pub chats: HashMap<i64, usize>,
+    /// Uses per hour, by message date
+    pub timeline: ActivityTimeline,
+    /// Uses per bot username of `/command@bot`
+    pub addressed_to: HashMap<String, usize>,
+    /// Uses per `/start` deep-link payload
+    pub payloads: HashMap<String, usize>,
+    /// Date of the latest use
+    pub last_used: i64,
+}
+
+/// Usage of every command seen in incoming messages.
+#[derive(Debug, Clone, Default)]
+pub struct CommandUsage {
+    commands: HashMap<String, CommandStats>,
+    /// Latest display name of every chat a command was used in
+    chat_names: HashMap<i64, String>,
+    /// Username of the bot itself, from `getMe`
+    bot_username: Option<String>,
+    /// Uses of `/command@bot` addressed to other bots
+    other_bots: HashMap<String, usize>,
+}
+
+impl CommandUsage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the bot's own username; later `/command@bot` forms addressed to
+    /// another bot are counted apart.
+    pub fn set_bot_username(&mut self, username: Option<String>) {
+        self.bot_username = username;
+    }
+
+    pub fn bot_username(&self) -> Option<&str> {
+        self.bot_username.as_deref()
+    }
+
+    /// Counts the commands in a message's text or caption.
+    pub fn record(&mut self, message: &Message) {
+        let text = message
+            .text
+            .as_deref()
+            .or_else(|| message.caption())
+            .unwrap_or("");
+        let invocations = parse_commands(text, &message.entities());
+        if invocations.is_empty() {
+            return;
+        }
+
+        self.chat_names
+            .insert(message.chat.id, message.chat.display_name());
+        for invocation in invocations {
+            if let (Some(bot), Some(own)) = (&invocation.bot_username, &self.bot_username)
+                && !bot.eq_ignore_ascii_case(own)
+            {
+                *self
+                    .other_bots
+                    .entry(format!("/{}@{bot}", invocation.command))
+                    .or_insert(0) += 1;
+                continue;
+            }
+            let stats = self.commands.entry(invocation.command).or_default();
+            stats.count += 1;
+            if let Some(from) = &message.from {
+                stats.users.insert(from.id);
+            }
+            *stats.chats.entry(message.chat.id).or_insert(0) += 1;
+            stats.timeline.record(message.date);
+            if let Some(bot_username) = invocation.bot_username {
+                *stats.addressed_to.entry(bot_username).or_insert(0) += 1;
+            }
+            if let Some(payload) = invocation.payload {
+                *stats.payloads.entry(payload).or_insert(0) += 1;
+            }
+            stats.last_used = stats.last_used.max(message.date);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Returns every command with its usage, most used first.
+    pub fn commands(&self) -> Vec<(&str, &CommandStats)> {
+        let mut commands: Vec<(&str, &CommandStats)> = self
+            .commands
+            .iter()
+            .map(|(command, stats)| (command.as_str(), stats))
+            .collect();
+        commands.sort_by(|a, b| b.1.count.cmp(&a.1.count).then_with(|| a.0.cmp(b.0)));
+        commands
+    }
+
+    /// Returns the uses of commands addressed to other bots, as `/command@bot`,
+    /// most used first.
+    pub fn other_bots(&self) -> Vec<(String, usize)> {
+        sorted_by_count(
+            self.other_bots
+                .iter()
+                .map(|(command, count)| (command.clone(), *count)),
+        )
+    }
+
+    /// Returns the display name of a chat a command was used in, or its ID.
+    pub fn chat_name(&self, chat_id: i64) -> String {
+        self.chat_names
+            .get(&chat_id)
+            .cloned()
+            .unwrap_or_else(|| chat_id.to_string())
+    }
+
+    /// Summarizes every command, most used first, checked against the
+    /// `registered` commands from `getMyCommands` if they were loaded.
+    pub fn summaries(&self, registered: Option<&HashSet<String>>, now: i64) -> Vec<CommandSummary> {
+        self.commands()
+            .into_iter()
+            .map(|(command, stats)| CommandSummary {
+                command: command.to_string(),
+                count: stats.count,
+                unique_users: stats.users.len(),
+                chats: sorted_by_count(
+                    stats
+                        .chats
+                        .iter()
+                        .map(|(chat_id, count)| (self.chat_name(*chat_id), *count)),
+                ),
+                last_24h: stats.timeline.recent(24, now),
+                addressed_to: sorted_by_count(
+                    stats
+                        .addressed_to
+                        .iter()
+                        .map(|(bot, count)| (bot.clone(), *count)),
+                ),
+                payloads: sorted_by_count(
+                    stats
+                        .payloads
+                        .iter()
+                        .map(|(payload, count)| (payload.clone(), *count)),
+                ),
+                last_used: stats.last_used,
+                registered: registered.map(|registered| registered.contains(command)),
+            })
+            .collect()
+    }
+}
+
+/// Usage of one command, as shown on the Analytics screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandSummary {
+    pub command: String,
+    pub count: usize,
+    pub unique_users: usize,
+    /// Uses per chat name, most first
+    pub chats: Vec<(String, usize)>,
+    /// Uses in each of the last 24 hours, oldest first
+    pub last_24h: Vec<u64>,
+    /// Uses per bot username of `/command@bot`, most first
+    pub addressed_to: Vec<(String, usize)>,
+    /// Uses per `/start` deep-link payload, most first
+    pub payloads: Vec<(String, usize)>,
+    pub last_used: i64,
+    /// Whether the command is in a list from `getMyCommands`; `None` if the
+    /// lists weren't loaded
+    pub registered: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_test_message;
+    use serde_json::json;
+
+    fn command_message(
+        chat_id: i64,
+        user_id: i64,
+        text: &str,
+        entities: serde_json::Value,
+    ) -> Message {
+        let mut message = create_test_message(chat_id, 1, 1_700_000_000);
+        message.text = Some(text.to_string());
+        if let Some(from) = &mut message.from {
+            from.id = user_id;
+        }
+        message.other.insert("entities".to_string(), entities);
+        message
+    }
+
+    #[test]
+    fn test_parse_commands_with_bot_and_payload() {
+        let message = command_message(
+            1,
+            1,
+            "/start ref_42",
+            json!([{"type": "bot_command", "offset": 0, "length": 6}]),
+        );
+        assert_eq!(
+            parse_commands(message.text.as_deref().unwrap(), &message.entities()),
+            vec![CommandInvocation {
+                command: "start".to_string(),
+                bot_username: None,
+                payload: Some("ref_42".to_string()),
+            }]
+        );
+
+        // UTF-16 offsets: the emoji takes two units
+        let text = "👋 /Help@MyBot and /stats";
+        let message = command_message(
+            1,
+            1,
+            text,
+            json!([
+                {"type": "bot_command", "offset": 3, "length": 11},
+                {"type": "bot_command", "offset": 19, "length": 6},
+                {"type": "bold", "offset": 0, "length": 2}
+            ]),
+        );
+        let commands = parse_commands(text, &message.entities());
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].command, "help");
+        assert_eq!(commands[0].bot_username.as_deref(), Some("MyBot"));
+        assert_eq!(commands[0].payload, None);
+        assert_eq!(commands[1].command, "stats");
+    }
+
+    #[test]
+    fn test_usage_counts_users_and_chats() {
+        let entities = json!([{"type": "bot_command", "offset": 0, "length": 5}]);
+        let mut usage = CommandUsage::new();
+        usage.record(&command_message(100, 1, "/help", entities.clone()));
+        usage.record(&command_message(100, 1, "/help", entities.clone()));
+        usage.record(&command_message(200, 2, "/help", entities));
+        usage.record(&command_message(
+            100,
+            3,
+            "/start abc",
+            json!([{"type": "bot_command", "offset": 0, "length": 6}]),
+        ));
+        usage.record(&command_message(100, 3, "no commands", json!([])));
+
+        let commands = usage.commands();
+        assert_eq!(commands.len(), 2);
+        let (command, help) = commands[0];
+        assert_eq!(command, "help");
+        assert_eq!(help.count, 3);
+        assert_eq!(help.users.len(), 2);
+        assert_eq!(help.chats[&100], 2);
+        assert_eq!(help.timeline.total(), 3);
+        assert_eq!(commands[1].1.payloads["abc"], 1);
+
+        let registered = HashSet::from(["start".to_string(), "settings".to_string()]);
+        let summaries = usage.summaries(Some(&registered), 1_700_000_000);
+        assert_eq!(summaries[0].command, "help");
+        assert_eq!(summaries[0].registered, Some(false));
+        assert_eq!(summaries[0].unique_users, 2);
+        assert_eq!(summaries[0].chats[0].1, 2);
+        assert_eq!(summaries[0].last_24h.len(), 24);
+        assert_eq!(summaries[0].last_24h[23], 3);
+        assert_eq!(summaries[1].registered, Some(true));
+        assert_eq!(usage.summaries(None, 0)[0].registered, None);
+    }
+
+    #[test]
+    fn test_commands_for_other_bots_are_counted_apart() {
+        let mut usage = CommandUsage::new();
+        usage.set_bot_username(Some("MyBot".to_string()));
+        let entities = json!([{"type": "bot_command", "offset": 0, "length": 13}]);
+        usage.record(&command_message(
+            1,
+            1,
+            "/help@mybot",
+            json!([{"type": "bot_command", "offset": 0, "length": 11}]),
+        ));
+        usage.record(&command_message(1, 1, "/foo@OtherBot", entities.clone()));
+        usage.record(&command_message(1, 2, "/foo@OtherBot", entities));
+
+        let commands = usage.commands();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].0, "help");
+        assert_eq!(usage.other_bots(), vec![("/foo@OtherBot".to_string(), 2)]);
+    }
+}
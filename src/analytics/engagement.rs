@@ -0,0 +1,376 @@
+//! Per-user engagement and cohort retention.
+//!
+//! Every update with a sender counts towards that user: messages, callback
+//! queries, inline queries and everything else (reactions, poll answers,
+//! member changes, edits). New messages are placed by their date, edits by
+//! their `edit_date` and other updates by the time they were received, and
+//! each user keeps the set of UTC days they were active on. A cohort is the
+//! users first seen on one day; its retention on day N+k is the share of them
+//! active on that day. Bots are left out.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use chrono::{DateTime, NaiveDate};
+
+use crate::telegram::{Update, UpdateKind};
+
+const SECONDS_PER_DAY: i64 = 24 * 3600;
+
+/// Days after the first one a cohort is followed for (day 0 through day 7).
+pub const RETENTION_DAYS: usize = 7;
+
+/// Activity of one user since monitoring started.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserEngagement {
+    pub user_id: i64,
+    /// Latest display name
+    pub name: String,
+    pub username: Option<String>,
+    pub messages: usize,
+    pub callbacks: usize,
+    /// Inline queries and chosen inline results
+    pub inline_queries: usize,
+    /// Every other update the user sent
+    pub other: usize,
+    pub first_seen: i64,
+    pub last_seen: i64,
+    /// UTC days the user was active on, as days since the Unix epoch
+    pub active_days: BTreeSet<i64>,
+}
+
+impl UserEngagement {
+    /// Number of updates from this user, of any kind.
+    pub fn total(&self) -> usize {
+        self.messages + self.callbacks + self.inline_queries + self.other
+    }
+}
+
+/// Users first seen on one day and how many of them came back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cohort {
+    /// UTC day, as days since the Unix epoch
+    pub day: i64,
+    pub size: usize,
+    /// Users active on day N+k, for every k up to [`RETENTION_DAYS`] that
+    /// has already started
+    pub retained: Vec<usize>,
+}
+
+impl Cohort {
+    pub fn date(&self) -> String {
+        day_label(self.day)
+    }
+
+    /// Share of the cohort active on day N+`offset`, in percent.
+    pub fn retention(&self, offset: usize) -> Option<f64> {
+        let retained = *self.retained.get(offset)?;
+        (self.size > 0).then(|| retained as f64 * 100.0 / self.size as f64)
+    }
+}
+
+/// Engagement of every user seen in received updates.
+#[derive(Debug, Clone, Default)]
+pub struct EngagementStore {
+    users: HashMap<i64, UserEngagement>,
+}
+
+impl EngagementStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts an update received at `now` towards its sender.
+    pub fn record(&mut self, update: &Update, now: i64) {
+        let kind = update.kind();
+        let Some(sender) = kind.sender().filter(|sender| !sender.is_bot) else {
+            return;
+        };
+        let date = match &kind {
+            UpdateKind::Message(message) | UpdateKind::BusinessMessage(message) => message.date,
+            // `date` of an edit is when the original was sent
+            UpdateKind::EditedMessage(message) | UpdateKind::EditedBusinessMessage(message) => {
+                message
+                    .other
+                    .get("edit_date")
+                    .and_then(|date| date.as_i64())
+                    .unwrap_or(now)
+            }
+            _ => now,
+        };
+
+        let user = self
+            .users
+            .entry(sender.id)
+            .or_insert_with(|| UserEngagement {
+                user_id: sender.id,
+                name: String::new(),
+                username: None,
+                messages: 0,
+                callbacks: 0,
+                inline_queries: 0,
+                other: 0,
+                first_seen: date,
+                last_seen: date,
+                active_days: BTreeSet::new(),
+            });
+        user.name = sender.full_name();
+        user.username = sender.username.clone();
+        match kind {
+            UpdateKind::Message(_) | UpdateKind::BusinessMessage(_) => user.messages += 1,
+            UpdateKind::CallbackQuery(_) => user.callbacks += 1,
+            UpdateKind::InlineQuery(_) | UpdateKind::ChosenInlineResult(_) => {
+                user.inline_queries += 1
+            }
+            _ => user.other += 1,
+        }
+        user.first_seen = user.first_seen.min(date);
+        user.last_seen = user.last_seen.max(date);
+        user.active_days.insert(date.div_euclid(SECONDS_PER_DAY));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+    }
+
+    /// Returns every user, most updates first.
+    pub fn users(&self) -> Vec<UserEngagement> {
+        let mut users: Vec<UserEngagement> = self.users.values().cloned().collect();
+        users.sort_by(|a, b| {
+            b.total()
+                .cmp(&a.total())
+                .then_with(|| b.last_seen.cmp(&a.last_seen))
+                .then_with(|| a.user_id.cmp(&b.user_id))
+        });
+        users
+    }
+
+    /// Groups users by the UTC day they were first seen on, oldest first, and
+    /// counts how many were active on each following day up to `now`.
+    pub fn cohorts(&self, now: i64) -> Vec<Cohort> {
+        let today = now.div_euclid(SECONDS_PER_DAY);
+        let mut cohorts: BTreeMap<i64, Vec<&UserEngagement>> = BTreeMap::new();
+        for user in self.users.values() {
+            if let Some(&first_day) = user.active_days.first() {
+                cohorts.entry(first_day).or_default().push(user);
+            }
+        }
+
+        cohorts
+            .into_iter()
+            .map(|(day, users)| {
+                let offsets = (today - day).clamp(0, RETENTION_DAYS as i64);
+                Cohort {
+                    day,
+                    size: users.len(),
+                    retained: (0..=offsets)
+                        .map(|offset| {
+                            users
+                                .iter()
+                                .filter(|user| user.active_days.contains(&(day + offset)))
+                                .count()
+                        })
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the users as CSV with a header row, one user per line.
+pub fn users_csv(users: &[UserEngagement]) -> String {
+    let mut csv = String::from(
+        "user_id,name,username,messages,callbacks,inline_queries,other_updates,total_updates,first_seen,last_seen,active_days\n",
+    );
+    for user in users {
+        let fields = [
+            user.user_id.to_string(),
+            user.name.clone(),
+            user.username.clone().unwrap_or_default(),
+            user.messages.to_string(),
+            user.callbacks.to_string(),
+            user.inline_queries.to_string(),
+            user.other.to_string(),
+            user.total().to_string(),
+            timestamp_label(user.first_seen),
+            timestamp_label(user.last_seen),
+            user.active_days.len().to_string(),
+        ];
+        push_csv_row(&mut csv, &fields);
+    }
+    csv
+}
+
+/// Returns the cohort table as CSV: cohort date, size, and the retained
+/// users and percentage for day 0 through [`RETENTION_DAYS`]. Days that
+/// haven't started yet are left empty.
+pub fn cohorts_csv(cohorts: &[Cohort]) -> String {
+    let mut header = vec!["cohort".to_string(), "users".to_string()];
+    for offset in 0..=RETENTION_DAYS {
+        header.push(format!("day_{offset}_users"));
+        header.push(format!("day_{offset}_percent"));
+    }
+    let mut csv = String::new();
+    push_csv_row(&mut csv, &header);
+    for cohort in cohorts {
+        let mut fields = vec![cohort.date(), cohort.size.to_string()];
+        for offset in 0..=RETENTION_DAYS {
+            match (cohort.retained.get(offset), cohort.retention(offset)) {
+                (Some(retained), Some(percent)) => {
+                    fields.push(retained.to_string());
+                    fields.push(format!("{percent:.1}"));
+                }
+                _ => fields.extend([String::new(), String::new()]),
+            }
+        }
+        push_csv_row(&mut csv, &fields);
+    }
+    csv
+}
+
+/// Appends a CSV row, quoting fields that contain a comma, quote or line break.
+fn push_csv_row(csv: &mut String, fields: &[String]) {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect();
+    csv.push_str(&fields.join(","));
+    csv.push('\n');
+}
+
+fn day_label(day: i64) -> String {
+    DateTime::from_timestamp(day * SECONDS_PER_DAY, 0)
+        .map(|dt| dt.date_naive())
+        .unwrap_or(NaiveDate::MIN)
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+fn timestamp_label(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_message, create_test_update};
+    use serde_json::json;
+
+    const DAY: i64 = SECONDS_PER_DAY;
+    /// 2023-11-14 00:00:00 UTC
+    const START: i64 = 19675 * DAY;
+
+    fn message_update(user_id: i64, date: i64) -> Update {
+        let mut message = create_test_message(100, 1, date);
+        if let Some(from) = &mut message.from {
+            from.id = user_id;
+        }
+        create_test_update(message)
+    }
+
+    fn callback_update(user_id: i64) -> Update {
+        Update {
+            update_id: 2,
+            message: None,
+            channel_post: None,
+            edited_message: None,
+            other: HashMap::from([(
+                "callback_query".to_string(),
+                json!({
+                    "id": "1",
+                    "from": {"id": user_id, "is_bot": false, "first_name": "Ann, \"A\""},
+                    "chat_instance": "1",
+                    "data": "ok"
+                }),
+            )]),
+        }
+    }
+
+    #[test]
+    fn test_counts_per_user() {
+        let mut store = EngagementStore::new();
+        store.record(&message_update(1, START + 10), START + 20);
+        store.record(&message_update(1, START + DAY), START + DAY);
+        store.record(&callback_update(1), START + 2 * DAY);
+        store.record(&message_update(2, START + 30), START + 30);
+
+        let users = store.users();
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].user_id, 1);
+        assert_eq!(users[0].messages, 2);
+        assert_eq!(users[0].callbacks, 1);
+        assert_eq!(users[0].total(), 3);
+        assert_eq!(users[0].first_seen, START + 10);
+        assert_eq!(users[0].last_seen, START + 2 * DAY);
+        assert_eq!(users[0].active_days.len(), 3);
+        assert_eq!(users[0].name, "Ann, \"A\"");
+
+        // An edit days later counts on the day of the edit
+        let mut edited = create_test_message(100, 1, START);
+        edited
+            .other
+            .insert("edit_date".to_string(), json!(START + 5 * DAY));
+        let update = Update {
+            update_id: 3,
+            message: None,
+            channel_post: None,
+            edited_message: Some(edited),
+            other: HashMap::new(),
+        };
+        let mut store = EngagementStore::new();
+        store.record(&update, START + 5 * DAY + 10);
+        let user = &store.users()[0];
+        assert_eq!(user.other, 1);
+        assert_eq!(user.first_seen, START + 5 * DAY);
+        assert_eq!(user.active_days.first(), Some(&(START / DAY + 5)));
+    }
+
+    #[test]
+    fn test_cohorts_follow_first_day() {
+        let mut store = EngagementStore::new();
+        // Two users start on day 0, one of them comes back on day 2
+        store.record(&message_update(1, START), START);
+        store.record(&message_update(2, START + 60), START);
+        store.record(&message_update(1, START + 2 * DAY), START);
+        // One user starts on day 1
+        store.record(&message_update(3, START + DAY), START);
+
+        let cohorts = store.cohorts(START + 2 * DAY + 5);
+        assert_eq!(cohorts.len(), 2);
+        assert_eq!(cohorts[0].date(), "2023-11-14");
+        assert_eq!(cohorts[0].size, 2);
+        assert_eq!(cohorts[0].retained, vec![2, 0, 1]);
+        assert_eq!(cohorts[0].retention(2), Some(50.0));
+        assert_eq!(cohorts[0].retention(3), None);
+        assert_eq!(cohorts[1].retained, vec![1, 0]);
+
+        let cohorts = store.cohorts(START + 30 * DAY);
+        assert_eq!(cohorts[0].retained.len(), RETENTION_DAYS + 1);
+    }
+
+    #[test]
+    fn test_csv_quotes_fields() {
+        let mut store = EngagementStore::new();
+        store.record(&callback_update(1), START);
+        let csv = users_csv(&store.users());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("user_id,name,username,messages"));
+        assert_eq!(
+            lines[1],
+            "1,\"Ann, \"\"A\"\"\",,0,1,0,0,1,2023-11-14 00:00:00,2023-11-14 00:00:00,1"
+        );
+
+        let csv = cohorts_csv(&store.cohorts(START + DAY));
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].starts_with("cohort,users,day_0_users,day_0_percent,day_1_users"));
+        assert!(lines[1].starts_with("2023-11-14,1,1,100.0,0,0.0,,"));
+    }
+}
@@ -1,8 +1,31 @@
 //! Analytics and statistics computation.
 //!
 //! This module analyzes discovered chats and computes various statistics
-//! including message counts, distribution by chat type, and hourly activity patterns.
+//! including message counts, distribution by chat type, hourly activity patterns,
+//! update throughput over time, bot command usage and per-user engagement.
 
+pub mod command_usage;
+pub mod engagement;
 pub mod stats;
+pub mod throughput;
+pub mod timeline;
+pub mod update_types;
 
+pub use command_usage::{
+    CommandInvocation, CommandStats, CommandSummary, CommandUsage, parse_commands,
+};
+pub use engagement::{
+    Cohort, EngagementStore, RETENTION_DAYS, UserEngagement, cohorts_csv, users_csv,
+};
 pub use stats::Statistics;
+pub use throughput::{SeriesFilter, Throughput, ThroughputStore, ThroughputWindow};
+pub use timeline::{ActivityHeatmap, ActivityTimeline, ActivityTimezone};
+pub use update_types::{ChatBreakdown, UpdateTypeCounts, UpdateTypeStat};
+
+/// Collects counts, highest first.
+fn sorted_by_count<K: Ord>(counts: impl IntoIterator<Item = (K, usize)>) -> Vec<(K, usize)> {
+    let mut counts: Vec<(K, usize)> = counts.into_iter().collect();
+    // Ties by key keep the order stable between renders
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
@@ -1,6 +1,10 @@
-use chrono::{DateTime, Local, Timelike};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use super::command_usage::{CommandSummary, CommandUsage};
+use super::engagement::{Cohort, EngagementStore, UserEngagement};
+use super::throughput::{SeriesFilter, Throughput, ThroughputStore, ThroughputWindow};
+use super::timeline::{ActivityHeatmap, ActivityTimeline, ActivityTimezone};
+use super::update_types::{ChatBreakdown, UpdateTypeCounts, UpdateTypeStat};
 use crate::telegram::DiscoveredChat;
 
 /// Aggregated statistics computed from discovered chats.
@@ -8,7 +12,11 @@ use crate::telegram::DiscoveredChat;
 /// Provides insights into message activity including:
 /// - Total messages and chats
 /// - Top chats by message volume
-/// - Hourly activity patterns
+/// - Hourly and day-of-week activity patterns
+/// - Update throughput over a recent time window
+/// - Updates by type, with trends, overall and per chat
+/// - Bot command usage
+/// - Per-user engagement and cohort retention
 /// - Distribution by chat type (private, group, supergroup, channel)
 #[derive(Debug, Clone)]
 pub struct Statistics {
@@ -20,15 +28,44 @@ pub struct Statistics {
     pub total_topics: usize,
     /// Message count per chat, sorted by count (descending)
     pub messages_per_chat: Vec<(String, usize)>,
-    /// Message distribution by hour of day (0-23)
+    /// Message distribution by hour of day (0-23), hours without messages left out
     pub hourly_distribution: Vec<(u32, usize)>,
+    /// Messages by day of week and hour of day
+    pub activity: ActivityHeatmap,
+    /// Timezone of `hourly_distribution` and `activity`
+    pub timezone: ActivityTimezone,
+    /// Unix time of the first hour in the activity timeline
+    pub activity_since: Option<i64>,
     /// Count of chats by type
     pub chat_type_distribution: HashMap<String, usize>,
+    /// Updates received per minute or hour in the selected window
+    pub throughput: Throughput,
+    /// Number of updates received since monitoring started
+    pub total_updates: usize,
+    /// Updates per type, most frequent first
+    pub update_types: Vec<UpdateTypeStat>,
+    /// Update types of the chats with the most updates
+    pub chat_update_types: Vec<ChatBreakdown>,
+    /// Usage of every command sent to the bot, most used first
+    pub commands: Vec<CommandSummary>,
+    /// Registered commands nobody used; empty if the lists weren't loaded
+    pub unused_commands: Vec<String>,
+    /// Whether the command lists from `getMyCommands` were loaded
+    pub commands_loaded: bool,
+    /// Uses of `/command@bot` addressed to other bots, most first
+    pub other_bot_commands: Vec<(String, usize)>,
+    /// Activity of every user, most updates first
+    pub user_engagement: Vec<UserEngagement>,
+    /// Users by the day they were first seen, oldest first
+    pub cohorts: Vec<Cohort>,
 }
 
 impl Statistics {
     /// Computes statistics from a list of discovered chats.
     ///
+    /// Chats only know when they were last seen, so the activity distribution
+    /// stays empty; add it with [`Statistics::with_activity`].
+    ///
     /// # Arguments
     ///
     /// * `chats` - Slice of references to discovered chats
@@ -51,7 +88,6 @@ impl Statistics {
         let mut total_messages = 0;
         let mut total_topics = 0;
         let mut messages_per_chat = Vec::new();
-        let mut hourly_distribution: HashMap<u32, usize> = HashMap::new();
         let mut chat_type_distribution: HashMap<String, usize> = HashMap::new();
 
         for chat in chats {
@@ -65,31 +101,123 @@ impl Statistics {
             *chat_type_distribution
                 .entry(chat.chat.chat_type.clone())
                 .or_insert(0) += 1;
-
-            // Calculate hourly distribution based on last_seen timestamp
-            let datetime = DateTime::from_timestamp(chat.last_seen, 0)
-                .unwrap_or_else(|| DateTime::<Local>::default().into());
-            let hour = datetime.hour();
-            *hourly_distribution.entry(hour).or_insert(0) += chat.message_count;
         }
 
         // Sort messages_per_chat by count descending
-        messages_per_chat.sort_by(|a, b| b.1.cmp(&a.1));
-
-        // Convert hourly_distribution to sorted vec
-        let mut hourly_vec: Vec<(u32, usize)> = hourly_distribution.into_iter().collect();
-        hourly_vec.sort_by_key(|(hour, _)| *hour);
+        messages_per_chat.sort_by_key(|entry| std::cmp::Reverse(entry.1));
 
         Statistics {
             total_messages,
             total_chats: chats.len(),
             total_topics,
             messages_per_chat,
-            hourly_distribution: hourly_vec,
+            hourly_distribution: Vec::new(),
+            activity: ActivityHeatmap::default(),
+            timezone: ActivityTimezone::default(),
+            activity_since: None,
             chat_type_distribution,
+            throughput: Throughput::default(),
+            total_updates: 0,
+            update_types: Vec::new(),
+            chat_update_types: Vec::new(),
+            commands: Vec::new(),
+            unused_commands: Vec::new(),
+            commands_loaded: false,
+            other_bot_commands: Vec::new(),
+            user_engagement: Vec::new(),
+            cohorts: Vec::new(),
         }
     }
 
+    /// Adds the hour-of-day and day-of-week distribution of the messages in
+    /// `timeline`, in `timezone`.
+    pub fn with_activity(
+        mut self,
+        timeline: &ActivityTimeline,
+        timezone: ActivityTimezone,
+    ) -> Self {
+        self.activity = timeline.heatmap(timezone);
+        self.hourly_distribution = (0u32..)
+            .zip(self.activity.hourly())
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        self.timezone = timezone;
+        self.activity_since = timeline.first_hour();
+        self
+    }
+
+    /// Adds the throughput of the last `window` before `now`, with series for
+    /// the five busiest update types and chats.
+    pub fn with_throughput(
+        mut self,
+        store: &ThroughputStore,
+        window: ThroughputWindow,
+        now: i64,
+    ) -> Self {
+        self.throughput = store.snapshot(window, now, 5);
+        self
+    }
+
+    /// Adds the update counts per type and chat, with each type's trend: its
+    /// count in the last `window` compared to the window before.
+    pub fn with_update_types(
+        mut self,
+        counts: &UpdateTypeCounts,
+        store: &ThroughputStore,
+        window: ThroughputWindow,
+        now: i64,
+    ) -> Self {
+        self.total_updates = counts.total();
+        self.update_types = counts
+            .totals()
+            .into_iter()
+            .map(|(update_type, total)| {
+                let (current, previous) =
+                    store.totals(window, SeriesFilter::Kind(&update_type), now);
+                UpdateTypeStat {
+                    update_type,
+                    total,
+                    current,
+                    previous,
+                }
+            })
+            .collect();
+        self.chat_update_types = counts.chat_breakdown(10);
+        self
+    }
+
+    /// Adds command usage, checked against the `registered` commands from
+    /// `getMyCommands` if they were loaded.
+    pub fn with_commands(
+        mut self,
+        usage: &CommandUsage,
+        registered: Option<&HashSet<String>>,
+        now: i64,
+    ) -> Self {
+        self.commands = usage.summaries(registered, now);
+        self.commands_loaded = registered.is_some();
+        self.other_bot_commands = usage.other_bots();
+        self.unused_commands = registered
+            .map(|registered| {
+                let mut unused: Vec<String> = registered
+                    .iter()
+                    .filter(|command| !self.commands.iter().any(|used| &used.command == *command))
+                    .cloned()
+                    .collect();
+                unused.sort();
+                unused
+            })
+            .unwrap_or_default();
+        self
+    }
+
+    /// Adds the activity of every user and the cohort retention up to `now`.
+    pub fn with_engagement(mut self, engagement: &EngagementStore, now: i64) -> Self {
+        self.user_engagement = engagement.users();
+        self.cohorts = engagement.cohorts(now);
+        self
+    }
+
     /// Returns the top N chats by message count.
     ///
     /// # Arguments
@@ -109,7 +237,8 @@ impl Statistics {
 mod tests {
     use super::*;
     use crate::test_utils::{
-        create_test_discovered_chat, create_test_discovered_chat_with_topics, create_test_topic,
+        create_test_discovered_chat, create_test_discovered_chat_with_topics, create_test_message,
+        create_test_topic, create_test_update,
     };
 
     #[test]
@@ -169,23 +298,52 @@ mod tests {
 
     #[test]
     fn test_hourly_distribution() {
-        // Create chat with specific last_seen to test hour extraction
-        let mut chat1 = create_test_discovered_chat(100, "private", 10);
-        chat1.last_seen = 1609459200; // 2021-01-01 00:00:00 UTC (hour 0)
-
-        let mut chat2 = create_test_discovered_chat(200, "group", 20);
-        chat2.last_seen = 1609462800; // 2021-01-01 01:00:00 UTC (hour 1)
+        let chat = create_test_discovered_chat(100, "private", 3);
+        let chats = vec![&chat];
 
-        let chats = vec![&chat1, &chat2];
-        let stats = Statistics::from_chats(&chats);
+        let mut timeline = ActivityTimeline::new();
+        timeline.record(1609462800); // 2021-01-01 01:00:00 UTC (hour 1)
+        timeline.record(1609459200); // 2021-01-01 00:00:00 UTC (hour 0)
+        timeline.record(1609459300); // hour 0
+        let stats = Statistics::from_chats(&chats).with_activity(&timeline, "UTC".parse().unwrap());
 
-        // Should have entries for the hours when messages were seen
-        assert!(!stats.hourly_distribution.is_empty());
+        assert_eq!(stats.hourly_distribution, vec![(0, 2), (1, 1)]);
+        // 2021-01-01 was a Friday
+        assert_eq!(stats.activity.daily(), [0, 0, 0, 0, 3, 0, 0]);
+        assert_eq!(stats.activity_since, Some(1609459200));
+    }
 
-        // Distribution should be sorted by hour
-        for i in 1..stats.hourly_distribution.len() {
-            assert!(stats.hourly_distribution[i - 1].0 <= stats.hourly_distribution[i].0);
+    #[test]
+    fn test_update_types_with_trends() {
+        let update = create_test_update(create_test_message(100, 1, 1000));
+        let now = 1_700_000_000;
+        let mut counts = UpdateTypeCounts::new();
+        let mut store = ThroughputStore::new();
+        for received in [now - 20 * 60, now - 60, now] {
+            counts.record(&update);
+            store.record(&update, received);
         }
+
+        let chats: Vec<&DiscoveredChat> = vec![];
+        let stats = Statistics::from_chats(&chats).with_update_types(
+            &counts,
+            &store,
+            ThroughputWindow::FifteenMinutes,
+            now,
+        );
+
+        assert_eq!(stats.total_updates, 3);
+        assert_eq!(
+            stats.update_types,
+            vec![UpdateTypeStat {
+                update_type: "message".to_string(),
+                total: 3,
+                current: 2,
+                previous: 1,
+            }]
+        );
+        assert_eq!(stats.update_types[0].trend(), "+100%");
+        assert_eq!(stats.chat_update_types[0].total, 3);
     }
 
     #[test]
@@ -311,21 +469,18 @@ mod tests {
     }
 
     #[test]
-    fn test_hourly_distribution_aggregates_correctly() {
-        // Create multiple chats with messages at same hour
+    fn test_hourly_distribution_ignores_last_seen() {
+        // A chat's whole message count used to land in the hour it was last seen
         let mut chat1 = create_test_discovered_chat(100, "private", 10);
-        chat1.last_seen = 1609459200; // hour 0
-
-        let mut chat2 = create_test_discovered_chat(200, "group", 20);
-        chat2.last_seen = 1609459200; // same hour 0
+        chat1.last_seen = 1609459200;
 
-        let chats = vec![&chat1, &chat2];
+        let chats = vec![&chat1];
         let stats = Statistics::from_chats(&chats);
+        assert!(stats.hourly_distribution.is_empty());
 
-        // Should aggregate messages from both chats for the same hour
-        // We can't reliably test the exact aggregation without knowing the timezone,
-        // but we can verify the structure
-        assert!(!stats.hourly_distribution.is_empty());
+        let stats = stats.with_activity(&ActivityTimeline::new(), ActivityTimezone::Local);
+        assert!(stats.hourly_distribution.is_empty());
+        assert_eq!(stats.activity.max(), 0);
     }
 
     #[test]
@@ -376,4 +531,28 @@ mod tests {
         let total_from_list: usize = stats.messages_per_chat.iter().map(|(_, count)| count).sum();
         assert_eq!(total_from_list, stats.total_messages);
     }
+
+    #[test]
+    fn test_with_commands_flags_unregistered_and_unused() {
+        let mut message = create_test_message(100, 1, 1000);
+        message.text = Some("/debug".to_string());
+        message.other.insert(
+            "entities".to_string(),
+            serde_json::json!([{"type": "bot_command", "offset": 0, "length": 6}]),
+        );
+        let mut usage = CommandUsage::new();
+        usage.record(&message);
+
+        let stats = Statistics::from_chats(&[]).with_commands(&usage, None, 1000);
+        assert!(!stats.commands_loaded);
+        assert_eq!(stats.commands[0].registered, None);
+        assert!(stats.unused_commands.is_empty());
+
+        let registered = HashSet::from(["start".to_string(), "help".to_string()]);
+        let stats = Statistics::from_chats(&[]).with_commands(&usage, Some(&registered), 1000);
+        assert!(stats.commands_loaded);
+        assert_eq!(stats.commands[0].command, "debug");
+        assert_eq!(stats.commands[0].registered, Some(false));
+        assert_eq!(stats.unused_commands, vec!["help", "start"]);
+    }
 }
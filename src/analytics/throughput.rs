@@ -0,0 +1,391 @@
+//! Rolling update throughput.
+//!
+//! Updates are counted when they arrive, by update type and by chat, in
+//! per-minute buckets for the last two hours and per-hour buckets for the last
+//! two weeks: twice the longest window, so every window can be compared with
+//! the one before it. Older buckets are dropped as new updates come in, so
+//! the store stays small however long monitoring runs.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::sorted_by_count;
+use crate::telegram::Update;
+
+/// Number of per-minute buckets kept.
+const MINUTE_BUCKETS: i64 = 2 * 60;
+/// Number of per-hour buckets kept.
+const HOUR_BUCKETS: i64 = 2 * 24 * 7;
+
+/// Time span shown by the throughput charts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ThroughputWindow {
+    #[default]
+    FifteenMinutes,
+    Hour,
+    Day,
+    Week,
+}
+
+impl ThroughputWindow {
+    /// Returns the next window, wrapping around after the longest.
+    pub fn next(self) -> Self {
+        match self {
+            Self::FifteenMinutes => Self::Hour,
+            Self::Hour => Self::Day,
+            Self::Day => Self::Week,
+            Self::Week => Self::FifteenMinutes,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::FifteenMinutes => "15m",
+            Self::Hour => "1h",
+            Self::Day => "24h",
+            Self::Week => "7d",
+        }
+    }
+
+    /// Length of one bucket in seconds.
+    pub fn bucket_seconds(self) -> i64 {
+        match self {
+            Self::FifteenMinutes | Self::Hour => 60,
+            Self::Day | Self::Week => 3600,
+        }
+    }
+
+    /// Number of buckets in the window.
+    pub fn bucket_count(self) -> usize {
+        match self {
+            Self::FifteenMinutes => 15,
+            Self::Hour => 60,
+            Self::Day => 24,
+            Self::Week => 24 * 7,
+        }
+    }
+}
+
+/// Update counts of one bucket.
+#[derive(Debug, Clone, Default)]
+struct Bucket {
+    total: usize,
+    by_kind: HashMap<String, usize>,
+    by_chat: HashMap<i64, usize>,
+}
+
+impl Bucket {
+    fn count(&self, filter: SeriesFilter) -> usize {
+        match filter {
+            SeriesFilter::All => self.total,
+            SeriesFilter::Kind(kind) => self.by_kind.get(kind).copied().unwrap_or(0),
+            SeriesFilter::Chat(chat_id) => self.by_chat.get(&chat_id).copied().unwrap_or(0),
+        }
+    }
+}
+
+/// Which updates a series counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeriesFilter<'a> {
+    All,
+    /// Updates of one type, e.g. `callback_query`
+    Kind(&'a str),
+    /// Updates in one chat
+    Chat(i64),
+}
+
+/// Rolling per-minute and per-hour update counts.
+#[derive(Debug, Clone, Default)]
+pub struct ThroughputStore {
+    minutes: BTreeMap<i64, Bucket>,
+    hours: BTreeMap<i64, Bucket>,
+    /// Latest display name of every chat counted
+    chat_names: HashMap<i64, String>,
+}
+
+impl ThroughputStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts an update received at `now` (Unix time).
+    pub fn record(&mut self, update: &Update, now: i64) {
+        let update_type = update.get_update_type();
+        let chat = update.kind().chat().map(|chat| {
+            self.chat_names.insert(chat.id, chat.display_name());
+            chat.id
+        });
+
+        for (buckets, bucket_seconds, kept) in [
+            (&mut self.minutes, 60, MINUTE_BUCKETS),
+            (&mut self.hours, 3600, HOUR_BUCKETS),
+        ] {
+            let start = now.div_euclid(bucket_seconds) * bucket_seconds;
+            let bucket = buckets.entry(start).or_default();
+            bucket.total += 1;
+            *bucket.by_kind.entry(update_type.clone()).or_insert(0) += 1;
+            if let Some(chat_id) = chat {
+                *bucket.by_chat.entry(chat_id).or_insert(0) += 1;
+            }
+            let oldest = start - (kept - 1) * bucket_seconds;
+            buckets.retain(|&bucket_start, _| bucket_start >= oldest);
+        }
+    }
+
+    /// Returns the counts of every bucket of `window` ending at `now`, oldest
+    /// first, with empty buckets as zero.
+    pub fn series(&self, window: ThroughputWindow, filter: SeriesFilter, now: i64) -> Vec<u64> {
+        let bucket_seconds = window.bucket_seconds();
+        let buckets = self.buckets(window);
+        let last = now.div_euclid(bucket_seconds) * bucket_seconds;
+        let first = last - (window.bucket_count() as i64 - 1) * bucket_seconds;
+        (0..window.bucket_count() as i64)
+            .map(|i| {
+                buckets
+                    .get(&(first + i * bucket_seconds))
+                    .map_or(0, |bucket| bucket.count(filter) as u64)
+            })
+            .collect()
+    }
+
+    /// Returns the number of updates in `window` ending at `now`, and in the
+    /// window of the same length before it.
+    pub fn totals(
+        &self,
+        window: ThroughputWindow,
+        filter: SeriesFilter,
+        now: i64,
+    ) -> (usize, usize) {
+        let length = window.bucket_seconds() * window.bucket_count() as i64;
+        let count = |now| {
+            self.window_buckets(window, now)
+                .map(|bucket| bucket.count(filter))
+                .sum()
+        };
+        (count(now), count(now - length))
+    }
+
+    /// Update types seen in `window`, busiest first.
+    pub fn kinds(&self, window: ThroughputWindow, now: i64) -> Vec<(String, usize)> {
+        let mut totals: HashMap<&str, usize> = HashMap::new();
+        for bucket in self.window_buckets(window, now) {
+            for (kind, count) in &bucket.by_kind {
+                *totals.entry(kind).or_insert(0) += count;
+            }
+        }
+        sorted_by_count(
+            totals
+                .into_iter()
+                .map(|(kind, count)| (kind.to_string(), count)),
+        )
+    }
+
+    /// Chats seen in `window` by ID, busiest first.
+    pub fn chats(&self, window: ThroughputWindow, now: i64) -> Vec<(i64, usize)> {
+        let mut totals: HashMap<i64, usize> = HashMap::new();
+        for bucket in self.window_buckets(window, now) {
+            for (chat_id, count) in &bucket.by_chat {
+                *totals.entry(*chat_id).or_insert(0) += count;
+            }
+        }
+        sorted_by_count(totals)
+    }
+
+    /// Returns the display name of a counted chat, or its ID.
+    pub fn chat_name(&self, chat_id: i64) -> String {
+        self.chat_names
+            .get(&chat_id)
+            .cloned()
+            .unwrap_or_else(|| chat_id.to_string())
+    }
+
+    /// Takes the series shown on the Analytics screen: all updates, and the
+    /// `limit` busiest update types and chats.
+    pub fn snapshot(&self, window: ThroughputWindow, now: i64, limit: usize) -> Throughput {
+        Throughput {
+            window,
+            total: self.series(window, SeriesFilter::All, now),
+            by_kind: self
+                .kinds(window, now)
+                .into_iter()
+                .take(limit)
+                .map(|(kind, _)| {
+                    let series = self.series(window, SeriesFilter::Kind(&kind), now);
+                    (kind, series)
+                })
+                .collect(),
+            by_chat: self
+                .chats(window, now)
+                .into_iter()
+                .take(limit)
+                .map(|(chat_id, _)| {
+                    (
+                        self.chat_name(chat_id),
+                        self.series(window, SeriesFilter::Chat(chat_id), now),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn buckets(&self, window: ThroughputWindow) -> &BTreeMap<i64, Bucket> {
+        match window {
+            ThroughputWindow::FifteenMinutes | ThroughputWindow::Hour => &self.minutes,
+            ThroughputWindow::Day | ThroughputWindow::Week => &self.hours,
+        }
+    }
+
+    fn window_buckets(&self, window: ThroughputWindow, now: i64) -> impl Iterator<Item = &Bucket> {
+        let bucket_seconds = window.bucket_seconds();
+        let last = now.div_euclid(bucket_seconds) * bucket_seconds;
+        let first = last - (window.bucket_count() as i64 - 1) * bucket_seconds;
+        self.buckets(window)
+            .range(first..=last)
+            .map(|(_, bucket)| bucket)
+    }
+}
+
+/// Throughput series of one window, oldest bucket first.
+#[derive(Debug, Clone, Default)]
+pub struct Throughput {
+    pub window: ThroughputWindow,
+    /// All updates
+    pub total: Vec<u64>,
+    /// Busiest update types
+    pub by_kind: Vec<(String, Vec<u64>)>,
+    /// Busiest chats, by display name
+    pub by_chat: Vec<(String, Vec<u64>)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_message, create_test_update};
+    use serde_json::json;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn inline_query_update() -> Update {
+        Update {
+            update_id: 2,
+            message: None,
+            channel_post: None,
+            edited_message: None,
+            other: HashMap::from([(
+                "inline_query".to_string(),
+                json!({"id": "1", "from": {"id": 1, "is_bot": false, "first_name": "A"}, "query": "", "offset": ""}),
+            )]),
+        }
+    }
+
+    #[test]
+    fn test_series_counts_per_bucket_by_kind_and_chat() {
+        let mut store = ThroughputStore::new();
+        store.record(
+            &create_test_update(create_test_message(100, 1, NOW)),
+            NOW - 120,
+        );
+        store.record(&create_test_update(create_test_message(100, 1, NOW)), NOW);
+        store.record(&create_test_update(create_test_message(200, 1, NOW)), NOW);
+        store.record(&inline_query_update(), NOW);
+
+        let window = ThroughputWindow::FifteenMinutes;
+        let total = store.series(window, SeriesFilter::All, NOW);
+        assert_eq!(total.len(), 15);
+        assert_eq!(total[14], 3);
+        assert_eq!(total[12], 1);
+        assert_eq!(total.iter().sum::<u64>(), 4);
+
+        assert_eq!(
+            store.series(window, SeriesFilter::Kind("inline_query"), NOW)[14],
+            1
+        );
+        assert_eq!(store.series(window, SeriesFilter::Chat(100), NOW)[12], 1);
+        assert_eq!(
+            store.kinds(window, NOW),
+            vec![("message".to_string(), 3), ("inline_query".to_string(), 1)]
+        );
+        assert_eq!(store.chats(window, NOW), vec![(100, 2), (200, 1)]);
+    }
+
+    #[test]
+    fn test_totals_compare_with_previous_window() {
+        let mut store = ThroughputStore::new();
+        store.record(
+            &create_test_update(create_test_message(100, 1, NOW)),
+            NOW - 20 * 60,
+        );
+        store.record(
+            &create_test_update(create_test_message(100, 1, NOW)),
+            NOW - 16 * 60,
+        );
+        store.record(&create_test_update(create_test_message(100, 1, NOW)), NOW);
+
+        let window = ThroughputWindow::FifteenMinutes;
+        assert_eq!(store.totals(window, SeriesFilter::All, NOW), (1, 2));
+        assert_eq!(
+            store.totals(window, SeriesFilter::Kind("callback_query"), NOW),
+            (0, 0)
+        );
+        // The previous hour comes from the minute buckets too
+        store.record(
+            &create_test_update(create_test_message(100, 1, NOW)),
+            NOW - 90 * 60,
+        );
+        assert_eq!(
+            store.totals(ThroughputWindow::Hour, SeriesFilter::Chat(100), NOW),
+            (3, 1)
+        );
+    }
+
+    #[test]
+    fn test_windows_use_their_own_buckets() {
+        let mut store = ThroughputStore::new();
+        store.record(
+            &create_test_update(create_test_message(100, 1, NOW)),
+            NOW - 2 * 3600,
+        );
+        store.record(&create_test_update(create_test_message(100, 1, NOW)), NOW);
+
+        // Two hours ago is outside the per-minute buckets but in the per-hour ones
+        assert_eq!(
+            store
+                .series(ThroughputWindow::Hour, SeriesFilter::All, NOW)
+                .iter()
+                .sum::<u64>(),
+            1
+        );
+        let day = store.series(ThroughputWindow::Day, SeriesFilter::All, NOW);
+        assert_eq!(day.len(), 24);
+        assert_eq!(day.iter().sum::<u64>(), 2);
+        assert_eq!(day[21], 1);
+    }
+
+    #[test]
+    fn test_old_buckets_are_dropped() {
+        let mut store = ThroughputStore::new();
+        store.record(
+            &create_test_update(create_test_message(100, 1, NOW)),
+            NOW - 15 * 24 * 3600,
+        );
+        store.record(&create_test_update(create_test_message(100, 1, NOW)), NOW);
+
+        assert_eq!(store.minutes.len(), 1);
+        assert_eq!(store.hours.len(), 1);
+        let snapshot = store.snapshot(ThroughputWindow::Week, NOW, 5);
+        assert_eq!(snapshot.total.iter().sum::<u64>(), 1);
+        assert_eq!(snapshot.by_kind.len(), 1);
+        assert_eq!(snapshot.by_chat[0].1.iter().sum::<u64>(), 1);
+    }
+
+    #[test]
+    fn test_window_cycles() {
+        let mut window = ThroughputWindow::default();
+        let mut labels = Vec::new();
+        for _ in 0..4 {
+            labels.push(window.label());
+            window = window.next();
+        }
+        assert_eq!(labels, ["15m", "1h", "24h", "7d"]);
+        assert_eq!(window, ThroughputWindow::FifteenMinutes);
+    }
+}
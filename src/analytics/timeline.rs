@@ -0,0 +1,299 @@
+//! Per-message activity timeline.
+//!
+//! Every message is counted in the one-hour bucket of UTC time its date falls
+//! in. Keeping UTC buckets instead of hour-of-day counts lets the hour and
+//! day-of-week distribution be computed afterwards in any timezone, and the
+//! buckets are what gets persisted in `AnalyticsData.hourly_distribution`.
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Datelike, FixedOffset, Local, Offset, TimeZone, Timelike, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+/// Number of hourly buckets kept (90 days); the oldest are dropped first.
+pub const MAX_TIMELINE_HOURS: usize = 24 * 90;
+
+const SECONDS_PER_HOUR: i64 = 3600;
+
+/// Message counts per hour, keyed by the Unix time the UTC hour starts at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActivityTimeline {
+    buckets: BTreeMap<i64, usize>,
+}
+
+impl ActivityTimeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restores a timeline persisted with [`ActivityTimeline::to_distribution`].
+    ///
+    /// Keys that don't start an hour are moved to the hour they fall in.
+    pub fn from_distribution(distribution: &HashMap<i64, usize>) -> Self {
+        let mut timeline = Self::new();
+        for (&hour_start, &count) in distribution {
+            *timeline
+                .buckets
+                .entry(hour_start.div_euclid(SECONDS_PER_HOUR) * SECONDS_PER_HOUR)
+                .or_insert(0) += count;
+        }
+        timeline.truncate();
+        timeline
+    }
+
+    /// Returns the buckets in the form persisted in the cache.
+    pub fn to_distribution(&self) -> HashMap<i64, usize> {
+        self.buckets
+            .iter()
+            .map(|(&hour_start, &count)| (hour_start, count))
+            .collect()
+    }
+
+    /// Counts a message sent at `date` (Unix time).
+    pub fn record(&mut self, date: i64) {
+        let hour_start = date.div_euclid(SECONDS_PER_HOUR) * SECONDS_PER_HOUR;
+        *self.buckets.entry(hour_start).or_insert(0) += 1;
+        self.truncate();
+    }
+
+    /// Total number of messages in the timeline.
+    pub fn total(&self) -> usize {
+        self.buckets.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    /// Unix time of the first hour in the timeline.
+    pub fn first_hour(&self) -> Option<i64> {
+        self.buckets.keys().next().copied()
+    }
+
+    /// Returns the counts of the `hours` hours up to the one `now` falls in,
+    /// oldest first.
+    pub fn recent(&self, hours: usize, now: i64) -> Vec<u64> {
+        let last = now.div_euclid(SECONDS_PER_HOUR) * SECONDS_PER_HOUR;
+        let first = last - (hours as i64 - 1) * SECONDS_PER_HOUR;
+        (0..hours as i64)
+            .map(|i| {
+                self.buckets
+                    .get(&(first + i * SECONDS_PER_HOUR))
+                    .map_or(0, |count| *count as u64)
+            })
+            .collect()
+    }
+
+    /// Spreads the messages over day of week and hour of day in `timezone`.
+    ///
+    /// Buckets are placed by the local time their hour starts at, so with an
+    /// offset that isn't a whole number of hours (e.g. UTC+05:30) every
+    /// message of a bucket lands in the same local hour.
+    pub fn heatmap(&self, timezone: ActivityTimezone) -> ActivityHeatmap {
+        let mut heatmap = ActivityHeatmap::default();
+        for (&hour_start, &count) in &self.buckets {
+            if let Some((weekday, hour)) = timezone.weekday_and_hour(hour_start) {
+                heatmap.cells[weekday][hour] += count;
+            }
+        }
+        heatmap
+    }
+
+    fn truncate(&mut self) {
+        while self.buckets.len() > MAX_TIMELINE_HOURS {
+            self.buckets.pop_first();
+        }
+    }
+}
+
+/// Message counts by day of week (Monday first) and hour of day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActivityHeatmap {
+    pub cells: [[usize; 24]; 7],
+}
+
+impl ActivityHeatmap {
+    /// Messages per hour of day, summed over all days.
+    pub fn hourly(&self) -> [usize; 24] {
+        let mut hourly = [0; 24];
+        for day in &self.cells {
+            for (hour, count) in day.iter().enumerate() {
+                hourly[hour] += count;
+            }
+        }
+        hourly
+    }
+
+    /// Messages per day of week, Monday first.
+    pub fn daily(&self) -> [usize; 7] {
+        self.cells.map(|day| day.iter().sum())
+    }
+
+    /// Largest count of a single cell.
+    pub fn max(&self) -> usize {
+        self.cells.iter().flatten().copied().max().unwrap_or(0)
+    }
+}
+
+/// Timezone the activity distribution is shown in.
+///
+/// Configured with `analytics.timezone` in `config/cache.json`: `local` (the
+/// default), `UTC`, or a fixed offset like `+02:00`, `-0530` or `UTC+3`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ActivityTimezone {
+    /// Timezone of this machine, including its daylight saving changes
+    #[default]
+    Local,
+    /// Fixed offset from UTC
+    Fixed(FixedOffset),
+}
+
+impl ActivityTimezone {
+    /// Returns the day of week (0 = Monday) and hour of a Unix time in this timezone.
+    pub fn weekday_and_hour(&self, timestamp: i64) -> Option<(usize, usize)> {
+        match self {
+            Self::Local => Local.timestamp_opt(timestamp, 0).single().map(weekday_hour),
+            Self::Fixed(offset) => offset
+                .timestamp_opt(timestamp, 0)
+                .single()
+                .map(weekday_hour),
+        }
+    }
+}
+
+fn weekday_hour<Tz: TimeZone>(time: DateTime<Tz>) -> (usize, usize) {
+    (
+        time.weekday().num_days_from_monday() as usize,
+        time.hour() as usize,
+    )
+}
+
+impl FromStr for ActivityTimezone {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("local") {
+            return Ok(Self::Local);
+        }
+        let offset = value
+            .strip_prefix("UTC")
+            .or_else(|| value.strip_prefix("utc"))
+            .unwrap_or(value);
+        if offset.is_empty() || offset.eq_ignore_ascii_case("z") {
+            return Ok(Self::Fixed(Utc.fix()));
+        }
+
+        let (sign, digits) = if let Some(digits) = offset.strip_prefix('+') {
+            (1, digits)
+        } else if let Some(digits) = offset.strip_prefix('-') {
+            (-1, digits)
+        } else {
+            bail!("Timezone must be 'local', 'UTC' or an offset like +02:00, got '{value}'");
+        };
+        if !digits.chars().all(|c| c.is_ascii_digit() || c == ':') {
+            bail!("Invalid timezone offset '{value}'");
+        }
+        let (hours, minutes) = match digits.split_once(':') {
+            Some((hours, minutes)) => (hours, minutes),
+            None if digits.len() > 2 => digits.split_at(digits.len() - 2),
+            None => (digits, "0"),
+        };
+        let hours: i32 = hours
+            .parse()
+            .with_context(|| format!("Invalid hours in timezone '{value}'"))?;
+        let minutes: i32 = minutes
+            .parse()
+            .with_context(|| format!("Invalid minutes in timezone '{value}'"))?;
+        if hours > 14 || minutes >= 60 {
+            bail!("Timezone offset out of range: '{value}'");
+        }
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(Self::Fixed)
+            .with_context(|| format!("Timezone offset out of range: '{value}'"))
+    }
+}
+
+impl fmt::Display for ActivityTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local => write!(f, "local, UTC{}", Local::now().offset()),
+            Self::Fixed(offset) if offset.local_minus_utc() == 0 => write!(f, "UTC"),
+            Self::Fixed(offset) => write!(f, "UTC{offset}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2021-01-04 was a Monday
+    const MONDAY_MIDNIGHT_UTC: i64 = 1609718400;
+
+    #[test]
+    fn test_timeline_buckets_messages_by_utc_hour() {
+        let mut timeline = ActivityTimeline::new();
+        timeline.record(MONDAY_MIDNIGHT_UTC + 10);
+        timeline.record(MONDAY_MIDNIGHT_UTC + 3599);
+        timeline.record(MONDAY_MIDNIGHT_UTC + 3600);
+
+        let distribution = timeline.to_distribution();
+        assert_eq!(distribution.len(), 2);
+        assert_eq!(distribution[&MONDAY_MIDNIGHT_UTC], 2);
+        assert_eq!(distribution[&(MONDAY_MIDNIGHT_UTC + 3600)], 1);
+        assert_eq!(ActivityTimeline::from_distribution(&distribution), timeline);
+        assert_eq!(timeline.total(), 3);
+        assert_eq!(
+            timeline.recent(3, MONDAY_MIDNIGHT_UTC + 3600 + 5),
+            vec![0, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_timeline_is_bounded() {
+        let mut timeline = ActivityTimeline::new();
+        for hour in 0..MAX_TIMELINE_HOURS as i64 + 10 {
+            timeline.record(hour * 3600);
+        }
+        assert_eq!(timeline.to_distribution().len(), MAX_TIMELINE_HOURS);
+        assert_eq!(timeline.first_hour(), Some(10 * 3600));
+    }
+
+    #[test]
+    fn test_heatmap_uses_the_timezone() {
+        let mut timeline = ActivityTimeline::new();
+        // Monday 23:00 UTC is Tuesday 01:00 at UTC+2 and Monday 18:00 at UTC-5
+        timeline.record(MONDAY_MIDNIGHT_UTC + 23 * 3600);
+        timeline.record(MONDAY_MIDNIGHT_UTC + 23 * 3600 + 60);
+
+        let utc = timeline.heatmap("UTC".parse().unwrap());
+        assert_eq!(utc.cells[0][23], 2);
+
+        let east = timeline.heatmap("+02:00".parse().unwrap());
+        assert_eq!(east.cells[1][1], 2);
+        assert_eq!(east.daily(), [0, 2, 0, 0, 0, 0, 0]);
+
+        let west = timeline.heatmap("UTC-5".parse().unwrap());
+        assert_eq!(west.hourly()[18], 2);
+        assert_eq!(west.max(), 2);
+    }
+
+    #[test]
+    fn test_parse_timezone() {
+        let offset = |seconds| ActivityTimezone::Fixed(FixedOffset::east_opt(seconds).unwrap());
+        assert_eq!(
+            "local".parse::<ActivityTimezone>().unwrap(),
+            ActivityTimezone::Local
+        );
+        assert_eq!("UTC".parse::<ActivityTimezone>().unwrap(), offset(0));
+        assert_eq!("+05:30".parse::<ActivityTimezone>().unwrap(), offset(19800));
+        assert_eq!("-0330".parse::<ActivityTimezone>().unwrap(), offset(-12600));
+        assert_eq!("UTC+3".parse::<ActivityTimezone>().unwrap(), offset(10800));
+        assert_eq!(offset(19800).to_string(), "UTC+05:30");
+        assert!("Europe/Berlin".parse::<ActivityTimezone>().is_err());
+        assert!("+25:00".parse::<ActivityTimezone>().is_err());
+        assert!("+é1".parse::<ActivityTimezone>().is_err());
+    }
+}
@@ -0,0 +1,189 @@
+//! Update counts by type, overall and per chat.
+//!
+//! Counts cover every update received since monitoring started, named as in
+//! [`Update::get_update_type`]. Together with the throughput of the previous
+//! window they show whether a feature rollout produces the updates it should.
+
+use std::collections::HashMap;
+
+use super::sorted_by_count;
+use crate::telegram::Update;
+
+/// Number of update types listed per chat.
+const TYPES_PER_CHAT: usize = 4;
+
+/// Running update counts by type and by chat.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateTypeCounts {
+    totals: HashMap<String, usize>,
+    by_chat: HashMap<i64, HashMap<String, usize>>,
+    /// Latest display name of every chat counted
+    chat_names: HashMap<i64, String>,
+}
+
+impl UpdateTypeCounts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, update: &Update) {
+        let update_type = update.get_update_type();
+        if let Some(chat) = update.kind().chat() {
+            self.chat_names.insert(chat.id, chat.display_name());
+            *self
+                .by_chat
+                .entry(chat.id)
+                .or_default()
+                .entry(update_type.clone())
+                .or_insert(0) += 1;
+        }
+        *self.totals.entry(update_type).or_insert(0) += 1;
+    }
+
+    /// Total number of updates counted.
+    pub fn total(&self) -> usize {
+        self.totals.values().sum()
+    }
+
+    /// Counts per update type, most frequent first.
+    pub fn totals(&self) -> Vec<(String, usize)> {
+        sorted_by_count(self.totals.clone())
+    }
+
+    /// The `limit` chats with the most updates, each with its most frequent
+    /// update types.
+    pub fn chat_breakdown(&self, limit: usize) -> Vec<ChatBreakdown> {
+        let mut chats: Vec<ChatBreakdown> = self
+            .by_chat
+            .iter()
+            .map(|(chat_id, counts)| ChatBreakdown {
+                chat_name: self
+                    .chat_names
+                    .get(chat_id)
+                    .cloned()
+                    .unwrap_or_else(|| chat_id.to_string()),
+                total: counts.values().sum(),
+                by_type: sorted_by_count(counts.clone())
+                    .into_iter()
+                    .take(TYPES_PER_CHAT)
+                    .collect(),
+            })
+            .collect();
+        chats.sort_by(|a, b| {
+            b.total
+                .cmp(&a.total)
+                .then_with(|| a.chat_name.cmp(&b.chat_name))
+        });
+        chats.truncate(limit);
+        chats
+    }
+}
+
+/// Update types received in one chat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatBreakdown {
+    pub chat_name: String,
+    pub total: usize,
+    /// Most frequent update types, with counts
+    pub by_type: Vec<(String, usize)>,
+}
+
+/// Count and recent trend of one update type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateTypeStat {
+    pub update_type: String,
+    /// Updates since monitoring started
+    pub total: usize,
+    /// Updates in the selected throughput window
+    pub current: usize,
+    /// Updates in the window before it
+    pub previous: usize,
+}
+
+impl UpdateTypeStat {
+    /// Describes the change from the previous window to the current one,
+    /// e.g. `+50%`, `-20%`, `new` or `=`.
+    pub fn trend(&self) -> String {
+        match (self.previous, self.current) {
+            (0, 0) => "-".to_string(),
+            (0, _) => "new".to_string(),
+            (previous, current) if previous == current => "=".to_string(),
+            (previous, current) => {
+                let change = (current as f64 - previous as f64) / previous as f64 * 100.0;
+                format!("{change:+.0}%")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_message, create_test_update};
+    use serde_json::json;
+
+    fn reaction_update(chat_id: i64) -> Update {
+        Update {
+            update_id: 2,
+            message: None,
+            channel_post: None,
+            edited_message: None,
+            other: HashMap::from([(
+                "message_reaction".to_string(),
+                json!({
+                    "chat": {"id": chat_id, "type": "group", "title": "Group"},
+                    "message_id": 1,
+                    "date": 1000,
+                    "old_reaction": [],
+                    "new_reaction": [{"type": "emoji", "emoji": "👍"}]
+                }),
+            )]),
+        }
+    }
+
+    #[test]
+    fn test_counts_by_type_and_chat() {
+        let mut counts = UpdateTypeCounts::new();
+        counts.record(&create_test_update(create_test_message(100, 1, 1000)));
+        counts.record(&create_test_update(create_test_message(100, 1, 1000)));
+        counts.record(&reaction_update(100));
+        counts.record(&create_test_update(create_test_message(200, 1, 1000)));
+
+        assert_eq!(counts.total(), 4);
+        assert_eq!(
+            counts.totals(),
+            vec![
+                ("message".to_string(), 3),
+                ("message_reaction".to_string(), 1)
+            ]
+        );
+
+        let chats = counts.chat_breakdown(5);
+        assert_eq!(chats.len(), 2);
+        assert_eq!(chats[0].total, 3);
+        assert_eq!(chats[0].chat_name, "Group");
+        assert_eq!(
+            chats[0].by_type,
+            vec![
+                ("message".to_string(), 2),
+                ("message_reaction".to_string(), 1)
+            ]
+        );
+        assert_eq!(counts.chat_breakdown(1).len(), 1);
+    }
+
+    #[test]
+    fn test_trend() {
+        let stat = |current, previous| UpdateTypeStat {
+            update_type: "message".to_string(),
+            total: 10,
+            current,
+            previous,
+        };
+        assert_eq!(stat(0, 0).trend(), "-");
+        assert_eq!(stat(3, 0).trend(), "new");
+        assert_eq!(stat(4, 4).trend(), "=");
+        assert_eq!(stat(6, 4).trend(), "+50%");
+        assert_eq!(stat(0, 4).trend(), "-100%");
+    }
+}
@@ -9,19 +9,28 @@ pub mod monitoring;
 pub mod state;
 pub mod telegram_manager;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use chrono::Local;
 use serde::Serialize;
 use std::fs;
 use std::path::Path;
 
-use crate::analytics::Statistics;
+use crate::analytics::{ActivityTimeline, Statistics, cohorts_csv, users_csv};
 use crate::storage::CacheManager;
+use crate::telegram::commands::{CommandSet, format_commands_text, parse_commands_text};
+use crate::telegram::diagnostics::CheckLevel;
+use crate::telegram::{
+    BotCommandScope, InputMedia, InputMediaSource, KnownUser, MEDIA_GROUP_MAX_ITEMS,
+    MessageRevision, ThreadRow,
+};
 use monitoring::MonitoringService;
 use state::UiState;
 use telegram_manager::TelegramManager;
 
-pub use state::{InputFocus, Screen, TestMessageMode};
+pub use state::{
+    AnalyticsTab, ChatTab, CommandsInput, InputFocus, MessageAction, MessagePanel, ProfileInput,
+    RawJsonSource, Screen, TestMessageMode, TopicAction,
+};
 
 /// Main application facade.
 ///
@@ -39,14 +48,26 @@ impl App {
         let cache_manager = CacheManager::new();
         let token = cache_manager.load_token()?;
 
-        let (telegram, initial_screen) = if let Some(token) = token {
+        let (mut telegram, initial_screen) = if let Some(token) = token {
             (TelegramManager::new_with_token(token), Screen::Home)
         } else {
             (TelegramManager::new(), Screen::TokenInput)
         };
+        telegram.set_file_base_url(cache_manager.load_file_base_url()?);
+
+        let analytics = cache_manager.load_analytics()?;
+        telegram.activity = ActivityTimeline::from_distribution(&analytics.hourly_distribution);
+        // Resume after the last counted update: monitoring confirms it with the
+        // first getUpdates offset, so Telegram doesn't deliver it again
+        telegram.last_processed_update_id = analytics.last_update_id.unwrap_or(0);
 
         let mut ui = UiState::new();
         ui.current_screen = initial_screen;
+        if let Some(timezone) = &analytics.timezone {
+            ui.activity_timezone = timezone
+                .parse()
+                .context("Invalid analytics.timezone in config/cache.json")?;
+        }
 
         Ok(Self {
             ui,
@@ -74,11 +95,104 @@ impl App {
 
         // Precompute analytics when switching to analytics screen
         if screen == Screen::Analytics {
-            let chats = self.telegram.get_discovered_chats();
-            self.ui.statistics = Some(Statistics::from_chats(&chats));
+            self.refresh_statistics();
         }
     }
 
+    /// Recomputes the statistics shown on the Analytics screen.
+    pub fn refresh_statistics(&mut self) {
+        let now = chrono::Utc::now().timestamp();
+        let chats = self.telegram.get_discovered_chats();
+        self.ui.statistics = Some(
+            Statistics::from_chats(&chats)
+                .with_activity(&self.telegram.activity, self.ui.activity_timezone)
+                .with_throughput(&self.telegram.throughput, self.ui.throughput_window, now)
+                .with_update_types(
+                    &self.telegram.update_types,
+                    &self.telegram.throughput,
+                    self.ui.throughput_window,
+                    now,
+                )
+                .with_commands(
+                    &self.telegram.command_usage,
+                    self.ui.commands.registered_commands().as_ref(),
+                    now,
+                )
+                .with_engagement(&self.telegram.engagement, now),
+        );
+        self.ui.mark_dirty();
+    }
+
+    pub fn toggle_analytics_tab(&mut self) {
+        self.ui.toggle_analytics_tab();
+    }
+
+    pub fn next_analytics_command(&mut self) {
+        let count = self.telegram.command_usage.commands().len();
+        if count > 0 {
+            self.ui.selected_command_index = (self.ui.selected_command_index + 1) % count;
+            self.mark_dirty();
+        }
+    }
+
+    pub fn previous_analytics_command(&mut self) {
+        let count = self.telegram.command_usage.commands().len();
+        if count > 0 {
+            self.ui.selected_command_index = (self.ui.selected_command_index + count - 1) % count;
+            self.mark_dirty();
+        }
+    }
+
+    /// Loads the registered command lists so used commands can be checked against them.
+    pub async fn load_registered_commands(&mut self) -> Result<()> {
+        if let Err(e) = self.telegram.load_bot_username().await {
+            self.set_status(format!("✗ Error: {e:#}"));
+            return Ok(());
+        }
+        self.load_commands().await?;
+        self.refresh_statistics();
+        Ok(())
+    }
+
+    /// Exports per-user engagement and the cohort table as CSV files.
+    pub fn export_engagement(&mut self) -> Result<()> {
+        let users = self.telegram.engagement.users();
+        if users.is_empty() {
+            self.set_status("No user activity to export".to_string());
+            return Ok(());
+        }
+        let cohorts = self
+            .telegram
+            .engagement
+            .cohorts(chrono::Utc::now().timestamp());
+        let users_path = self.export_file(&users_csv(&users), "engagement_users", "csv")?;
+        let cohorts_path = self.export_file(&cohorts_csv(&cohorts), "engagement_cohorts", "csv")?;
+        self.set_status(format!(
+            "Exported {} user(s) to: {users_path} and {} cohort(s) to: {cohorts_path}",
+            users.len(),
+            cohorts.len()
+        ));
+        Ok(())
+    }
+
+    /// Switches the throughput charts to the next time window.
+    pub fn next_throughput_window(&mut self) {
+        self.ui.throughput_window = self.ui.throughput_window.next();
+        self.refresh_statistics();
+    }
+
+    /// Persists the activity timeline and the last counted update ID, so the
+    /// hourly distribution survives restarts without counting updates twice.
+    pub fn save_activity(&self) -> Result<()> {
+        if self.telegram.activity.is_empty() && self.telegram.last_processed_update_id == 0 {
+            return Ok(());
+        }
+        self.cache_manager.save_activity(
+            self.telegram.activity.to_distribution(),
+            self.telegram.last_processed_update_id,
+        )
+    }
+
     pub fn set_status(&mut self, message: String) {
         self.ui.set_status(message);
     }
@@ -96,28 +210,68 @@ impl App {
 
     pub fn next_message(&mut self) {
         if let Some(chat) = self.get_selected_chat() {
-            let message_count = self.telegram.get_messages_for_chat(chat.chat.id).len();
+            let message_count = self
+                .telegram
+                .get_message_groups_for_chat(chat.chat.id)
+                .len();
             self.ui.next_message(message_count);
         }
     }
 
     pub fn previous_message(&mut self) {
         if let Some(chat) = self.get_selected_chat() {
-            let message_count = self.telegram.get_messages_for_chat(chat.chat.id).len();
+            let message_count = self
+                .telegram
+                .get_message_groups_for_chat(chat.chat.id)
+                .len();
             self.ui.previous_message(message_count);
         }
     }
 
     pub fn next_update(&mut self) {
+        if self.ui.raw_json_source == RawJsonSource::ApiResponses {
+            let count = self.telegram.wire_captures().len();
+            if count > 0 {
+                self.ui.selected_response_index = (self.ui.selected_response_index + 1) % count;
+                self.mark_dirty();
+            }
+            return;
+        }
         let update_count = self.telegram.raw_updates.len();
         self.ui.next_update(update_count);
     }
 
     pub fn previous_update(&mut self) {
+        if self.ui.raw_json_source == RawJsonSource::ApiResponses {
+            let count = self.telegram.wire_captures().len();
+            if count > 0 {
+                self.ui.selected_response_index =
+                    (self.ui.selected_response_index + count - 1) % count;
+                self.mark_dirty();
+            }
+            return;
+        }
         let update_count = self.telegram.raw_updates.len();
         self.ui.previous_update(update_count);
     }
 
+    /// Switches the Raw JSON screen between the JSON as received and re-serialized.
+    pub fn toggle_raw_json_view(&mut self) {
+        self.ui.raw_json_normalized = !self.ui.raw_json_normalized;
+        self.mark_dirty();
+    }
+
+    /// Switches the Raw JSON screen between updates and API responses.
+    pub fn toggle_raw_json_source(&mut self) {
+        self.ui.raw_json_source = match self.ui.raw_json_source {
+            RawJsonSource::Updates => RawJsonSource::ApiResponses,
+            RawJsonSource::ApiResponses => RawJsonSource::Updates,
+        };
+        // Start at the newest response
+        self.ui.selected_response_index = self.telegram.wire_captures().len().saturating_sub(1);
+        self.mark_dirty();
+    }
+
     // Telegram delegates
     pub fn get_discovered_chats(&self) -> Vec<&crate::telegram::DiscoveredChat> {
         self.telegram.get_discovered_chats()
@@ -137,13 +291,41 @@ impl App {
         )
     }
 
+    /// Returns the edit history of the selected message or channel post.
+    pub fn selected_message_history(&self) -> Option<&[MessageRevision]> {
+        let update = self.get_selected_message_for_current_chat()?;
+        let (chat_id, message_id) = update.message_key()?;
+        self.telegram.get_message_history(chat_id, message_id)
+    }
+
+    /// Returns the reply thread of the selected message, including the bot's own replies.
+    pub fn selected_message_thread(&self) -> Vec<ThreadRow<'_>> {
+        self.get_selected_message_for_current_chat()
+            .and_then(|update| update.message_key())
+            .map(|(chat_id, message_id)| self.telegram.get_conversation_thread(chat_id, message_id))
+            .unwrap_or_default()
+    }
+
+    /// Shows a panel next to the message list, or the details if it is already shown.
+    pub fn toggle_message_panel(&mut self, panel: MessagePanel) {
+        self.ui.message_panel = if self.ui.message_panel == panel {
+            MessagePanel::Details
+        } else {
+            panel
+        };
+    }
+
     pub async fn validate_and_save_token(&mut self) -> Result<()> {
         let validation_result = self.telegram.validate_token(&self.ui.token_input).await?;
 
         match validation_result {
             telegram_manager::TokenValidationResult::Valid(client) => {
-                self.cache_manager
-                    .save_token(client.get_token().to_string())?;
+                let token = client.get_token().to_string();
+                if self.cache_manager.load_token()?.as_ref() != Some(&token) {
+                    // Update IDs belong to one bot
+                    self.telegram.last_processed_update_id = 0;
+                }
+                self.cache_manager.save_token(token)?;
                 self.ui.token_error = None;
                 self.switch_screen(Screen::Home);
                 self.set_status("Token validated successfully!".to_string());
@@ -165,14 +347,24 @@ impl App {
         Ok(())
     }
 
+    /// Sends the test message to the selected chat or the manual chat ID.
+    ///
+    /// If the target group was upgraded to a supergroup, both are linked and the
+    /// new chat becomes the target, so pressing Enter again sends there.
     pub async fn send_test_message(&mut self) -> Result<()> {
+        let target_chat_id = match self.ui.test_message_mode {
+            TestMessageMode::SelectedChat => self.get_selected_chat().map(|c| c.chat.id),
+            TestMessageMode::ManualChatId => self.ui.manual_chat_id_input.trim().parse().ok(),
+        };
+        let selected_chat = self.get_selected_chat().cloned();
         let result = self
             .telegram
             .send_test_message(
                 &self.ui.test_message_input,
                 &self.ui.manual_chat_id_input,
                 self.ui.test_message_mode,
-                self.get_selected_chat(),
+                selected_chat.as_ref(),
+                self.test_message_thread_id(),
             )
             .await?;
 
@@ -180,12 +372,700 @@ impl App {
         if result.success {
             self.ui.test_message_input.clear();
         }
+        if let (Some(old_chat_id), Some(new_chat_id)) = (target_chat_id, result.migrate_to_chat_id)
+        {
+            self.offer_migrated_chat(old_chat_id, new_chat_id);
+        }
         self.ui.mark_dirty();
 
         Ok(())
     }
 
+    /// Links an upgraded group to its supergroup and makes the new ID the test message target.
+    fn offer_migrated_chat(&mut self, old_chat_id: i64, new_chat_id: i64) {
+        self.telegram.link_migration(old_chat_id, new_chat_id);
+        match self.ui.test_message_mode {
+            TestMessageMode::SelectedChat => {
+                if let Some(index) = self
+                    .get_discovered_chats()
+                    .iter()
+                    .position(|c| c.chat.id == new_chat_id)
+                {
+                    self.ui.selected_chat_index = index;
+                    self.ui.test_message_topic_index = 0;
+                }
+            }
+            TestMessageMode::ManualChatId => {
+                self.ui.manual_chat_id_input = new_chat_id.to_string();
+                self.ui.test_message_input_focus = InputFocus::MessageText;
+            }
+        }
+    }
+
+    /// Returns the topic the test message is sent into, if one is selected.
+    ///
+    /// Topics are only offered for the selected chat, not for manual chat IDs.
+    pub fn test_message_thread_id(&self) -> Option<i64> {
+        if self.ui.test_message_mode != TestMessageMode::SelectedChat {
+            return None;
+        }
+        let index = self.ui.test_message_topic_index.checked_sub(1)?;
+        self.get_selected_chat()?
+            .topics
+            .get(index)
+            .map(|topic| topic.thread_id)
+    }
+
+    pub fn next_test_message_topic(&mut self) {
+        let count = self.get_selected_chat().map_or(0, |c| c.topics.len()) + 1;
+        self.ui.test_message_topic_index = (self.ui.test_message_topic_index + 1) % count;
+        self.mark_dirty();
+    }
+
+    pub fn previous_test_message_topic(&mut self) {
+        let count = self.get_selected_chat().map_or(0, |c| c.topics.len()) + 1;
+        self.ui.test_message_topic_index = (self.ui.test_message_topic_index + count - 1) % count;
+        self.mark_dirty();
+    }
+
+    /// Adds the item typed into the album composer.
+    ///
+    /// The input is `<file_id, URL or path>` optionally followed by ` | <caption>`.
+    pub fn add_media_group_item(&mut self) {
+        let input = self.ui.media_group_input.trim();
+        if input.is_empty() {
+            return;
+        }
+
+        if self.ui.media_group_items.len() >= MEDIA_GROUP_MAX_ITEMS {
+            self.ui.media_group_result = Some(format!(
+                "✗ Error: An album can hold at most {MEDIA_GROUP_MAX_ITEMS} items"
+            ));
+            self.ui.mark_dirty();
+            return;
+        }
+
+        let (source, caption) = match input.split_once(" | ") {
+            Some((source, caption)) => (source, Some(caption.trim().to_string())),
+            None => (input, None),
+        };
+
+        self.ui.media_group_items.push(InputMedia {
+            kind: self.ui.media_group_kind,
+            source: InputMediaSource::from_input(source),
+            caption: caption.filter(|c| !c.is_empty()),
+        });
+        self.ui.media_group_input.clear();
+        self.ui.media_group_result = None;
+        self.ui.mark_dirty();
+    }
+
+    /// Removes the most recently added album item.
+    pub fn remove_last_media_group_item(&mut self) {
+        self.ui.media_group_items.pop();
+        self.ui.mark_dirty();
+    }
+
+    pub async fn send_media_group(&mut self) -> Result<()> {
+        let selected_chat = self.get_selected_chat().cloned();
+        let result = self
+            .telegram
+            .send_media_group(
+                &self.ui.media_group_items,
+                &self.ui.manual_chat_id_input,
+                self.ui.test_message_mode,
+                selected_chat.as_ref(),
+                self.test_message_thread_id(),
+            )
+            .await?;
+
+        self.ui.media_group_result = Some(result.message);
+        if result.success {
+            self.ui.media_group_items.clear();
+        }
+        self.ui.mark_dirty();
+
+        Ok(())
+    }
+
+    /// Resolves every file of the selected message (or album) and saves it to `exports/files`.
+    pub async fn inspect_selected_message_files(&mut self) -> Result<()> {
+        let Some(chat_id) = self.get_selected_chat().map(|c| c.chat.id) else {
+            self.set_status("No chat selected".to_string());
+            return Ok(());
+        };
+        let Some(group) = self
+            .telegram
+            .get_message_groups_for_chat(chat_id)
+            .into_iter()
+            .nth(self.ui.selected_message_index)
+            .map(|group| group.into_iter().cloned().collect::<Vec<_>>())
+        else {
+            self.set_status("No message selected to inspect".to_string());
+            return Ok(());
+        };
+
+        let download_dir = Path::new("exports").join("files");
+        let inspections = self.telegram.inspect_files(&group, &download_dir).await?;
+
+        let saved = inspections
+            .iter()
+            .filter(|inspection| inspection.outcome.starts_with('✓'))
+            .count();
+        self.set_status(if inspections.is_empty() {
+            "Selected message contains no files".to_string()
+        } else {
+            format!(
+                "Downloaded {saved}/{} files to {}",
+                inspections.len(),
+                download_dir.display()
+            )
+        });
+        self.ui.file_inspection_update_id = Some(group[0].update_id);
+        self.ui.file_inspections = inspections;
+        self.ui.mark_dirty();
+
+        Ok(())
+    }
+
+    /// Runs the permission and privacy-mode diagnostics for all discovered chats.
+    pub async fn run_diagnostics(&mut self) -> Result<()> {
+        match self.telegram.run_diagnostics().await {
+            Ok(diagnostics) => {
+                let problems = diagnostics
+                    .chats
+                    .iter()
+                    .filter(|chat| chat.worst_level() != CheckLevel::Ok)
+                    .count();
+                self.set_status(format!(
+                    "Diagnostics complete: {} chats checked, {problems} with issues",
+                    diagnostics.chats.len()
+                ));
+                self.ui.diagnostics = Some(diagnostics);
+            }
+            Err(e) => self.set_status(format!("✗ Error: {e:#}")),
+        }
+        Ok(())
+    }
+
+    /// Cycles through the tabs of the chat view; forum supergroups also get Topics.
+    ///
+    /// Details are fetched the first time the tab is opened for a chat.
+    pub async fn toggle_chat_tab(&mut self) -> Result<()> {
+        let with_topics = self
+            .get_selected_chat()
+            .is_some_and(|c| c.chat.chat_type == "supergroup");
+        self.ui.toggle_chat_tab(with_topics);
+
+        let selected_chat_id = self.get_selected_chat().map(|c| c.chat.id);
+        let cached_chat_id = self.ui.chat_inspection.as_ref().map(|i| i.chat_id);
+        if self.ui.chat_tab == ChatTab::Details && selected_chat_id != cached_chat_id {
+            self.refresh_chat_inspection().await?;
+        }
+        Ok(())
+    }
+
+    /// Queries the Bot API for details of the selected chat.
+    pub async fn refresh_chat_inspection(&mut self) -> Result<()> {
+        let Some(chat_id) = self.get_selected_chat().map(|c| c.chat.id) else {
+            self.set_status("No chat selected".to_string());
+            return Ok(());
+        };
+
+        match self.telegram.inspect_chat(chat_id).await {
+            Ok(inspection) => {
+                self.ui.chat_inspection = Some(inspection);
+                self.set_status(format!("Chat {chat_id} details refreshed"));
+            }
+            Err(e) => self.set_status(format!("✗ Error: {e:#}")),
+        }
+        Ok(())
+    }
+
+    /// Opens the prompt for an action on the selected message.
+    ///
+    /// Edits start with the current text or inline keyboard of the message.
+    pub fn start_message_action(&mut self, action: MessageAction) {
+        let Some(update) = self.get_selected_message_for_current_chat() else {
+            self.set_status("No message selected".to_string());
+            return;
+        };
+        let initial_input = match action {
+            MessageAction::EditText => update.message_text().unwrap_or_default().to_string(),
+            MessageAction::EditReplyMarkup => update
+                .message_other()
+                .and_then(|other| other.get("reply_markup"))
+                .map(|markup| markup.to_string())
+                .unwrap_or_default(),
+            _ => String::new(),
+        };
+
+        self.ui.message_action_result = None;
+        self.ui.start_message_action(action, initial_input);
+    }
+
+    pub fn cancel_message_action(&mut self) {
+        self.ui.cancel_message_action();
+    }
+
+    /// Applies the pending action to the selected message (or every message of an album).
+    pub async fn confirm_message_action(&mut self) -> Result<()> {
+        let Some(action) = self.ui.message_action else {
+            return Ok(());
+        };
+        let Some(chat_id) = self.get_selected_chat().map(|c| c.chat.id) else {
+            self.ui.cancel_message_action();
+            self.set_status("No chat selected".to_string());
+            return Ok(());
+        };
+
+        let message_ids: Vec<i64> = self
+            .telegram
+            .get_message_groups_for_chat(chat_id)
+            .into_iter()
+            .nth(self.ui.selected_message_index)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|update| update.message_id())
+            .collect();
+
+        let result = self
+            .telegram
+            .apply_message_action(action, chat_id, &message_ids, &self.ui.message_action_input)
+            .await?;
+
+        self.ui.cancel_message_action();
+        self.ui.message_action_result = Some(result.message.clone());
+        self.set_status(result.message);
+
+        Ok(())
+    }
+
+    /// Returns the thread ID of the selected row of the Topics tab (`None` = General).
+    pub fn selected_topic_thread_id(&self) -> Option<i64> {
+        let index = self.ui.selected_topic_index.checked_sub(1)?;
+        self.get_selected_chat()?
+            .topics
+            .get(index)
+            .map(|topic| topic.thread_id)
+    }
+
+    pub fn next_topic(&mut self) {
+        let count = self.get_selected_chat().map_or(0, |c| c.topics.len()) + 1;
+        self.ui.selected_topic_index = (self.ui.selected_topic_index + 1) % count;
+        self.mark_dirty();
+    }
+
+    pub fn previous_topic(&mut self) {
+        let count = self.get_selected_chat().map_or(0, |c| c.topics.len()) + 1;
+        self.ui.selected_topic_index = (self.ui.selected_topic_index + count - 1) % count;
+        self.mark_dirty();
+    }
+
+    /// Opens the prompt for an action on the selected topic.
+    ///
+    /// Editing starts with the known name and icon of the topic.
+    pub fn start_topic_action(&mut self, action: TopicAction) {
+        let thread_id = self.selected_topic_thread_id();
+        let initial_input = match (action, thread_id) {
+            (TopicAction::Edit, Some(thread_id)) => self
+                .get_selected_chat()
+                .and_then(|c| c.topics.iter().find(|t| t.thread_id == thread_id))
+                .map(|topic| {
+                    let name = topic.name.clone().unwrap_or_default();
+                    match &topic.icon_custom_emoji_id {
+                        Some(icon) => format!("{name} | {icon}"),
+                        None => name,
+                    }
+                })
+                .unwrap_or_default(),
+            _ => String::new(),
+        };
+
+        self.ui.topic_action_result = None;
+        self.ui.start_topic_action(action, initial_input);
+    }
+
+    pub fn cancel_topic_action(&mut self) {
+        self.ui.cancel_topic_action();
+    }
+
+    /// Applies the pending action to the selected topic of the selected chat.
+    pub async fn confirm_topic_action(&mut self) -> Result<()> {
+        let Some(action) = self.ui.topic_action else {
+            return Ok(());
+        };
+        let Some(chat_id) = self.get_selected_chat().map(|c| c.chat.id) else {
+            self.ui.cancel_topic_action();
+            self.set_status("No chat selected".to_string());
+            return Ok(());
+        };
+        let thread_id = self.selected_topic_thread_id();
+
+        let result = self
+            .telegram
+            .apply_topic_action(action, chat_id, thread_id, &self.ui.topic_action_input)
+            .await?;
+
+        self.ui.cancel_topic_action();
+        if action == TopicAction::Delete && result.success {
+            self.ui.selected_topic_index = self.ui.selected_topic_index.saturating_sub(1);
+        }
+        self.ui.topic_action_result = Some(result.message.clone());
+        self.set_status(result.message);
+
+        Ok(())
+    }
+
+    /// Loads the custom emoji stickers that can be used as topic icons.
+    pub async fn load_topic_icon_stickers(&mut self) -> Result<()> {
+        match self.telegram.load_topic_icon_stickers().await {
+            Ok(stickers) => {
+                self.set_status(format!("✓ Loaded {} topic icons", stickers.len()));
+                self.ui.topic_icon_stickers = stickers;
+            }
+            Err(e) => self.set_status(format!("✗ Error: {e:#}")),
+        }
+        Ok(())
+    }
+
+    /// Fetches every command list of the matrix, adding the selected chat's scopes first.
+    pub async fn load_commands(&mut self) -> Result<()> {
+        if let Some(chat) = self.get_selected_chat().map(|c| c.chat.clone()) {
+            self.ui
+                .commands
+                .add_scope(BotCommandScope::Chat { chat_id: chat.id });
+            if chat.chat_type != "private" {
+                self.ui
+                    .commands
+                    .add_scope(BotCommandScope::ChatAdministrators { chat_id: chat.id });
+            }
+        }
+
+        let mut commands = std::mem::take(&mut self.ui.commands);
+        let result = self.telegram.load_commands(&mut commands).await;
+        self.ui.commands = commands;
+
+        let cells = self.ui.commands.scopes.len() * self.ui.commands.languages.len();
+        let failed = self.ui.commands.failed_cells();
+        let message = match result {
+            Ok(count) if failed > 0 => format!(
+                "✗ Error: {failed} of {cells} cells failed to load (see cell details), {count} with commands"
+            ),
+            Ok(count) => format!("✓ Loaded {cells} cells, {count} with commands"),
+            Err(e) => format!("✗ Error: {e:#}"),
+        };
+        self.ui.commands_result = Some(message.clone());
+        self.set_status(message);
+        Ok(())
+    }
+
+    /// Opens an input on the Commands screen; edits are prefilled with the current list.
+    pub fn start_commands_input(&mut self, mode: CommandsInput) {
+        let initial = match mode {
+            CommandsInput::Edit => {
+                let Some((scope, language)) = self.selected_commands_cell() else {
+                    return;
+                };
+                format_commands_text(
+                    self.ui
+                        .commands
+                        .cell(&scope, &language, true)
+                        .unwrap_or_default(),
+                )
+            }
+            CommandsInput::ConfirmApply if self.ui.commands.pending_changes().is_empty() => {
+                self.set_status("No pending command changes".to_string());
+                return;
+            }
+            _ => String::new(),
+        };
+        self.ui.start_commands_input(mode, initial);
+    }
+
+    pub fn cancel_commands_input(&mut self) {
+        self.ui.cancel_commands_input();
+    }
+
+    /// Completes the pending Commands screen input.
+    pub async fn confirm_commands_input(&mut self) -> Result<()> {
+        let Some(mode) = self.ui.commands_input_mode else {
+            return Ok(());
+        };
+        let input = self.ui.commands_input.trim().to_string();
+
+        let message = match mode {
+            CommandsInput::Edit => {
+                match (parse_commands_text(&input), self.selected_commands_cell()) {
+                    (Ok(commands), Some((scope, language))) => {
+                        self.ui.commands.set_draft(&scope, &language, commands);
+                        format!(
+                            "✓ Draft saved for {} [{}]",
+                            scope.label(),
+                            display_language(&language)
+                        )
+                    }
+                    (Err(e), _) => format!("✗ Error: {e:#}"),
+                    (_, None) => "✗ Error: No cell selected".to_string(),
+                }
+            }
+            CommandsInput::AddLanguage => match self.ui.commands.add_language(&input) {
+                Ok(()) => format!("✓ Added language {input} - press r to load it"),
+                Err(e) => format!("✗ Error: {e:#}"),
+            },
+            CommandsInput::Import => match fs::read_to_string(&input)
+                .map_err(anyhow::Error::from)
+                .and_then(|json| Ok(serde_json::from_str::<CommandSet>(&json)?))
+                .and_then(|set| self.ui.commands.import(&set))
+            {
+                Ok(()) => format!(
+                    "✓ Imported {input}: {} pending change(s)",
+                    self.ui.commands.pending_changes().len()
+                ),
+                Err(e) => format!("✗ Error: {e:#}"),
+            },
+            CommandsInput::ConfirmApply => {
+                let pending = self.ui.commands.pending_changes();
+                let result = self.telegram.apply_commands(&pending).await;
+                let mut message = result.message;
+                if result.success {
+                    // Reload so the matrix shows what Telegram actually stored
+                    let mut commands = std::mem::take(&mut self.ui.commands);
+                    let reload = self.telegram.load_commands(&mut commands).await;
+                    self.ui.commands = commands;
+                    let failed = self.ui.commands.failed_cells();
+                    if let Err(e) = reload {
+                        message = format!("{message} (reload failed: {e:#})");
+                    } else if failed > 0 {
+                        message = format!("{message} (reload failed for {failed} cell(s))");
+                    }
+                }
+                message
+            }
+        };
+
+        self.ui.cancel_commands_input();
+        self.ui.commands_result = Some(message.clone());
+        self.set_status(message);
+        Ok(())
+    }
+
+    pub fn discard_command_drafts(&mut self) {
+        self.ui.commands.clear_drafts();
+        self.set_status("Command drafts discarded".to_string());
+    }
+
+    /// Exports all loaded command lists as a JSON file that can be imported again.
+    pub fn export_commands(&mut self) -> Result<()> {
+        let set = self.ui.commands.to_command_set();
+        let filepath = self.export_to_json(&set, "commands")?;
+        self.set_status(format!(
+            "Exported {} command list(s) to: {filepath}",
+            set.entries.len()
+        ));
+        Ok(())
+    }
+
+    /// Returns the (scope, language) of the selected Commands matrix cell.
+    pub fn selected_commands_cell(&self) -> Option<(BotCommandScope, String)> {
+        let scope = self
+            .ui
+            .commands
+            .scopes
+            .get(self.ui.commands_selected_scope)?;
+        let language = self
+            .ui
+            .commands
+            .languages
+            .get(self.ui.commands_selected_language)?;
+        Some((scope.clone(), language.clone()))
+    }
+
+    /// Loads getMe and every Bot Profile value for all profile languages.
+    pub async fn load_profile(&mut self) -> Result<()> {
+        let mut profile = std::mem::take(&mut self.ui.bot_profile);
+        let result = self.telegram.load_profile(&mut profile).await;
+        self.ui.bot_profile = profile;
+
+        let rows = self.ui.bot_profile.rows().len();
+        let failed = self.ui.bot_profile.failed_rows();
+        let message = match result {
+            Ok(()) if failed > 0 => {
+                format!("✗ Error: {failed} of {rows} rows failed to load (see row details)")
+            }
+            Ok(()) => format!(
+                "✓ Bot profile loaded for {} language(s)",
+                self.ui.bot_profile.languages.len()
+            ),
+            Err(e) => format!("✗ Error: {e:#}"),
+        };
+        self.ui.profile_result = Some(message.clone());
+        self.set_status(message);
+        Ok(())
+    }
+
+    /// Opens an input on the Bot Profile screen; edits are prefilled with the current value.
+    pub fn start_profile_input(&mut self, mode: ProfileInput) {
+        let initial = match mode {
+            ProfileInput::Edit => {
+                let Some(row) = self.selected_profile_row() else {
+                    return;
+                };
+                self.ui.bot_profile.value(&row)
+            }
+            ProfileInput::AddLanguage => String::new(),
+        };
+        self.ui.start_profile_input(mode, initial);
+    }
+
+    pub fn cancel_profile_input(&mut self) {
+        self.ui.cancel_profile_input();
+    }
+
+    /// Completes the pending Bot Profile input and reloads the profile after a save.
+    pub async fn confirm_profile_input(&mut self) -> Result<()> {
+        let Some(mode) = self.ui.profile_input_mode else {
+            return Ok(());
+        };
+        let input = std::mem::take(&mut self.ui.profile_input);
+        self.ui.cancel_profile_input();
+
+        let message = match mode {
+            ProfileInput::Edit => {
+                let Some(row) = self.selected_profile_row() else {
+                    return Ok(());
+                };
+                let result = self.telegram.apply_profile_edit(&row, &input).await;
+                if result.success {
+                    let mut profile = std::mem::take(&mut self.ui.bot_profile);
+                    let reload = self.telegram.load_profile(&mut profile).await;
+                    self.ui.bot_profile = profile;
+                    let failed = self.ui.bot_profile.failed_rows();
+                    match reload {
+                        Ok(()) if failed > 0 => {
+                            format!("{} ({failed} row(s) failed to reload)", result.message)
+                        }
+                        Ok(()) => result.message,
+                        Err(e) => format!("{} (reload failed: {e:#})", result.message),
+                    }
+                } else {
+                    result.message
+                }
+            }
+            ProfileInput::AddLanguage => match self.ui.bot_profile.add_language(&input) {
+                Ok(()) => format!("✓ Added language {} - press r to load it", input.trim()),
+                Err(e) => format!("✗ Error: {e:#}"),
+            },
+        };
+
+        self.ui.profile_result = Some(message.clone());
+        self.set_status(message);
+        Ok(())
+    }
+
+    pub fn next_profile_row(&mut self) {
+        let count = self.ui.bot_profile.rows().len();
+        self.ui.profile_selected_row = (self.ui.profile_selected_row + 1) % count;
+        self.mark_dirty();
+    }
+
+    pub fn previous_profile_row(&mut self) {
+        let count = self.ui.bot_profile.rows().len();
+        self.ui.profile_selected_row = (self.ui.profile_selected_row + count - 1) % count;
+        self.mark_dirty();
+    }
+
+    /// Returns the selected row of the Bot Profile screen.
+    pub fn selected_profile_row(&self) -> Option<crate::telegram::profile::ProfileRow> {
+        self.ui
+            .bot_profile
+            .rows()
+            .into_iter()
+            .nth(self.ui.profile_selected_row)
+    }
+
     // Export methods
+    /// Returns the known users matching the search of the Users screen.
+    pub fn filtered_users(&self) -> Vec<&KnownUser> {
+        self.telegram
+            .get_known_users()
+            .into_iter()
+            .filter(|user| user.matches(&self.ui.users_search))
+            .collect()
+    }
+
+    pub fn get_selected_user(&self) -> Option<&KnownUser> {
+        self.filtered_users()
+            .get(self.ui.selected_user_index)
+            .copied()
+    }
+
+    pub fn next_user(&mut self) {
+        let count = self.filtered_users().len();
+        if count > 0 {
+            self.ui.selected_user_index = (self.ui.selected_user_index + 1) % count;
+            self.mark_dirty();
+        }
+    }
+
+    pub fn previous_user(&mut self) {
+        let count = self.filtered_users().len();
+        if count > 0 {
+            self.ui.selected_user_index = (self.ui.selected_user_index + count - 1) % count;
+            self.mark_dirty();
+        }
+    }
+
+    /// Applies a change of the search text, keeping the selection on the first match.
+    pub fn edit_users_search(&mut self, edit: impl FnOnce(&mut String)) {
+        edit(&mut self.ui.users_search);
+        self.ui.selected_user_index = 0;
+        self.mark_dirty();
+    }
+
+    /// Exports the users currently listed on the Users screen.
+    pub fn export_users(&mut self) -> Result<()> {
+        let users: Vec<KnownUser> = self.filtered_users().into_iter().cloned().collect();
+        if users.is_empty() {
+            self.set_status("No users to export".to_string());
+            return Ok(());
+        }
+        let filepath = self.export_to_json(&users, "users")?;
+        self.set_status(format!("Exported {} user(s) to: {filepath}", users.len()));
+        Ok(())
+    }
+
+    pub fn next_drift_entry(&mut self) {
+        let count = self.telegram.get_schema_drift().entries().len();
+        if count > 0 {
+            self.ui.selected_drift_index = (self.ui.selected_drift_index + 1) % count;
+            self.mark_dirty();
+        }
+    }
+
+    pub fn previous_drift_entry(&mut self) {
+        let count = self.telegram.get_schema_drift().entries().len();
+        if count > 0 {
+            self.ui.selected_drift_index = (self.ui.selected_drift_index + count - 1) % count;
+            self.mark_dirty();
+        }
+    }
+
+    /// Exports the unknown fields with their sample payloads.
+    pub fn export_schema_drift(&mut self) -> Result<()> {
+        let entries = self.telegram.get_schema_drift().entries().to_vec();
+        if entries.is_empty() {
+            self.set_status("No unknown fields to export".to_string());
+            return Ok(());
+        }
+        let filepath = self.export_to_json(&entries, "schema_drift")?;
+        self.set_status(format!(
+            "Exported {} unknown field(s) to: {filepath}",
+            entries.len()
+        ));
+        Ok(())
+    }
+
     pub fn export_selected_chat(&mut self) -> Result<()> {
         let chat_option = self.get_selected_chat().cloned();
         self.export_selected_generic(
@@ -196,6 +1076,30 @@ impl App {
     }
 
     pub fn export_selected_message(&mut self) -> Result<()> {
+        let album = self.get_selected_chat().and_then(|chat| {
+            self.telegram
+                .get_message_groups_for_chat(chat.chat.id)
+                .into_iter()
+                .nth(self.ui.selected_message_index)
+                .filter(|group| group.len() > 1)
+                .map(|group| {
+                    group
+                        .into_iter()
+                        .map(|arc| arc.as_ref().clone())
+                        .collect::<Vec<_>>()
+                })
+        });
+        if let Some(album) = album {
+            return self.export_selected_generic(
+                Some(album),
+                |album| {
+                    let group_id = album[0].media_group_id().unwrap_or("unknown");
+                    format!("album_{group_id}")
+                },
+                "No message selected to export",
+            );
+        }
+
         let message_option = self.telegram.get_selected_message_for_chat(
             self.get_selected_chat().map(|c| c.chat.id),
             self.ui.selected_message_index,
@@ -207,7 +1111,36 @@ impl App {
         )
     }
 
+    /// Exports the selected update, or API response, as received from Telegram.
+    ///
+    /// Updates whose JSON wasn't captured (e.g. received while the monitor was
+    /// paused) are exported re-serialized.
     pub fn export_selected_update(&mut self) -> Result<()> {
+        if self.ui.raw_json_source == RawJsonSource::ApiResponses {
+            let captures = self.telegram.wire_captures();
+            let Some(capture) = captures.get(self.ui.selected_response_index) else {
+                self.set_status("No API response selected to export".to_string());
+                return Ok(());
+            };
+            let filepath =
+                self.export_text(&capture.body, &format!("response_{}", capture.method))?;
+            self.set_status(format!("Exported verbatim JSON to: {filepath}"));
+            return Ok(());
+        }
+
+        let verbatim = self
+            .telegram
+            .get_selected_update(self.ui.selected_update_index)
+            .and_then(|update| {
+                let raw = self.telegram.verbatim_update(update.update_id)?;
+                Some((update.update_id, raw.to_string()))
+            });
+        if let Some((update_id, raw)) = verbatim {
+            let filepath = self.export_text(&raw, &format!("update_{update_id}"))?;
+            self.set_status(format!("Exported verbatim JSON to: {filepath}"));
+            return Ok(());
+        }
+
         let update_option = self
             .telegram
             .get_selected_update(self.ui.selected_update_index);
@@ -238,23 +1171,37 @@ impl App {
     }
 
     fn export_to_json<T: Serialize>(&mut self, data: &T, base_name: &str) -> Result<String> {
+        let json = serde_json::to_string_pretty(data)?;
+        self.export_text(&json, base_name)
+    }
+
+    /// Writes JSON text unchanged to `exports/<base_name>_<timestamp>.json`.
+    fn export_text(&mut self, json: &str, base_name: &str) -> Result<String> {
+        self.export_file(json, base_name, "json")
+    }
+
+    /// Writes `contents` to `exports/<base_name>_<timestamp>.<extension>`.
+    fn export_file(&mut self, contents: &str, base_name: &str, extension: &str) -> Result<String> {
         let export_dir = Path::new("exports");
         if !export_dir.exists() {
             fs::create_dir(export_dir)?;
         }
 
         let timestamp = Local::now().format("%Y%m%d_%H%M%S");
-        let filename = format!("{base_name}_{timestamp}.json");
+        let filename = format!("{base_name}_{timestamp}.{extension}");
         let filepath = export_dir.join(&filename);
 
-        let json = serde_json::to_string_pretty(data)?;
-        fs::write(&filepath, json)?;
+        fs::write(&filepath, contents)?;
 
         Ok(filepath.to_string_lossy().to_string())
     }
 
     // Monitoring delegates
     pub async fn toggle_monitoring(&mut self) {
+        if !self.monitoring.is_active() {
+            // Best effort: without it, commands to other bots count as this bot's
+            let _ = self.telegram.load_bot_username().await;
+        }
         if let Some(client) = &self.telegram.client {
             let last_update_id = self.telegram.last_processed_update_id;
             self.monitoring.toggle(client.clone(), last_update_id).await;
@@ -291,15 +1238,21 @@ impl App {
 
     pub fn process_received_updates(&mut self) {
         if let Some(updates_batch) = self.monitoring.receive_updates() {
-            for updates in updates_batch {
+            for response in updates_batch {
                 if self.monitoring.paused {
                     // Still process for update tracking, but don't add to monitor messages
-                    self.telegram.update_processor.process_updates(updates);
+                    self.telegram
+                        .update_processor
+                        .process_updates(response.result);
                 } else {
+                    self.telegram.record_verbatim_updates(&response);
                     self.telegram
-                        .process_updates_batch(updates, &mut self.monitoring.messages);
+                        .process_updates_batch(response.result, &mut self.monitoring.messages);
                 }
             }
+            if self.ui.current_screen == Screen::Analytics {
+                self.refresh_statistics();
+            }
             self.ui.mark_dirty();
         }
     }
@@ -402,3 +1355,12 @@ impl Default for App {
         Self::new().unwrap()
     }
 }
+
+/// Returns the label of a command language column ("all" for the empty code).
+pub fn display_language(language_code: &str) -> &str {
+    if language_code.is_empty() {
+        "all"
+    } else {
+        language_code
+    }
+}
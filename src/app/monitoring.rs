@@ -5,7 +5,7 @@
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
-use crate::telegram::{TelegramClient, Update};
+use crate::telegram::{GetUpdatesResponse, MessageEntity, TelegramClient};
 
 /// A monitored message displayed in the live monitor screen.
 #[derive(Debug, Clone)]
@@ -13,7 +13,10 @@ pub struct MonitorMessage {
     pub timestamp: i64,
     pub chat_name: String,
     pub sender: Option<String>,
+    /// Text, or a summary of media and service messages
     pub text: String,
+    /// Entities of `text`; empty for summaries
+    pub entities: Vec<MessageEntity>,
 }
 
 /// Commands sent to the monitoring background task.
@@ -41,7 +44,7 @@ pub struct MonitoringService {
     task_handle: Option<JoinHandle<()>>,
 
     /// Channel for receiving updates from the background task
-    update_receiver: Option<mpsc::Receiver<Vec<Update>>>,
+    update_receiver: Option<mpsc::Receiver<GetUpdatesResponse>>,
 
     /// Channel for sending control commands to the background task
     control_sender: Option<mpsc::Sender<MonitoringCommand>>,
@@ -73,7 +76,7 @@ impl MonitoringService {
             return; // Already running
         }
 
-        let (update_tx, update_rx) = mpsc::channel::<Vec<Update>>(100);
+        let (update_tx, update_rx) = mpsc::channel::<GetUpdatesResponse>(100);
         let (control_tx, mut control_rx) = mpsc::channel::<MonitoringCommand>(10);
 
         let mut current_update_id = last_update_id;
@@ -99,8 +102,8 @@ impl MonitoringService {
                                 }
                             }
 
-                            // Send updates to main app
-                            if update_tx.send(response.result).await.is_err() {
+                            // Send updates and their verbatim JSON to main app
+                            if update_tx.send(response).await.is_err() {
                                 break; // Channel closed, stop task
                             }
                         }
@@ -146,8 +149,8 @@ impl MonitoringService {
 
     /// Receives updates from the background task without blocking.
     ///
-    /// Returns `Some(Vec<Update>)` if updates are available, `None` if channel is empty.
-    pub fn receive_updates(&mut self) -> Option<Vec<Vec<Update>>> {
+    /// Returns `Some` with one `getUpdates` response per fetched batch, `None` if channel is empty.
+    pub fn receive_updates(&mut self) -> Option<Vec<GetUpdatesResponse>> {
         if let Some(receiver) = &mut self.update_receiver {
             let mut all_updates = Vec::new();
             while let Ok(updates) = receiver.try_recv() {
@@ -2,7 +2,12 @@
 //!
 //! Manages screen navigation and UI-specific state like selections and scroll positions.
 
-use crate::analytics::Statistics;
+use super::telegram_manager::{ChatInspection, FileInspection};
+use crate::analytics::{ActivityTimezone, Statistics, ThroughputWindow};
+use crate::telegram::commands::CommandMatrix;
+use crate::telegram::diagnostics::BotDiagnostics;
+use crate::telegram::profile::BotProfile;
+use crate::telegram::{InputMedia, InputMediaKind, Sticker};
 
 /// Represents the current screen/mode of the application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,6 +21,11 @@ pub enum Screen {
     Analytics,
     RawJson,
     WebhookManagement,
+    MediaGroup,
+    Commands,
+    BotProfile,
+    Users,
+    SchemaDrift,
     Help,
 }
 
@@ -37,6 +47,176 @@ pub enum InputFocus {
     MessageText,
 }
 
+/// Tabs of the chat view opened from Discovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatTab {
+    /// Messages received in the chat
+    Messages,
+    /// Chat information, administrators and bot rights from the Bot API
+    Details,
+    /// Forum topic management (supergroups only)
+    Topics,
+}
+
+/// Tabs of the Analytics screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnalyticsTab {
+    /// Chats, throughput and activity heatmap
+    Overview,
+    /// Updates by type and by chat, with trends
+    UpdateTypes,
+    /// Bot command usage, checked against the registered commands
+    Commands,
+    /// Per-user activity and cohort retention
+    Engagement,
+}
+
+/// What the right-hand panel of the Messages tab shows for the selected message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessagePanel {
+    /// Message fields, album items, files and actions
+    Details,
+    /// Original version and every edit, diffed
+    EditHistory,
+    /// Reply thread the message belongs to
+    Conversation,
+    /// Entities of the text or caption with their UTF-16 and UTF-8 ranges
+    Entities,
+}
+
+/// What the Raw JSON screen lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawJsonSource {
+    /// Received updates
+    Updates,
+    /// Responses of every API call, including `getUpdates`
+    ApiResponses,
+}
+
+/// Action on the selected topic of the Topics tab.
+///
+/// Actions on the General topic use the `*GeneralForumTopic` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopicAction {
+    /// Create a new topic (`createForumTopic`)
+    Create,
+    /// Rename the topic or change its icon (`editForumTopic`)
+    Edit,
+    /// Send a test message into the topic
+    Send,
+    /// Close the topic (`closeForumTopic`)
+    Close,
+    /// Reopen the topic (`reopenForumTopic`)
+    Reopen,
+    /// Delete the topic and all its messages after confirmation (`deleteForumTopic`)
+    Delete,
+    /// Hide the General topic (`hideGeneralForumTopic`)
+    Hide,
+    /// Show the hidden General topic (`unhideGeneralForumTopic`)
+    Unhide,
+}
+
+impl TopicAction {
+    /// Returns the prompt shown while the action is pending.
+    pub fn prompt(self) -> &'static str {
+        match self {
+            TopicAction::Create => {
+                "New topic name, optionally \"| icon custom_emoji_id\" (Enter/Esc)"
+            }
+            TopicAction::Edit => "New name, optionally \"| icon custom_emoji_id\" (Enter/Esc)",
+            TopicAction::Send => "Message text (Enter to send, Esc to cancel)",
+            TopicAction::Close => "Close this topic? (y to confirm, n/Esc to cancel)",
+            TopicAction::Reopen => "Reopen this topic? (y to confirm, n/Esc to cancel)",
+            TopicAction::Delete => {
+                "Delete this topic and all its messages? (y to confirm, n/Esc to cancel)"
+            }
+            TopicAction::Hide => "Hide the General topic? (y to confirm, n/Esc to cancel)",
+            TopicAction::Unhide => "Show the General topic? (y to confirm, n/Esc to cancel)",
+        }
+    }
+
+    /// Returns true for actions that take text input instead of a y/n confirmation.
+    pub fn needs_input(self) -> bool {
+        matches!(
+            self,
+            TopicAction::Create | TopicAction::Edit | TopicAction::Send
+        )
+    }
+}
+
+/// Action on the selected message of the Messages screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageAction {
+    /// Replace the message text (`editMessageText`)
+    EditText,
+    /// Replace or remove the inline keyboard (`editMessageReplyMarkup`)
+    EditReplyMarkup,
+    /// Forward the message to another chat (`forwardMessage`)
+    Forward,
+    /// Copy the message to another chat (`copyMessage`)
+    Copy,
+    /// Delete the message or album after confirmation (`deleteMessage(s)`)
+    Delete,
+}
+
+impl MessageAction {
+    /// Returns the prompt shown while the action is pending.
+    pub fn prompt(self) -> &'static str {
+        match self {
+            MessageAction::EditText => "New text (Enter to apply, Esc to cancel)",
+            MessageAction::EditReplyMarkup => {
+                "Inline keyboard JSON, empty to remove (Enter to apply, Esc to cancel)"
+            }
+            MessageAction::Forward => "Forward to chat ID, empty for this chat (Enter/Esc)",
+            MessageAction::Copy => "Copy to chat ID, empty for this chat (Enter/Esc)",
+            MessageAction::Delete => "Delete permanently? (y to confirm, n/Esc to cancel)",
+        }
+    }
+
+    /// Returns true for actions that cannot be undone and need a confirmation.
+    pub fn is_destructive(self) -> bool {
+        matches!(self, MessageAction::Delete)
+    }
+}
+
+/// Pending input on the Commands screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandsInput {
+    /// Edit the draft of the selected cell
+    Edit,
+    /// Add a language column
+    AddLanguage,
+    /// Import a JSON file as drafts
+    Import,
+    /// Confirm applying all pending drafts
+    ConfirmApply,
+}
+
+impl CommandsInput {
+    /// Returns the prompt shown while the input is pending.
+    pub fn prompt(self) -> &'static str {
+        match self {
+            CommandsInput::Edit => {
+                "Commands as \"cmd - description; ...\", empty to delete (Enter/Esc)"
+            }
+            CommandsInput::AddLanguage => "Two-letter language code, e.g. de (Enter/Esc)",
+            CommandsInput::Import => "Path of a command set JSON file (Enter/Esc)",
+            CommandsInput::ConfirmApply => {
+                "Apply all pending changes? (y to confirm, n/Esc to cancel)"
+            }
+        }
+    }
+}
+
+/// Pending input on the Bot Profile screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileInput {
+    /// Edit the value of the selected row
+    Edit,
+    /// Add a language
+    AddLanguage,
+}
+
 /// Manages all UI-related state.
 ///
 /// Handles screen navigation, item selection, and scroll positions.
@@ -52,6 +232,12 @@ pub struct UiState {
     pub selected_message_index: usize,
     pub selected_update_index: usize,
 
+    // Raw JSON screen: list shown, selected API response, and whether the
+    // JSON is shown re-serialized instead of as received
+    pub raw_json_source: RawJsonSource,
+    pub selected_response_index: usize,
+    pub raw_json_normalized: bool,
+
     // Token input screen state
     pub token_input: String,
     pub token_error: Option<String>,
@@ -62,9 +248,69 @@ pub struct UiState {
     pub test_message_mode: TestMessageMode,
     pub manual_chat_id_input: String,
     pub test_message_input_focus: InputFocus,
+    /// Topic of the selected chat to send into (0 = no topic / General)
+    pub test_message_topic_index: usize,
+
+    // Media group (album) composer state
+    pub media_group_items: Vec<InputMedia>,
+    pub media_group_input: String,
+    pub media_group_kind: InputMediaKind,
+    pub media_group_result: Option<String>,
+
+    // File inspector state (files of the message with this update ID)
+    pub file_inspection_update_id: Option<i64>,
+    pub file_inspections: Vec<FileInspection>,
+
+    // Last permission/privacy diagnostics run (Home screen)
+    pub diagnostics: Option<BotDiagnostics>,
+
+    // Chat view tab and the last chat inspection (getChat & co.)
+    pub chat_tab: ChatTab,
+    pub chat_inspection: Option<ChatInspection>,
+
+    // Topics tab: selection (0 = General topic), pending action and icon stickers
+    pub selected_topic_index: usize,
+    pub topic_action: Option<TopicAction>,
+    pub topic_action_input: String,
+    pub topic_action_result: Option<String>,
+    pub topic_icon_stickers: Vec<Sticker>,
+
+    // Pending action on the selected message
+    pub message_action: Option<MessageAction>,
+    pub message_action_input: String,
+    pub message_action_result: Option<String>,
+    /// Panel shown next to the message list
+    pub message_panel: MessagePanel,
+
+    // Bot commands manager (scope × language matrix)
+    pub commands: CommandMatrix,
+    pub commands_selected_scope: usize,
+    pub commands_selected_language: usize,
+    pub commands_input_mode: Option<CommandsInput>,
+    pub commands_input: String,
+    pub commands_result: Option<String>,
+
+    // Bot profile screen state
+    pub bot_profile: BotProfile,
+    pub profile_selected_row: usize,
+    pub profile_input_mode: Option<ProfileInput>,
+    pub profile_input: String,
+    pub profile_result: Option<String>,
+
+    // Users screen: selection within the filtered list and search
+    pub selected_user_index: usize,
+    pub users_search: String,
+    pub users_search_active: bool,
+
+    // Schema drift screen: selected unknown field
+    pub selected_drift_index: usize,
 
     // Analytics cache
     pub statistics: Option<Statistics>,
+    pub activity_timezone: ActivityTimezone,
+    pub throughput_window: ThroughputWindow,
+    pub analytics_tab: AnalyticsTab,
+    pub selected_command_index: usize,
 
     // Webhook management screen state
     pub webhook_url_input: String,
@@ -85,6 +331,9 @@ impl UiState {
             selected_chat_index: 0,
             selected_message_index: 0,
             selected_update_index: 0,
+            raw_json_source: RawJsonSource::Updates,
+            selected_response_index: 0,
+            raw_json_normalized: false,
             token_input: String::new(),
             token_error: None,
             test_message_input: String::new(),
@@ -92,7 +341,45 @@ impl UiState {
             test_message_mode: TestMessageMode::SelectedChat,
             manual_chat_id_input: String::new(),
             test_message_input_focus: InputFocus::MessageText, // Start with MessageText in SelectedChat mode
+            test_message_topic_index: 0,
+            media_group_items: Vec::new(),
+            media_group_input: String::new(),
+            media_group_kind: InputMediaKind::Photo,
+            media_group_result: None,
+            file_inspection_update_id: None,
+            file_inspections: Vec::new(),
+            diagnostics: None,
+            chat_tab: ChatTab::Messages,
+            chat_inspection: None,
+            selected_topic_index: 0,
+            topic_action: None,
+            topic_action_input: String::new(),
+            topic_action_result: None,
+            topic_icon_stickers: Vec::new(),
+            message_action: None,
+            message_action_input: String::new(),
+            message_action_result: None,
+            message_panel: MessagePanel::Details,
+            commands: CommandMatrix::new(),
+            commands_selected_scope: 0,
+            commands_selected_language: 0,
+            commands_input_mode: None,
+            commands_input: String::new(),
+            commands_result: None,
+            bot_profile: BotProfile::new(),
+            profile_selected_row: 0,
+            profile_input_mode: None,
+            profile_input: String::new(),
+            profile_result: None,
+            selected_user_index: 0,
+            users_search: String::new(),
+            users_search_active: false,
+            selected_drift_index: 0,
             statistics: None,
+            activity_timezone: ActivityTimezone::default(),
+            throughput_window: ThroughputWindow::default(),
+            analytics_tab: AnalyticsTab::Overview,
+            selected_command_index: 0,
             webhook_url_input: String::new(),
             webhook_info_cache: None,
             webhook_operation_result: None,
@@ -131,6 +418,11 @@ impl UiState {
             | Screen::RawJson
             | Screen::TestMessage
             | Screen::WebhookManagement
+            | Screen::MediaGroup
+            | Screen::Commands
+            | Screen::BotProfile
+            | Screen::Users
+            | Screen::SchemaDrift
             | Screen::Help => Some(Screen::Home),
             Screen::Home => {
                 // On home, Esc quits
@@ -157,6 +449,7 @@ impl UiState {
     pub fn next_chat(&mut self, chat_count: usize) {
         if chat_count > 0 {
             self.selected_chat_index = (self.selected_chat_index + 1) % chat_count;
+            self.test_message_topic_index = 0;
             self.mark_dirty();
         }
     }
@@ -168,6 +461,7 @@ impl UiState {
             } else {
                 self.selected_chat_index -= 1;
             }
+            self.test_message_topic_index = 0;
             self.mark_dirty();
         }
     }
@@ -224,6 +518,96 @@ impl UiState {
         self.mark_dirty();
     }
 
+    /// Cycles the chat view tabs; the Topics tab is only offered when `with_topics` is set.
+    pub fn toggle_chat_tab(&mut self, with_topics: bool) {
+        self.chat_tab = match self.chat_tab {
+            ChatTab::Messages => ChatTab::Details,
+            ChatTab::Details if with_topics => ChatTab::Topics,
+            ChatTab::Details | ChatTab::Topics => ChatTab::Messages,
+        };
+        self.mark_dirty();
+    }
+
+    pub fn toggle_analytics_tab(&mut self) {
+        self.analytics_tab = match self.analytics_tab {
+            AnalyticsTab::Overview => AnalyticsTab::UpdateTypes,
+            AnalyticsTab::UpdateTypes => AnalyticsTab::Commands,
+            AnalyticsTab::Commands => AnalyticsTab::Engagement,
+            AnalyticsTab::Engagement => AnalyticsTab::Overview,
+        };
+        self.mark_dirty();
+    }
+
+    pub fn start_topic_action(&mut self, action: TopicAction, initial_input: String) {
+        self.topic_action = Some(action);
+        self.topic_action_input = initial_input;
+        self.mark_dirty();
+    }
+
+    pub fn cancel_topic_action(&mut self) {
+        self.topic_action = None;
+        self.topic_action_input.clear();
+        self.mark_dirty();
+    }
+
+    pub fn start_message_action(&mut self, action: MessageAction, initial_input: String) {
+        self.message_action = Some(action);
+        self.message_action_input = initial_input;
+        self.mark_dirty();
+    }
+
+    pub fn cancel_message_action(&mut self) {
+        self.message_action = None;
+        self.message_action_input.clear();
+        self.mark_dirty();
+    }
+
+    pub fn start_commands_input(&mut self, mode: CommandsInput, initial_input: String) {
+        self.commands_input_mode = Some(mode);
+        self.commands_input = initial_input;
+        self.mark_dirty();
+    }
+
+    pub fn cancel_commands_input(&mut self) {
+        self.commands_input_mode = None;
+        self.commands_input.clear();
+        self.mark_dirty();
+    }
+
+    /// Moves the Commands screen selection, wrapping around in both directions.
+    pub fn move_commands_selection(&mut self, scope_delta: isize, language_delta: isize) {
+        let wrap = |index: usize, delta: isize, len: usize| {
+            if len == 0 {
+                0
+            } else {
+                (index as isize + delta).rem_euclid(len as isize) as usize
+            }
+        };
+        self.commands_selected_scope = wrap(
+            self.commands_selected_scope,
+            scope_delta,
+            self.commands.scopes.len(),
+        );
+        self.commands_selected_language = wrap(
+            self.commands_selected_language,
+            language_delta,
+            self.commands.languages.len(),
+        );
+        self.mark_dirty();
+    }
+
+    pub fn start_profile_input(&mut self, mode: ProfileInput, initial_input: String) {
+        self.profile_input_mode = Some(mode);
+        self.profile_input = initial_input;
+        self.mark_dirty();
+    }
+
+    pub fn cancel_profile_input(&mut self) {
+        self.profile_input_mode = None;
+        self.profile_input.clear();
+        self.mark_dirty();
+    }
+
     pub fn toggle_input_focus(&mut self) {
         self.test_message_input_focus = match self.test_message_input_focus {
             InputFocus::ChatId => InputFocus::MessageText,
@@ -319,6 +703,11 @@ mod tests {
 
         state.previous_chat(3); // Wrap back
         assert_eq!(state.selected_chat_index, 2);
+
+        // The topic of the previous chat does not carry over
+        state.test_message_topic_index = 2;
+        state.next_chat(3);
+        assert_eq!(state.test_message_topic_index, 0);
     }
 
     // Boundary conditions
@@ -435,4 +824,68 @@ mod tests {
         assert!(state.needs_render);
         assert_eq!(state.selected_chat_index, 0);
     }
+
+    #[test]
+    fn test_message_action_lifecycle() {
+        let mut state = UiState::new();
+        state.start_message_action(MessageAction::EditText, "old text".to_string());
+        assert_eq!(state.message_action, Some(MessageAction::EditText));
+        assert_eq!(state.message_action_input, "old text");
+
+        state.cancel_message_action();
+        assert!(state.message_action.is_none());
+        assert!(state.message_action_input.is_empty());
+    }
+
+    #[test]
+    fn test_only_delete_is_destructive() {
+        assert!(MessageAction::Delete.is_destructive());
+        assert!(!MessageAction::EditText.is_destructive());
+        assert!(!MessageAction::Copy.is_destructive());
+    }
+
+    #[test]
+    fn test_toggle_chat_tab() {
+        let mut state = UiState::new();
+        assert_eq!(state.chat_tab, ChatTab::Messages);
+
+        state.toggle_chat_tab(false);
+        assert_eq!(state.chat_tab, ChatTab::Details);
+
+        state.toggle_chat_tab(false);
+        assert_eq!(state.chat_tab, ChatTab::Messages);
+
+        state.toggle_chat_tab(true);
+        state.toggle_chat_tab(true);
+        assert_eq!(state.chat_tab, ChatTab::Topics);
+        state.toggle_chat_tab(true);
+        assert_eq!(state.chat_tab, ChatTab::Messages);
+    }
+
+    #[test]
+    fn test_topic_action_input_kinds() {
+        assert!(TopicAction::Create.needs_input());
+        assert!(TopicAction::Send.needs_input());
+        assert!(!TopicAction::Close.needs_input());
+        assert!(!TopicAction::Delete.needs_input());
+    }
+
+    #[test]
+    fn test_commands_selection_wraps() {
+        let mut state = UiState::new();
+        state.move_commands_selection(-1, 0);
+        assert_eq!(
+            state.commands_selected_scope,
+            state.commands.scopes.len() - 1
+        );
+        state.move_commands_selection(1, 1);
+        assert_eq!(state.commands_selected_scope, 0);
+        assert_eq!(state.commands_selected_language, 0);
+
+        state.start_commands_input(CommandsInput::Edit, "start - Start".to_string());
+        assert_eq!(state.commands_input_mode, Some(CommandsInput::Edit));
+        state.cancel_commands_input();
+        assert!(state.commands_input_mode.is_none());
+        assert!(state.commands_input.is_empty());
+    }
 }
@@ -4,15 +4,37 @@
 
 use anyhow::Result;
 use serde_json::Value as JsonValue;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::Arc;
 
-use super::state::TestMessageMode;
-use crate::telegram::{DiscoveredChat, TelegramClient, Update, UpdateProcessor};
+use super::state::{MessageAction, TestMessageMode, TopicAction};
+use crate::analytics::{
+    ActivityTimeline, CommandUsage, EngagementStore, ThroughputStore, UpdateTypeCounts,
+};
+use crate::telegram::commands::{CommandMatrix, PendingCommandChange};
+use crate::telegram::diagnostics::{
+    BotDiagnostics, ChatDiagnostics, CheckItem, general_checks, membership_checks,
+};
+use crate::telegram::files::{format_file_size, guess_mime_type, update_file_references};
+use crate::telegram::profile::{
+    BotProfile, ProfileField, ProfileRow, parse_menu_button, validate_profile_text,
+};
+use crate::telegram::{
+    ApiResponse, ChatAdministratorRights, ChatFullInfo, ChatMember, ConversationNode,
+    DiscoveredChat, FORUM_TOPIC_ICON_COLORS, FileReference, GetUpdatesResponse, InputMedia,
+    KnownUser, Message, MessageContent, MessageRevision, SchemaDrift, TelegramClient, ThreadRow,
+    Update, UpdateProcessor, WireCapture,
+};
 
 // Input validation constants
 const MAX_TOKEN_LENGTH: usize = 256;
 const MAX_CHAT_ID_LENGTH: usize = 20;
 const MAX_MESSAGE_LENGTH: usize = 4096;
+const MAX_TOPIC_NAME_LENGTH: usize = 128;
+
+/// Number of messages sent by the bot kept for the Messages screen.
+const MAX_OUTGOING_MESSAGES: usize = 100;
 
 /// Result of token validation.
 pub enum TokenValidationResult {
@@ -26,35 +48,155 @@ pub enum TokenValidationResult {
 pub struct SendMessageResult {
     pub success: bool,
     pub message: String,
+    /// New supergroup ID when the target group has been upgraded
+    pub migrate_to_chat_id: Option<i64>,
+}
+
+/// Outcome of resolving and downloading one file of a message.
+#[derive(Debug, Clone)]
+pub struct FileInspection {
+    pub reference: FileReference,
+    pub file_path: Option<String>,
+    pub file_size: Option<u64>,
+    pub mime_type: Option<String>,
+    pub outcome: String,
+}
+
+/// Everything the Bot API reports about one chat and the bot's place in it.
+///
+/// Each call can fail independently (e.g. administrators can't be listed in
+/// private chats), so every part keeps its own error message.
+#[derive(Debug, Clone)]
+pub struct ChatInspection {
+    pub chat_id: i64,
+    pub chat: std::result::Result<ChatFullInfo, String>,
+    pub administrators: std::result::Result<Vec<ChatMember>, String>,
+    pub member_count: std::result::Result<i64, String>,
+    pub bot_member: std::result::Result<ChatMember, String>,
+}
+
+impl ChatInspection {
+    /// Explains what the bot's membership and rights mean for this chat.
+    ///
+    /// Lines start with ✓ (fine), ⚠ (limited) or ✗ (blocking).
+    pub fn notes(&self) -> Vec<String> {
+        let chat_type = self
+            .chat
+            .as_ref()
+            .map(|c| c.chat_type.as_str())
+            .unwrap_or_default();
+        let mut checks = membership_checks(
+            chat_type,
+            self.bot_member.as_ref().map_err(String::as_str),
+            None,
+        );
+
+        if let (Ok(bot), Ok(chat)) = (&self.bot_member, &self.chat)
+            && bot.is_present()
+        {
+            let limited_admin = bot.status == "administrator";
+            if limited_admin && bot.can_delete_messages != Some(true) && chat_type != "private" {
+                checks.push(CheckItem::warning(
+                    "can_delete_messages missing - the bot can only delete its own messages",
+                ));
+            }
+            if limited_admin && bot.can_pin_messages == Some(false) {
+                checks.push(CheckItem::warning(
+                    "can_pin_messages missing - pinning will fail",
+                ));
+            }
+            if chat.is_forum && bot.status != "creator" && bot.can_manage_topics != Some(true) {
+                checks.push(CheckItem::warning(
+                    "can_manage_topics missing - the bot cannot create or edit topics",
+                ));
+            }
+            if let Some(delay) = chat.slow_mode_delay.filter(|d| *d > 0) {
+                checks.push(if bot.is_admin() {
+                    CheckItem::ok(format!(
+                        "Slow mode ({delay}s) does not apply to administrators"
+                    ))
+                } else {
+                    CheckItem::warning(format!(
+                        "Slow mode: the bot can send one message every {delay}s"
+                    ))
+                });
+            }
+            let can_send = chat.permissions.as_ref().and_then(|p| p.can_send_messages);
+            if can_send == Some(false) && !bot.is_admin() {
+                checks.push(CheckItem::error(
+                    "Members may not send messages - neither may the bot",
+                ));
+            }
+        }
+
+        checks.iter().map(CheckItem::to_string).collect()
+    }
 }
 
 /// Manages Telegram API client and update processing.
 pub struct TelegramManager {
     pub client: Option<TelegramClient>,
+    pub file_base_url: Option<String>,
     pub update_processor: UpdateProcessor,
     pub raw_updates: Vec<Arc<Update>>,
-    pub raw_json_updates: Vec<JsonValue>, // Complete raw JSON from API
+    /// JSON text of the updates in `raw_updates` exactly as Telegram sent it, by update ID
+    pub raw_json_updates: HashMap<i64, String>,
+    /// Messages sent by the bot, wrapped in updates with negative IDs since
+    /// getUpdates never returns them
+    pub outgoing_updates: Vec<Arc<Update>>,
+    /// Messages deleted by the bot, as `(chat_id, message_id)`
+    pub deleted_messages: HashSet<(i64, i64)>,
+    /// Last (negative) update ID given to an outgoing message or edit
+    last_outgoing_update_id: i64,
     pub last_processed_update_id: i64,
+    /// Hourly counts of received messages and channel posts, by their date
+    pub activity: ActivityTimeline,
+    /// Recent updates per minute and hour, by the time they were received
+    pub throughput: ThroughputStore,
+    /// Updates received per type, overall and per chat
+    pub update_types: UpdateTypeCounts,
+    /// Bot commands sent in received messages
+    pub command_usage: CommandUsage,
+    /// Updates per user, with the days they were active on
+    pub engagement: EngagementStore,
 }
 
 impl TelegramManager {
     pub fn new() -> Self {
         Self {
             client: None,
+            file_base_url: None,
             update_processor: UpdateProcessor::new(),
             raw_updates: Vec::new(),
-            raw_json_updates: Vec::new(),
+            raw_json_updates: HashMap::new(),
+            outgoing_updates: Vec::new(),
+            deleted_messages: HashSet::new(),
+            last_outgoing_update_id: 0,
             last_processed_update_id: 0,
+            activity: ActivityTimeline::new(),
+            throughput: ThroughputStore::new(),
+            update_types: UpdateTypeCounts::new(),
+            command_usage: CommandUsage::new(),
+            engagement: EngagementStore::new(),
         }
     }
 
     pub fn new_with_token(token: String) -> Self {
         Self {
             client: Some(TelegramClient::new(token)),
+            file_base_url: None,
             update_processor: UpdateProcessor::new(),
             raw_updates: Vec::new(),
-            raw_json_updates: Vec::new(),
+            raw_json_updates: HashMap::new(),
+            outgoing_updates: Vec::new(),
+            deleted_messages: HashSet::new(),
+            last_outgoing_update_id: 0,
             last_processed_update_id: 0,
+            activity: ActivityTimeline::new(),
+            throughput: ThroughputStore::new(),
+            update_types: UpdateTypeCounts::new(),
+            command_usage: CommandUsage::new(),
+            engagement: EngagementStore::new(),
         }
     }
 
@@ -69,6 +211,23 @@ impl TelegramManager {
             .ok_or_else(|| anyhow::anyhow!("No Telegram client available - token not set"))
     }
 
+    /// Sets the file download endpoint for the current and all future clients.
+    pub fn set_file_base_url(&mut self, file_base_url: Option<String>) {
+        self.file_base_url = file_base_url;
+        if let Some(client) = self.client.take() {
+            let token = client.get_token().to_string();
+            self.client = Some(self.build_client(token));
+        }
+    }
+
+    fn build_client(&self, token: String) -> TelegramClient {
+        let client = TelegramClient::new(token);
+        match &self.file_base_url {
+            Some(url) => client.with_file_base_url(url),
+            None => client,
+        }
+    }
+
     pub async fn validate_token(&mut self, token_input: &str) -> Result<TokenValidationResult> {
         let token = token_input.trim().to_string();
 
@@ -80,11 +239,13 @@ impl TelegramManager {
             return Ok(TokenValidationResult::TooLong(MAX_TOKEN_LENGTH));
         }
 
-        let client = TelegramClient::new(token);
+        let client = self.build_client(token);
         match client.get_me().await {
             Ok(response) => {
                 if response.ok {
                     self.client = Some(client.clone());
+                    self.command_usage
+                        .set_bot_username(response.result.and_then(|me| me.username));
                     Ok(TokenValidationResult::Valid(client))
                 } else {
                     Ok(TokenValidationResult::Invalid("Invalid token".to_string()))
@@ -95,16 +256,18 @@ impl TelegramManager {
     }
 
     pub async fn send_test_message(
-        &self,
+        &mut self,
         message_input: &str,
         chat_id_input: &str,
         mode: TestMessageMode,
         selected_chat: Option<&DiscoveredChat>,
+        message_thread_id: Option<i64>,
     ) -> Result<SendMessageResult> {
         let Some(client) = &self.client else {
             return Ok(SendMessageResult {
                 success: false,
                 message: "✗ Error: No client available".to_string(),
+                migrate_to_chat_id: None,
             });
         };
 
@@ -115,6 +278,7 @@ impl TelegramManager {
             return Ok(SendMessageResult {
                 success: false,
                 message: "✗ Error: Message cannot be empty".to_string(),
+                migrate_to_chat_id: None,
             });
         }
 
@@ -122,58 +286,117 @@ impl TelegramManager {
             return Ok(SendMessageResult {
                 success: false,
                 message: format!("✗ Error: Message too long (max {MAX_MESSAGE_LENGTH} characters)"),
+                migrate_to_chat_id: None,
             });
         }
 
-        let chat_id = match mode {
-            TestMessageMode::SelectedChat => {
-                if let Some(chat) = selected_chat {
-                    chat.chat.id
-                } else {
-                    return Ok(SendMessageResult {
-                        success: false,
-                        message: "✗ Error: No chat selected".to_string(),
-                    });
-                }
+        let chat_id = match Self::resolve_chat_id(chat_id_input, mode, selected_chat) {
+            Ok(id) => id,
+            Err(message) => {
+                return Ok(SendMessageResult {
+                    success: false,
+                    message,
+                    migrate_to_chat_id: None,
+                });
             }
-            TestMessageMode::ManualChatId => {
-                let chat_id_str = chat_id_input.trim();
+        };
 
-                if chat_id_str.is_empty() {
-                    return Ok(SendMessageResult {
-                        success: false,
-                        message: "✗ Error: Chat ID cannot be empty".to_string(),
-                    });
-                }
+        let result = client
+            .send_message(chat_id, text, message_thread_id)
+            .await?;
+        if let Some(sent) = result.result.clone() {
+            self.record_outgoing_message(sent);
+        }
 
-                if chat_id_str.len() > MAX_CHAT_ID_LENGTH {
-                    return Ok(SendMessageResult {
-                        success: false,
-                        message: format!(
-                            "✗ Error: Chat ID too long (max {MAX_CHAT_ID_LENGTH} chars)"
-                        ),
-                    });
-                }
-
-                match chat_id_str.parse::<i64>() {
-                    Ok(id) => id,
-                    Err(_) => {
-                        return Ok(SendMessageResult {
-                            success: false,
-                            message: "✗ Error: Invalid chat ID format (must be a number)"
-                                .to_string(),
-                        });
-                    }
-                }
+        if result.ok {
+            let message = match message_thread_id {
+                Some(thread_id) => format!("✓ Message sent successfully to topic {thread_id}!"),
+                None => "✓ Message sent successfully!".to_string(),
+            };
+            Ok(SendMessageResult {
+                success: true,
+                message,
+                migrate_to_chat_id: None,
+            })
+        } else {
+            let error = result
+                .description
+                .unwrap_or_else(|| "Unknown error".to_string());
+            let migrate_to_chat_id = result.parameters.and_then(|p| p.migrate_to_chat_id);
+            let message = match migrate_to_chat_id {
+                Some(new_chat_id) => format!(
+                    "✗ Error: {error} - the group was upgraded to supergroup {new_chat_id}, press Enter to send there"
+                ),
+                None => format!("✗ Error: {error}"),
+            };
+            Ok(SendMessageResult {
+                success: false,
+                message,
+                migrate_to_chat_id,
+            })
+        }
+    }
+
+    /// Sends the composed album to the selected chat or the manually entered chat ID.
+    pub async fn send_media_group(
+        &mut self,
+        items: &[InputMedia],
+        chat_id_input: &str,
+        mode: TestMessageMode,
+        selected_chat: Option<&DiscoveredChat>,
+        message_thread_id: Option<i64>,
+    ) -> Result<SendMessageResult> {
+        let Some(client) = &self.client else {
+            return Ok(SendMessageResult {
+                success: false,
+                message: "✗ Error: No client available".to_string(),
+                migrate_to_chat_id: None,
+            });
+        };
+
+        if let Err(e) = InputMedia::validate_group(items) {
+            return Ok(SendMessageResult {
+                success: false,
+                message: format!("✗ Error: {e}"),
+                migrate_to_chat_id: None,
+            });
+        }
+
+        let chat_id = match Self::resolve_chat_id(chat_id_input, mode, selected_chat) {
+            Ok(id) => id,
+            Err(message) => {
+                return Ok(SendMessageResult {
+                    success: false,
+                    message,
+                    migrate_to_chat_id: None,
+                });
             }
         };
 
-        let result = client.send_message(chat_id, text, None).await?;
+        let result = match client
+            .send_media_group(chat_id, items, message_thread_id)
+            .await
+        {
+            Ok(result) => result,
+            Err(e) => {
+                return Ok(SendMessageResult {
+                    success: false,
+                    message: format!("✗ Error: {e:#}"),
+                    migrate_to_chat_id: None,
+                });
+            }
+        };
 
         if result.ok {
+            let sent = result.result.unwrap_or_default();
+            let count = sent.len();
+            for message in sent {
+                self.record_outgoing_message(message);
+            }
             Ok(SendMessageResult {
                 success: true,
-                message: "✓ Message sent successfully!".to_string(),
+                message: format!("✓ Album sent ({count} messages)"),
+                migrate_to_chat_id: None,
             })
         } else {
             let error = result
@@ -182,27 +405,167 @@ impl TelegramManager {
             Ok(SendMessageResult {
                 success: false,
                 message: format!("✗ Error: {error}"),
+                migrate_to_chat_id: None,
             })
         }
     }
 
+    /// Determines the target chat ID from the current send mode.
+    ///
+    /// Returns a ready-to-display error message if no valid chat ID is available.
+    fn resolve_chat_id(
+        chat_id_input: &str,
+        mode: TestMessageMode,
+        selected_chat: Option<&DiscoveredChat>,
+    ) -> std::result::Result<i64, String> {
+        match mode {
+            TestMessageMode::SelectedChat => selected_chat
+                .map(|chat| chat.chat.id)
+                .ok_or_else(|| "✗ Error: No chat selected".to_string()),
+            TestMessageMode::ManualChatId => {
+                let chat_id_str = chat_id_input.trim();
+
+                if chat_id_str.is_empty() {
+                    return Err("✗ Error: Chat ID cannot be empty".to_string());
+                }
+
+                if chat_id_str.len() > MAX_CHAT_ID_LENGTH {
+                    return Err(format!(
+                        "✗ Error: Chat ID too long (max {MAX_CHAT_ID_LENGTH} chars)"
+                    ));
+                }
+
+                chat_id_str
+                    .parse::<i64>()
+                    .map_err(|_| "✗ Error: Invalid chat ID format (must be a number)".to_string())
+            }
+        }
+    }
+
     pub fn get_discovered_chats(&self) -> Vec<&DiscoveredChat> {
         self.update_processor.get_discovered_chats()
     }
 
+    /// Returns the received and sent messages of a chat, ordered by date.
     pub fn get_messages_for_chat(&self, chat_id: i64) -> Vec<&Arc<Update>> {
-        self.raw_updates
+        // Messages of an upgraded group belong to its supergroup
+        let resolve = |id: i64| self.update_processor.resolve_chat_id(id);
+        let mut messages: Vec<&Arc<Update>> = self
+            .raw_updates
             .iter()
+            .chain(&self.outgoing_updates)
             .filter(|update| {
                 if let Some(message) = &update.message {
-                    message.chat.id == chat_id
+                    resolve(message.chat.id) == chat_id
                 } else if let Some(channel_post) = &update.channel_post {
                     channel_post.chat.id == chat_id
                 } else {
                     false
                 }
             })
-            .collect()
+            .collect();
+        // Stable sort keeps arrival order for messages sent in the same second
+        messages.sort_by_key(|update| {
+            update
+                .message
+                .as_ref()
+                .map(|m| m.date)
+                .or_else(|| update.channel_post.as_ref().map(|p| p.date))
+        });
+        messages
+    }
+
+    /// Records a message sent, forwarded or edited by the bot.
+    ///
+    /// Every version gets the next negative update ID. A new version of a known
+    /// outgoing message replaces it in place; its earlier versions stay in the
+    /// edit history.
+    pub fn record_outgoing_message(&mut self, message: Message) {
+        let key = (message.chat.id, message.message_id);
+        let known = self
+            .outgoing_updates
+            .iter()
+            .position(|update| update.message_key() == Some(key));
+        self.last_outgoing_update_id -= 1;
+        let update_id = self.last_outgoing_update_id;
+        self.update_processor.record_outgoing(update_id, &message);
+
+        let update = Arc::new(Update {
+            update_id,
+            message: Some(message),
+            channel_post: None,
+            edited_message: None,
+            other: std::collections::HashMap::new(),
+        });
+        match known {
+            Some(index) => self.outgoing_updates[index] = update,
+            None => {
+                self.outgoing_updates.push(update);
+                if self.outgoing_updates.len() > MAX_OUTGOING_MESSAGES {
+                    self.outgoing_updates.remove(0);
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the update wraps a message sent by the bot.
+    pub fn is_outgoing(update: &Update) -> bool {
+        update.update_id < 0
+    }
+
+    /// Returns `true` if the bot deleted this message.
+    pub fn is_deleted(&self, chat_id: i64, message_id: i64) -> bool {
+        self.deleted_messages.contains(&(chat_id, message_id))
+    }
+
+    /// Returns the original version and all edits of a message, oldest first.
+    pub fn get_message_history(&self, chat_id: i64, message_id: i64) -> Option<&[MessageRevision]> {
+        self.update_processor
+            .get_message_history(chat_id, message_id)
+    }
+
+    /// Returns the fields seen in updates that the targeted Bot API version doesn't have.
+    pub fn get_schema_drift(&self) -> &SchemaDrift {
+        self.update_processor.get_schema_drift()
+    }
+
+    /// Returns the number of edits seen for a message.
+    pub fn edit_count(&self, chat_id: i64, message_id: i64) -> usize {
+        self.update_processor.edit_count(chat_id, message_id)
+    }
+
+    /// Returns a message of the conversation model.
+    pub fn get_conversation_node(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+    ) -> Option<&ConversationNode> {
+        self.update_processor
+            .get_conversation_node(chat_id, message_id)
+    }
+
+    /// Returns the reply thread a message belongs to, including the bot's own messages.
+    pub fn get_conversation_thread(&self, chat_id: i64, message_id: i64) -> Vec<ThreadRow<'_>> {
+        self.update_processor
+            .get_conversation_thread(chat_id, message_id)
+    }
+
+    pub fn get_known_users(&self) -> Vec<&KnownUser> {
+        self.update_processor.get_known_users()
+    }
+
+    /// Records a migration reported by the API and merges the group into its supergroup.
+    pub fn link_migration(&mut self, old_chat_id: i64, new_chat_id: i64) {
+        self.update_processor
+            .link_migration(old_chat_id, new_chat_id);
+    }
+
+    /// Returns the messages of a chat with album items collapsed into one entry.
+    ///
+    /// Each entry holds all updates sharing a `media_group_id` (in arrival order), or a
+    /// single update for regular messages. Entries are ordered by their first update.
+    pub fn get_message_groups_for_chat(&self, chat_id: i64) -> Vec<Vec<&Arc<Update>>> {
+        group_by_media_group(self.get_messages_for_chat(chat_id))
     }
 
     pub fn get_selected_message_for_chat(
@@ -210,23 +573,48 @@ impl TelegramManager {
         chat_id: Option<i64>,
         message_index: usize,
     ) -> Option<&Arc<Update>> {
-        if let Some(id) = chat_id {
-            let messages = self.get_messages_for_chat(id);
-            messages.get(message_index).copied()
-        } else {
-            None
-        }
+        let id = chat_id?;
+        self.get_message_groups_for_chat(id)
+            .get(message_index)
+            .and_then(|group| group.first().copied())
     }
 
     pub fn get_selected_update(&self, index: usize) -> Option<&Arc<Update>> {
         self.raw_updates.get(index)
     }
 
+    /// Keeps the verbatim JSON of the updates of a `getUpdates` response.
+    ///
+    /// Must be called before [`Self::process_updates_batch`] for the same
+    /// response, which drops the JSON of updates no longer kept.
+    pub fn record_verbatim_updates(&mut self, response: &GetUpdatesResponse) {
+        if response.raw_result.len() != response.result.len() {
+            return;
+        }
+        for (update, raw) in response.result.iter().zip(&response.raw_result) {
+            self.raw_json_updates.insert(update.update_id, raw.clone());
+        }
+    }
+
+    /// Returns the JSON text of an update exactly as Telegram sent it.
+    pub fn verbatim_update(&self, update_id: i64) -> Option<&str> {
+        self.raw_json_updates.get(&update_id).map(String::as_str)
+    }
+
+    /// Returns the recent API responses as received, oldest first.
+    pub fn wire_captures(&self) -> Vec<WireCapture> {
+        self.client
+            .as_ref()
+            .map(|client| client.wire_log().captures())
+            .unwrap_or_default()
+    }
+
     pub fn process_updates_batch(
         &mut self,
         updates: Vec<Update>,
         monitor_messages: &mut Vec<super::monitoring::MonitorMessage>,
     ) {
+        let now = chrono::Utc::now().timestamp();
         for update in &updates {
             if update.update_id > self.last_processed_update_id {
                 self.last_processed_update_id = update.update_id;
@@ -239,9 +627,28 @@ impl TelegramManager {
 
                 // Extract monitor message if applicable
                 self.extract_monitor_message(update, monitor_messages);
+
+                if let Some(date) = update
+                    .message
+                    .as_ref()
+                    .map(|m| m.date)
+                    .or_else(|| update.channel_post.as_ref().map(|p| p.date))
+                {
+                    self.activity.record(date);
+                }
+                self.throughput.record(update, now);
+                self.update_types.record(update);
+                if let Some(message) = &update.message {
+                    self.command_usage.record(message);
+                }
+                self.engagement.record(update, now);
             }
         }
 
+        let kept: HashSet<i64> = self.raw_updates.iter().map(|u| u.update_id).collect();
+        self.raw_json_updates
+            .retain(|update_id, _| kept.contains(update_id));
+
         self.update_processor.process_updates(updates);
     }
 
@@ -258,16 +665,21 @@ impl TelegramManager {
                 .from
                 .as_ref()
                 .map(|u| u.username.clone().unwrap_or_else(|| u.first_name.clone()));
-            let text = message
-                .text
-                .clone()
-                .unwrap_or_else(|| "[No text]".to_string());
+            let content = message.content();
+            // Summaries of media and service messages have no entities
+            let entities = if matches!(content, MessageContent::Text(_)) {
+                message.entities()
+            } else {
+                Vec::new()
+            };
+            let text = content.summary();
 
             monitor_messages.push(super::monitoring::MonitorMessage {
                 timestamp: Local::now().timestamp(),
                 chat_name,
                 sender,
                 text,
+                entities,
             });
 
             // Keep only last 100 messages
@@ -276,16 +688,21 @@ impl TelegramManager {
             }
         } else if let Some(channel_post) = &update.channel_post {
             let chat_name = channel_post.chat.display_name();
-            let text = channel_post
-                .text
-                .clone()
-                .unwrap_or_else(|| "[No text]".to_string());
+            let content = channel_post.content();
+            // Summaries of media and service messages have no entities
+            let entities = if matches!(content, MessageContent::Text(_)) {
+                channel_post.entities()
+            } else {
+                Vec::new()
+            };
+            let text = content.summary();
 
             monitor_messages.push(super::monitoring::MonitorMessage {
                 timestamp: Local::now().timestamp(),
                 chat_name,
                 sender: None,
                 text,
+                entities,
             });
 
             // Keep only last 100 messages
@@ -294,6 +711,803 @@ impl TelegramManager {
             }
         }
     }
+
+    /// Resolves every file of the given updates with getFile and saves them to `download_dir`.
+    ///
+    /// Files above the 20 MB download limit are reported instead of downloaded.
+    pub async fn inspect_files(
+        &self,
+        updates: &[Arc<Update>],
+        download_dir: &Path,
+    ) -> Result<Vec<FileInspection>> {
+        let client = self.get_client()?;
+        let limit = client.download_limit();
+
+        let mut references: Vec<FileReference> = Vec::new();
+        for update in updates {
+            for reference in update_file_references(update) {
+                if !references.iter().any(|r| r.file_id == reference.file_id) {
+                    references.push(reference);
+                }
+            }
+        }
+
+        let mut inspections = Vec::with_capacity(references.len());
+        for reference in references {
+            let mut inspection = FileInspection {
+                file_path: None,
+                file_size: reference.file_size,
+                mime_type: reference.mime_type.clone(),
+                outcome: String::new(),
+                reference,
+            };
+
+            if let Some(limit) = limit
+                && inspection.reference.exceeds_download_limit(Some(limit))
+            {
+                inspection.outcome =
+                    too_large_message(inspection.file_size.unwrap_or_default(), limit);
+                inspections.push(inspection);
+                continue;
+            }
+
+            let file = match client.get_file(&inspection.reference.file_id).await {
+                Ok(response) if response.ok => response.result,
+                Ok(response) => {
+                    let error = response
+                        .description
+                        .unwrap_or_else(|| "Unknown error".to_string());
+                    inspection.outcome = format!("✗ Error: {error}");
+                    inspections.push(inspection);
+                    continue;
+                }
+                Err(e) => {
+                    inspection.outcome = format!("✗ Error: {e:#}");
+                    inspections.push(inspection);
+                    continue;
+                }
+            };
+            let Some(file) = file else {
+                inspection.outcome = "✗ Error: getFile returned no file".to_string();
+                inspections.push(inspection);
+                continue;
+            };
+
+            inspection.file_path = file.file_path.clone();
+            inspection.file_size = file.file_size.or(inspection.file_size);
+            if inspection.mime_type.is_none() {
+                inspection.mime_type = file
+                    .file_path
+                    .as_deref()
+                    .and_then(guess_mime_type)
+                    .map(str::to_string);
+            }
+
+            if let Some((size, limit)) = inspection.file_size.zip(limit).filter(|(s, l)| s > l) {
+                inspection.outcome = too_large_message(size, limit);
+                inspections.push(inspection);
+                continue;
+            }
+
+            inspection.outcome = match client.download_file(&file).await {
+                Ok(bytes) => {
+                    let target = download_dir.join(download_file_name(&inspection));
+                    match std::fs::create_dir_all(download_dir)
+                        .and_then(|_| std::fs::write(&target, &bytes))
+                    {
+                        Ok(()) => format!("✓ Saved to {}", target.display()),
+                        Err(e) => format!("✗ Error: Failed to save file: {e}"),
+                    }
+                }
+                Err(e) => format!("✗ Error: {e:#}"),
+            };
+            inspections.push(inspection);
+        }
+
+        Ok(inspections)
+    }
+
+    /// Applies an edit, forward, copy or delete action to messages of one chat.
+    ///
+    /// `message_ids` holds all messages of the selection (several for albums).
+    /// Edits apply to the first message; forward and copy target the chat ID
+    /// in `input` (or the same chat when empty). Edited and forwarded messages
+    /// returned by the API are recorded as outgoing, deleted ones are marked.
+    pub async fn apply_message_action(
+        &mut self,
+        action: MessageAction,
+        chat_id: i64,
+        message_ids: &[i64],
+        input: &str,
+    ) -> Result<SendMessageResult> {
+        let failure = |message: String| SendMessageResult {
+            success: false,
+            message,
+            migrate_to_chat_id: None,
+        };
+
+        let Some(client) = &self.client else {
+            return Ok(failure("✗ Error: No client available".to_string()));
+        };
+        let Some(&message_id) = message_ids.first() else {
+            return Ok(failure("✗ Error: No message selected".to_string()));
+        };
+        let input = input.trim();
+        let mut returned: Option<Message> = None;
+        let mut deleted = false;
+
+        let outcome = match action {
+            MessageAction::EditText => {
+                if input.is_empty() {
+                    return Ok(failure("✗ Error: Message cannot be empty".to_string()));
+                }
+                if input.len() > MAX_MESSAGE_LENGTH {
+                    return Ok(failure(format!(
+                        "✗ Error: Message too long (max {MAX_MESSAGE_LENGTH} characters)"
+                    )));
+                }
+                client
+                    .edit_message_text(chat_id, message_id, input)
+                    .await
+                    .map(|r| {
+                        returned = r.result;
+                        api_outcome(r.ok, r.description, "Message text edited".to_string())
+                    })
+            }
+            MessageAction::EditReplyMarkup => {
+                let markup = if input.is_empty() {
+                    None
+                } else {
+                    match serde_json::from_str::<JsonValue>(input) {
+                        Ok(value) if value.get("inline_keyboard").is_some() => Some(value),
+                        Ok(_) => {
+                            return Ok(failure(
+                                "✗ Error: Expected an object with \"inline_keyboard\"".to_string(),
+                            ));
+                        }
+                        Err(e) => return Ok(failure(format!("✗ Error: Invalid JSON: {e}"))),
+                    }
+                };
+                let done = if markup.is_some() {
+                    "Inline keyboard replaced"
+                } else {
+                    "Inline keyboard removed"
+                };
+                client
+                    .edit_message_reply_markup(chat_id, message_id, markup.as_ref())
+                    .await
+                    .map(|r| {
+                        returned = r.result;
+                        api_outcome(r.ok, r.description, done.to_string())
+                    })
+            }
+            MessageAction::Forward | MessageAction::Copy => {
+                let target = if input.is_empty() {
+                    chat_id
+                } else {
+                    match Self::resolve_chat_id(input, TestMessageMode::ManualChatId, None) {
+                        Ok(id) => id,
+                        Err(message) => return Ok(failure(message)),
+                    }
+                };
+                if action == MessageAction::Forward {
+                    client
+                        .forward_message(target, chat_id, message_id)
+                        .await
+                        .map(|r| {
+                            let new_id = r.result.as_ref().map(|m| m.message_id).unwrap_or(0);
+                            returned = r.result;
+                            api_outcome(
+                                r.ok,
+                                r.description,
+                                format!("Forwarded to {target} as message {new_id}"),
+                            )
+                        })
+                } else {
+                    client
+                        .copy_message(target, chat_id, message_id)
+                        .await
+                        .map(|r| {
+                            let new_id = r.result.map(|id| id.message_id).unwrap_or(0);
+                            api_outcome(
+                                r.ok,
+                                r.description,
+                                format!("Copied to {target} as message {new_id}"),
+                            )
+                        })
+                }
+            }
+            MessageAction::Delete => {
+                let done = format!("Deleted {} message(s)", message_ids.len());
+                if message_ids.len() == 1 {
+                    client.delete_message(chat_id, message_id).await.map(|r| {
+                        deleted = r.ok;
+                        api_outcome(r.ok, r.description, done)
+                    })
+                } else {
+                    client.delete_messages(chat_id, message_ids).await.map(|r| {
+                        deleted = r.ok;
+                        api_outcome(r.ok, r.description, done)
+                    })
+                }
+            }
+        };
+
+        if let Some(message) = returned {
+            self.record_outgoing_message(message);
+        }
+        if deleted {
+            self.deleted_messages
+                .extend(message_ids.iter().map(|id| (chat_id, *id)));
+        }
+
+        Ok(outcome.unwrap_or_else(|e| failure(format!("✗ Error: {e:#}"))))
+    }
+
+    /// Fetches the bot's username with getMe unless it is known, so commands
+    /// addressed to other bots can be told apart.
+    pub async fn load_bot_username(&mut self) -> Result<()> {
+        if self.command_usage.bot_username().is_some() {
+            return Ok(());
+        }
+        let me = self
+            .get_client()?
+            .get_me()
+            .await?
+            .result
+            .ok_or_else(|| anyhow::anyhow!("getMe returned no bot user"))?;
+        self.command_usage.set_bot_username(me.username);
+        Ok(())
+    }
+
+    /// Queries getChat, getChatAdministrators, getChatMemberCount and the bot's own membership.
+    pub async fn inspect_chat(&self, chat_id: i64) -> Result<ChatInspection> {
+        let client = self.get_client()?;
+        let text = |e: anyhow::Error| format!("{e:#}");
+
+        let bot_id = client
+            .get_me()
+            .await?
+            .result
+            .map(|me| me.id)
+            .ok_or_else(|| anyhow::anyhow!("getMe returned no bot user"))?;
+
+        let chat = client
+            .get_chat(chat_id)
+            .await
+            .and_then(ApiResponse::into_result)
+            .map_err(text);
+        let administrators = client
+            .get_chat_administrators(chat_id)
+            .await
+            .and_then(ApiResponse::into_result)
+            .map_err(text);
+        let member_count = client
+            .get_chat_member_count(chat_id)
+            .await
+            .and_then(ApiResponse::into_result)
+            .map_err(text);
+        let bot_member = client
+            .get_chat_member(chat_id, bot_id)
+            .await
+            .and_then(ApiResponse::into_result)
+            .map_err(text);
+
+        Ok(ChatInspection {
+            chat_id,
+            chat,
+            administrators,
+            member_count,
+            bot_member,
+        })
+    }
+
+    /// Runs the privacy-mode and permission diagnostics for the bot and every discovered chat.
+    ///
+    /// Combines getMe, getWebhookInfo and getChatMember for the bot in each chat.
+    pub async fn run_diagnostics(&self) -> Result<BotDiagnostics> {
+        let client = self.get_client()?;
+
+        let bot = client
+            .get_me()
+            .await?
+            .result
+            .ok_or_else(|| anyhow::anyhow!("getMe returned no bot user"))?;
+        let webhook = client
+            .get_webhook_info()
+            .await
+            .ok()
+            .and_then(|response| response.result);
+
+        let mut general = general_checks(&bot, webhook.as_ref());
+        if self.get_discovered_chats().is_empty() {
+            general.push(CheckItem::warning(
+                "No chats discovered yet - start the Live Monitor (F5) to find chats",
+            ));
+        }
+
+        let mut chats = Vec::new();
+        for chat in self.get_discovered_chats() {
+            let member = client
+                .get_chat_member(chat.chat.id, bot.id)
+                .await
+                .and_then(ApiResponse::into_result)
+                .map_err(|e| format!("{e:#}"));
+            chats.push(ChatDiagnostics {
+                chat_id: chat.chat.id,
+                chat_name: chat.chat.display_name(),
+                chat_type: chat.chat.chat_type.clone(),
+                checks: membership_checks(
+                    &chat.chat.chat_type,
+                    member.as_ref().map_err(String::as_str),
+                    bot.can_read_all_group_messages,
+                ),
+            });
+        }
+
+        Ok(BotDiagnostics {
+            general,
+            chats,
+            generated_at: chrono::Utc::now().timestamp(),
+        })
+    }
+
+    /// Fetches the command list of every (scope, language) cell of the matrix.
+    ///
+    /// A failing cell records its error in the matrix and the other cells are
+    /// still fetched. Returns the number of non-empty lists. Drafts are kept.
+    pub async fn load_commands(&self, matrix: &mut CommandMatrix) -> Result<usize> {
+        let client = self.get_client()?;
+
+        let mut non_empty = 0;
+        for scope in matrix.scopes.clone() {
+            for language in matrix.languages.clone() {
+                match client
+                    .get_my_commands(Some(&scope), Some(&language))
+                    .await
+                    .and_then(ApiResponse::into_result)
+                {
+                    Ok(commands) => {
+                        if !commands.is_empty() {
+                            non_empty += 1;
+                        }
+                        matrix.set_loaded(&scope, &language, commands);
+                    }
+                    Err(e) => matrix.set_error(&scope, &language, format!("{e:#}")),
+                }
+            }
+        }
+        Ok(non_empty)
+    }
+
+    /// Applies pending command drafts with setMyCommands, or deleteMyCommands for empty lists.
+    ///
+    /// Stops at the first failing request; earlier lists stay applied.
+    pub async fn apply_commands(&self, pending: &[PendingCommandChange]) -> SendMessageResult {
+        let Some(client) = &self.client else {
+            return api_outcome(
+                false,
+                Some("No client available".to_string()),
+                String::new(),
+            );
+        };
+
+        for change in pending {
+            let language = Some(change.language_code.as_str());
+            let response = if change.commands.is_empty() {
+                client
+                    .delete_my_commands(Some(&change.scope), language)
+                    .await
+            } else {
+                client
+                    .set_my_commands(&change.commands, Some(&change.scope), language)
+                    .await
+            };
+            if let Err(e) = response.and_then(ApiResponse::into_result) {
+                return api_outcome(
+                    false,
+                    Some(format!(
+                        "{} [{}]: {e:#}",
+                        change.scope.label(),
+                        change.language_code
+                    )),
+                    String::new(),
+                );
+            }
+        }
+
+        api_outcome(
+            true,
+            None,
+            format!("Applied {} command list(s)", pending.len()),
+        )
+    }
+
+    /// Loads getMe, every name/description per language, the default menu button
+    /// and the default administrator rights into `profile`.
+    ///
+    /// A failing row records its error in the profile and the other rows are
+    /// still fetched; only a failing getMe aborts the load.
+    pub async fn load_profile(&self, profile: &mut BotProfile) -> Result<()> {
+        let client = self.get_client()?;
+
+        profile.bot = Some(
+            client
+                .get_me()
+                .await?
+                .result
+                .ok_or_else(|| anyhow::anyhow!("getMe returned no bot user"))?,
+        );
+        for row in profile.rows() {
+            match &row {
+                ProfileRow::Text {
+                    field,
+                    language_code,
+                } => {
+                    let language = Some(language_code.as_str());
+                    let text = match field {
+                        ProfileField::Name => client
+                            .get_my_name(language)
+                            .await
+                            .and_then(ApiResponse::into_result)
+                            .map(|name| name.name),
+                        ProfileField::Description => client
+                            .get_my_description(language)
+                            .await
+                            .and_then(ApiResponse::into_result)
+                            .map(|description| description.description),
+                        ProfileField::ShortDescription => client
+                            .get_my_short_description(language)
+                            .await
+                            .and_then(ApiResponse::into_result)
+                            .map(|short| short.short_description),
+                    };
+                    match text {
+                        Ok(text) => profile.set_text(*field, language_code, text),
+                        Err(e) => profile.set_error(row, format!("{e:#}")),
+                    }
+                }
+                ProfileRow::MenuButton => match client
+                    .get_chat_menu_button(None)
+                    .await
+                    .and_then(ApiResponse::into_result)
+                {
+                    Ok(button) => profile.set_menu_button(button),
+                    Err(e) => profile.set_error(row, format!("{e:#}")),
+                },
+                ProfileRow::AdministratorRights { for_channels } => match client
+                    .get_my_default_administrator_rights(*for_channels)
+                    .await
+                    .and_then(ApiResponse::into_result)
+                {
+                    Ok(rights) => profile.set_administrator_rights(*for_channels, rights),
+                    Err(e) => profile.set_error(row, format!("{e:#}")),
+                },
+            }
+        }
+        profile.loaded_at = Some(chrono::Utc::now().timestamp());
+        Ok(())
+    }
+
+    /// Validates the edited value of a Bot Profile row and saves it with the matching set* method.
+    pub async fn apply_profile_edit(&self, row: &ProfileRow, input: &str) -> SendMessageResult {
+        let failure = |message: String| api_outcome(false, Some(message), String::new());
+        let Some(client) = &self.client else {
+            return failure("No client available".to_string());
+        };
+        let input = input.trim();
+
+        let response = match row {
+            ProfileRow::Text {
+                field,
+                language_code,
+            } => {
+                if let Err(e) = validate_profile_text(*field, input) {
+                    return failure(format!("{e:#}"));
+                }
+                let value = Some(input);
+                let language = Some(language_code.as_str());
+                match field {
+                    ProfileField::Name => client.set_my_name(value, language).await,
+                    ProfileField::Description => client.set_my_description(value, language).await,
+                    ProfileField::ShortDescription => {
+                        client.set_my_short_description(value, language).await
+                    }
+                }
+            }
+            ProfileRow::MenuButton => match parse_menu_button(input) {
+                Ok(button) => client.set_chat_menu_button(None, Some(&button)).await,
+                Err(e) => return failure(format!("{e:#}")),
+            },
+            ProfileRow::AdministratorRights { for_channels } => {
+                match ChatAdministratorRights::parse_granted(input) {
+                    Ok(rights) => {
+                        client
+                            .set_my_default_administrator_rights(Some(&rights), *for_channels)
+                            .await
+                    }
+                    Err(e) => return failure(format!("{e:#}")),
+                }
+            }
+        };
+
+        match response {
+            Ok(response) => api_outcome(
+                response.ok,
+                response.description,
+                format!("{} saved", row.label()),
+            ),
+            Err(e) => failure(format!("{e:#}")),
+        }
+    }
+
+    /// Applies a topic action in a forum supergroup and reflects the result in the
+    /// discovered chat's topics.
+    ///
+    /// `thread_id` is `None` for the General topic. Create and edit take
+    /// `name | icon_custom_emoji_id` as input; send takes the message text.
+    pub async fn apply_topic_action(
+        &mut self,
+        action: TopicAction,
+        chat_id: i64,
+        thread_id: Option<i64>,
+        input: &str,
+    ) -> Result<SendMessageResult> {
+        let failure = |message: &str| SendMessageResult {
+            success: false,
+            message: format!("✗ Error: {message}"),
+            migrate_to_chat_id: None,
+        };
+        let Some(client) = &self.client else {
+            return Ok(failure("No client available"));
+        };
+
+        // Only create and edit take a "name | icon" input; send takes message text
+        let topic_input = || {
+            let (name, icon) = parse_topic_input(input);
+            if name.chars().count() > MAX_TOPIC_NAME_LENGTH {
+                return Err(failure(&format!(
+                    "Topic name too long (max {MAX_TOPIC_NAME_LENGTH} characters)"
+                )));
+            }
+            Ok((name, icon))
+        };
+
+        let topic_label = match thread_id {
+            Some(id) => format!("Topic {id}"),
+            None => "General topic".to_string(),
+        };
+        let result = match (action, thread_id) {
+            (TopicAction::Create, _) => {
+                let (name, icon) = match topic_input() {
+                    Ok(parsed) => parsed,
+                    Err(error) => return Ok(error),
+                };
+                if name.is_empty() {
+                    return Ok(failure("Topic name cannot be empty"));
+                }
+                let topic_count = self
+                    .update_processor
+                    .get_discovered_chats()
+                    .into_iter()
+                    .find(|c| c.chat.id == chat_id)
+                    .map_or(0, |c| c.topics.len());
+                let color = FORUM_TOPIC_ICON_COLORS[topic_count % FORUM_TOPIC_ICON_COLORS.len()];
+                let response = client
+                    .create_forum_topic(chat_id, name, Some(color), icon)
+                    .await?;
+                match response.result {
+                    Some(topic) if response.ok => {
+                        self.update_processor.upsert_topic(chat_id, &topic);
+                        return Ok(api_outcome(
+                            true,
+                            None,
+                            format!(
+                                "Created topic \"{}\" ({})",
+                                topic.name, topic.message_thread_id
+                            ),
+                        ));
+                    }
+                    _ => (false, response.description),
+                }
+            }
+            (TopicAction::Edit, Some(id)) => {
+                let (name, icon) = match topic_input() {
+                    Ok(parsed) => parsed,
+                    Err(error) => return Ok(error),
+                };
+                let name = Some(name).filter(|n| !n.is_empty());
+                let response = client.edit_forum_topic(chat_id, id, name, icon).await?;
+                if response.ok {
+                    self.update_processor.rename_topic(chat_id, id, name, icon);
+                }
+                (response.ok, response.description)
+            }
+            (TopicAction::Edit, None) => {
+                let (name, _) = match topic_input() {
+                    Ok(parsed) => parsed,
+                    Err(error) => return Ok(error),
+                };
+                if name.is_empty() {
+                    return Ok(failure("Topic name cannot be empty"));
+                }
+                let response = client.edit_general_forum_topic(chat_id, name).await?;
+                (response.ok, response.description)
+            }
+            (TopicAction::Send, _) => {
+                let text = input.trim();
+                if text.is_empty() {
+                    return Ok(failure("Message cannot be empty"));
+                }
+                if text.len() > MAX_MESSAGE_LENGTH {
+                    return Ok(failure(&format!(
+                        "Message too long (max {MAX_MESSAGE_LENGTH} characters)"
+                    )));
+                }
+                let response = client.send_message(chat_id, text, thread_id).await?;
+                if let Some(sent) = response.result {
+                    self.record_outgoing_message(sent);
+                }
+                (response.ok, response.description)
+            }
+            (TopicAction::Close, Some(id)) => {
+                let response = client.close_forum_topic(chat_id, id).await?;
+                if response.ok {
+                    self.update_processor.set_topic_closed(chat_id, id, true);
+                }
+                (response.ok, response.description)
+            }
+            (TopicAction::Reopen, Some(id)) => {
+                let response = client.reopen_forum_topic(chat_id, id).await?;
+                if response.ok {
+                    self.update_processor.set_topic_closed(chat_id, id, false);
+                }
+                (response.ok, response.description)
+            }
+            (TopicAction::Delete, Some(id)) => {
+                let response = client.delete_forum_topic(chat_id, id).await?;
+                if response.ok {
+                    self.update_processor.remove_topic(chat_id, id);
+                }
+                (response.ok, response.description)
+            }
+            (TopicAction::Close, None) => {
+                let response = client.close_general_forum_topic(chat_id).await?;
+                (response.ok, response.description)
+            }
+            (TopicAction::Reopen, None) => {
+                let response = client.reopen_general_forum_topic(chat_id).await?;
+                (response.ok, response.description)
+            }
+            (TopicAction::Hide, None) => {
+                let response = client.hide_general_forum_topic(chat_id).await?;
+                (response.ok, response.description)
+            }
+            (TopicAction::Unhide, None) => {
+                let response = client.unhide_general_forum_topic(chat_id).await?;
+                (response.ok, response.description)
+            }
+            (TopicAction::Delete, None) => {
+                return Ok(failure("The General topic cannot be deleted"));
+            }
+            (TopicAction::Hide | TopicAction::Unhide, Some(_)) => {
+                return Ok(failure("Only the General topic can be hidden"));
+            }
+        };
+
+        let done = match action {
+            TopicAction::Create => "Topic created".to_string(),
+            TopicAction::Edit => format!("{topic_label} edited"),
+            TopicAction::Send => format!("Message sent to {topic_label}"),
+            TopicAction::Close => format!("{topic_label} closed"),
+            TopicAction::Reopen => format!("{topic_label} reopened"),
+            TopicAction::Delete => format!("{topic_label} deleted"),
+            TopicAction::Hide => format!("{topic_label} hidden"),
+            TopicAction::Unhide => format!("{topic_label} shown"),
+        };
+        Ok(api_outcome(result.0, result.1, done))
+    }
+
+    /// Fetches the custom emoji stickers usable as topic icons.
+    pub async fn load_topic_icon_stickers(&self) -> Result<Vec<crate::telegram::Sticker>> {
+        self.get_client()?
+            .get_forum_topic_icon_stickers()
+            .await
+            .and_then(ApiResponse::into_result)
+    }
+}
+
+/// Turns the `ok`/`description` pair of an API response into a status message.
+fn api_outcome(ok: bool, description: Option<String>, done: String) -> SendMessageResult {
+    if ok {
+        SendMessageResult {
+            success: true,
+            message: format!("✓ {done}"),
+            migrate_to_chat_id: None,
+        }
+    } else {
+        let error = description.unwrap_or_else(|| "Unknown error".to_string());
+        SendMessageResult {
+            success: false,
+            message: format!("✗ Error: {error}"),
+            migrate_to_chat_id: None,
+        }
+    }
+}
+
+/// Splits topic input `name | icon_custom_emoji_id` into its trimmed parts.
+///
+/// The icon is `None` without a `|`, and `Some("")` to remove the icon.
+fn parse_topic_input(input: &str) -> (&str, Option<&str>) {
+    match input.split_once('|') {
+        Some((name, icon)) => (name.trim(), Some(icon.trim())),
+        None => (input.trim(), None),
+    }
+}
+
+fn too_large_message(size: u64, limit: u64) -> String {
+    format!(
+        "✗ Too large: {} exceeds the {} Bot API download limit",
+        format_file_size(size),
+        format_file_size(limit)
+    )
+}
+
+/// Builds a unique local file name from the stable file identifier and the server path.
+fn download_file_name(inspection: &FileInspection) -> String {
+    let id = inspection
+        .reference
+        .file_unique_id
+        .as_deref()
+        .unwrap_or(&inspection.reference.file_id);
+    let name = inspection
+        .reference
+        .file_name
+        .as_deref()
+        .or_else(|| {
+            inspection
+                .file_path
+                .as_deref()
+                .and_then(|path| path.rsplit('/').next())
+        })
+        .unwrap_or("file");
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || ".-_".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{id}_{name}")
+}
+
+/// Groups updates so that all items of an album end up in the same entry.
+///
+/// Updates without a `media_group_id` stay on their own. The position of each group is
+/// determined by the first update seen for it.
+pub fn group_by_media_group(updates: Vec<&Arc<Update>>) -> Vec<Vec<&Arc<Update>>> {
+    let mut groups: Vec<Vec<&Arc<Update>>> = Vec::new();
+    let mut album_positions: std::collections::HashMap<&str, usize> =
+        std::collections::HashMap::new();
+
+    for update in updates {
+        match update.media_group_id() {
+            Some(group_id) => {
+                if let Some(&position) = album_positions.get(group_id) {
+                    groups[position].push(update);
+                } else {
+                    album_positions.insert(group_id, groups.len());
+                    groups.push(vec![update]);
+                }
+            }
+            None => groups.push(vec![update]),
+        }
+    }
+
+    groups
 }
 
 impl Default for TelegramManager {
@@ -301,3 +1515,439 @@ impl Default for TelegramManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::ThroughputWindow;
+    use crate::telegram::MAX_DOWNLOAD_FILE_SIZE;
+    use crate::test_utils::create_test_message;
+
+    fn album_update(update_id: i64, message_id: i64, group_id: Option<&str>) -> Arc<Update> {
+        let mut message = create_test_message(100, message_id, 1000);
+        if let Some(group_id) = group_id {
+            message
+                .other
+                .insert("media_group_id".to_string(), JsonValue::from(group_id));
+        }
+        Arc::new(Update {
+            update_id,
+            message: Some(message),
+            channel_post: None,
+            edited_message: None,
+            other: std::collections::HashMap::new(),
+        })
+    }
+
+    #[test]
+    fn test_group_by_media_group_collapses_albums() {
+        let updates = [
+            album_update(1, 1, None),
+            album_update(2, 2, Some("a")),
+            album_update(3, 3, Some("a")),
+            album_update(4, 4, None),
+            album_update(5, 5, Some("a")),
+        ];
+
+        let groups = group_by_media_group(updates.iter().collect());
+        let sizes: Vec<usize> = groups.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![1, 3, 1]);
+        assert_eq!(groups[1][2].update_id, 5);
+    }
+
+    #[test]
+    fn test_selected_message_indexes_groups() {
+        let mut manager = TelegramManager::new();
+        manager.raw_updates = vec![
+            album_update(1, 1, Some("a")),
+            album_update(2, 2, Some("a")),
+            album_update(3, 3, None),
+        ];
+
+        assert_eq!(manager.get_message_groups_for_chat(100).len(), 2);
+        let selected = manager.get_selected_message_for_chat(Some(100), 1).unwrap();
+        assert_eq!(selected.update_id, 3);
+    }
+
+    #[test]
+    fn test_verbatim_json_follows_kept_updates() {
+        let mut manager = TelegramManager::new();
+        let mut monitor_messages = Vec::new();
+        for update_id in 1..=55 {
+            let response = GetUpdatesResponse {
+                ok: true,
+                result: vec![album_update(update_id, update_id, None).as_ref().clone()],
+                raw_result: vec![format!(r#"{{"update_id":{update_id},"x":1.0}}"#)],
+            };
+            manager.record_verbatim_updates(&response);
+            manager.process_updates_batch(response.result, &mut monitor_messages);
+        }
+
+        assert_eq!(manager.raw_json_updates.len(), 50);
+        assert_eq!(manager.verbatim_update(1), None);
+        assert_eq!(
+            manager.verbatim_update(55),
+            Some(r#"{"update_id":55,"x":1.0}"#)
+        );
+    }
+
+    #[test]
+    fn test_activity_counts_each_message_once() {
+        let mut manager = TelegramManager::new();
+        let mut monitor_messages = Vec::new();
+        let update = album_update(1, 1, None).as_ref().clone();
+        manager.process_updates_batch(vec![update.clone()], &mut monitor_messages);
+        manager.process_updates_batch(vec![update], &mut monitor_messages);
+
+        assert_eq!(manager.activity.total(), 1);
+        assert_eq!(manager.update_types.total(), 1);
+        assert!(manager.command_usage.is_empty());
+        assert_eq!(manager.engagement.users()[0].messages, 1);
+        assert_eq!(
+            manager.throughput.kinds(
+                ThroughputWindow::FifteenMinutes,
+                chrono::Utc::now().timestamp()
+            ),
+            vec![("message".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_outgoing_messages_interleave_and_keep_edits() {
+        let mut manager = TelegramManager::new();
+        manager.raw_updates = vec![album_update(1, 1, None)];
+        let mut later = create_test_message(100, 3, 1002);
+        later.text = Some("later question".to_string());
+        manager.raw_updates.push(Arc::new(Update {
+            update_id: 2,
+            message: Some(later),
+            channel_post: None,
+            edited_message: None,
+            other: std::collections::HashMap::new(),
+        }));
+
+        let mut answer = create_test_message(100, 2, 1001);
+        answer.text = Some("answer".to_string());
+        manager.record_outgoing_message(answer.clone());
+
+        let messages = manager.get_messages_for_chat(100);
+        let ids: Vec<Option<i64>> = messages.iter().map(|u| u.message_id()).collect();
+        assert_eq!(ids, vec![Some(1), Some(2), Some(3)]);
+        assert!(TelegramManager::is_outgoing(messages[1]));
+        assert!(!TelegramManager::is_outgoing(messages[0]));
+
+        // An edit replaces the outgoing message and keeps both revisions
+        answer.text = Some("better answer".to_string());
+        answer
+            .other
+            .insert("edit_date".to_string(), JsonValue::from(1010));
+        manager.record_outgoing_message(answer);
+        assert_eq!(manager.outgoing_updates.len(), 1);
+        assert_eq!(
+            manager.outgoing_updates[0].message_text(),
+            Some("better answer")
+        );
+        assert_eq!(manager.edit_count(100, 2), 1);
+        assert_eq!(manager.get_message_history(100, 2).unwrap().len(), 2);
+
+        manager.deleted_messages.insert((100, 2));
+        assert!(manager.is_deleted(100, 2));
+        assert!(!manager.is_deleted(100, 1));
+    }
+
+    #[tokio::test]
+    async fn test_inspect_files_reports_files_over_download_limit() {
+        let mut message = create_test_message(100, 1, 1000);
+        message.other.insert(
+            "video".to_string(),
+            serde_json::json!({"file_id": "big", "file_size": MAX_DOWNLOAD_FILE_SIZE + 1}),
+        );
+        let update = Arc::new(Update {
+            update_id: 1,
+            message: Some(message),
+            channel_post: None,
+            edited_message: None,
+            other: std::collections::HashMap::new(),
+        });
+
+        let manager = TelegramManager::new_with_token("test".to_string());
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let inspections = manager
+            .inspect_files(&[update], temp_dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(inspections.len(), 1);
+        assert_eq!(inspections[0].reference.location, "message.video");
+        assert!(inspections[0].outcome.contains("Too large"));
+        assert!(std::fs::read_dir(temp_dir.path()).unwrap().next().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_inspect_files_requires_client() {
+        let manager = TelegramManager::new();
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        assert!(manager.inspect_files(&[], temp_dir.path()).await.is_err());
+    }
+
+    #[test]
+    fn test_download_file_name() {
+        let inspection = FileInspection {
+            reference: FileReference {
+                location: "message.document".to_string(),
+                file_id: "BQAC".to_string(),
+                file_unique_id: Some("AgAD".to_string()),
+                file_size: Some(10),
+                mime_type: None,
+                file_name: Some("my report.pdf".to_string()),
+            },
+            file_path: Some("documents/file_3.pdf".to_string()),
+            file_size: Some(10),
+            mime_type: None,
+            outcome: String::new(),
+        };
+        assert_eq!(download_file_name(&inspection), "AgAD_my_report.pdf");
+
+        let mut photo = inspection.clone();
+        photo.reference.file_name = None;
+        photo.file_path = Some("photos/file_7.jpg".to_string());
+        assert_eq!(download_file_name(&photo), "AgAD_file_7.jpg");
+    }
+
+    #[test]
+    fn test_set_file_base_url_rebuilds_client() {
+        let mut manager = TelegramManager::new_with_token("tok".to_string());
+        manager.set_file_base_url(Some("http://localhost:8081/file".to_string()));
+
+        let client = manager.get_client().unwrap();
+        assert_eq!(client.get_token(), "tok");
+        assert_eq!(
+            client.file_url("a.jpg"),
+            "http://localhost:8081/file/bottok/a.jpg"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_message_action_validates_input() {
+        let mut manager = TelegramManager::new_with_token("test".to_string());
+
+        let result = manager
+            .apply_message_action(MessageAction::EditText, 100, &[1], "   ")
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.message.contains("cannot be empty"));
+
+        let result = manager
+            .apply_message_action(MessageAction::EditReplyMarkup, 100, &[1], "{not json")
+            .await
+            .unwrap();
+        assert!(result.message.contains("Invalid JSON"));
+
+        let result = manager
+            .apply_message_action(MessageAction::EditReplyMarkup, 100, &[1], r#"{"a": 1}"#)
+            .await
+            .unwrap();
+        assert!(result.message.contains("inline_keyboard"));
+
+        let result = manager
+            .apply_message_action(MessageAction::Forward, 100, &[1], "abc")
+            .await
+            .unwrap();
+        assert!(result.message.contains("Invalid chat ID"));
+
+        let result = manager
+            .apply_message_action(MessageAction::Delete, 100, &[], "")
+            .await
+            .unwrap();
+        assert!(result.message.contains("No message selected"));
+    }
+
+    #[tokio::test]
+    async fn test_apply_message_action_without_client() {
+        let mut manager = TelegramManager::new();
+        let result = manager
+            .apply_message_action(MessageAction::Delete, 100, &[1], "")
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.message.contains("No client"));
+    }
+
+    #[test]
+    fn test_api_outcome() {
+        assert_eq!(
+            api_outcome(true, None, "Done".to_string()).message,
+            "✓ Done"
+        );
+        let failed = api_outcome(false, Some("Bad Request".to_string()), "Done".to_string());
+        assert!(!failed.success);
+        assert_eq!(failed.message, "✗ Error: Bad Request");
+    }
+
+    fn inspection(chat: serde_json::Value, bot: serde_json::Value) -> ChatInspection {
+        ChatInspection {
+            chat_id: -1001,
+            chat: Ok(serde_json::from_value(chat).unwrap()),
+            administrators: Ok(vec![]),
+            member_count: Ok(10),
+            bot_member: Ok(serde_json::from_value(bot).unwrap()),
+        }
+    }
+
+    fn bot_json(status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "status": status,
+            "user": {"id": 1, "is_bot": true, "first_name": "Bot"}
+        })
+    }
+
+    #[test]
+    fn test_chat_inspection_notes_for_group_member() {
+        let notes = inspection(
+            serde_json::json!({"id": -1001, "type": "supergroup", "is_forum": true}),
+            bot_json("member"),
+        )
+        .notes();
+
+        assert!(
+            notes
+                .iter()
+                .any(|n| n.starts_with('⚠') && n.contains("privacy mode"))
+        );
+        assert!(notes.iter().any(|n| n.contains("can_manage_topics")));
+    }
+
+    #[test]
+    fn test_chat_inspection_notes_for_admin_without_delete_right() {
+        let mut bot = bot_json("administrator");
+        bot["can_delete_messages"] = serde_json::json!(false);
+        bot["can_manage_topics"] = serde_json::json!(true);
+        let notes = inspection(
+            serde_json::json!({"id": -1001, "type": "supergroup", "slow_mode_delay": 30}),
+            bot,
+        )
+        .notes();
+
+        assert!(
+            notes
+                .iter()
+                .any(|n| n.starts_with('✓') && n.contains("administrator"))
+        );
+        assert!(notes.iter().any(|n| n.contains("can_delete_messages")));
+        assert!(
+            notes
+                .iter()
+                .any(|n| n.contains("Slow mode") && n.starts_with('✓'))
+        );
+    }
+
+    #[test]
+    fn test_chat_inspection_notes_for_removed_bot_and_channel() {
+        let notes = inspection(
+            serde_json::json!({"id": -1001, "type": "supergroup"}),
+            bot_json("kicked"),
+        )
+        .notes();
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].starts_with('✗'));
+
+        let notes = inspection(
+            serde_json::json!({"id": -1001, "type": "channel"}),
+            bot_json("member"),
+        )
+        .notes();
+        assert!(notes[0].contains("channel posts are not delivered"));
+    }
+
+    #[test]
+    fn test_chat_inspection_notes_with_unknown_membership() {
+        let mut inspection = inspection(
+            serde_json::json!({"id": 5, "type": "private"}),
+            bot_json("member"),
+        );
+        inspection.bot_member = Err("Bad Request: chat not found (400)".to_string());
+        let notes = inspection.notes();
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].contains("chat not found"));
+    }
+
+    #[tokio::test]
+    async fn test_commands_require_client() {
+        let manager = TelegramManager::new();
+        let mut matrix = CommandMatrix::new();
+        assert!(manager.load_commands(&mut matrix).await.is_err());
+
+        let scope = crate::telegram::BotCommandScope::Default;
+        let start = crate::telegram::BotCommand {
+            command: "start".to_string(),
+            description: "Start".to_string(),
+        };
+        matrix.set_loaded(&scope, "", vec![start]);
+        matrix.set_draft(&scope, "", Vec::new());
+        let result = manager.apply_commands(&matrix.pending_changes()).await;
+        assert!(!result.success);
+        assert!(result.message.contains("No client"));
+    }
+
+    #[tokio::test]
+    async fn test_apply_profile_edit_validates_before_sending() {
+        let manager = TelegramManager::new_with_token("test".to_string());
+
+        let row = ProfileRow::Text {
+            field: ProfileField::Name,
+            language_code: String::new(),
+        };
+        let result = manager.apply_profile_edit(&row, &"x".repeat(65)).await;
+        assert!(!result.success);
+        assert!(result.message.contains("too long"));
+
+        let result = manager
+            .apply_profile_edit(&ProfileRow::MenuButton, "web_app Open http://insecure")
+            .await;
+        assert!(result.message.contains("https://"));
+
+        let rights = ProfileRow::AdministratorRights { for_channels: true };
+        let result = manager.apply_profile_edit(&rights, "can_fly").await;
+        assert!(result.message.contains("Unknown administrator right"));
+    }
+
+    #[test]
+    fn test_parse_topic_input() {
+        assert_eq!(parse_topic_input(" Test topic "), ("Test topic", None));
+        assert_eq!(parse_topic_input("Bugs | 5312"), ("Bugs", Some("5312")));
+        assert_eq!(parse_topic_input("| "), ("", Some("")));
+    }
+
+    #[tokio::test]
+    async fn test_apply_topic_action_rejects_invalid_input() {
+        let mut manager = TelegramManager::new_with_token("test".to_string());
+
+        let result = manager
+            .apply_topic_action(TopicAction::Create, -100, None, "  ")
+            .await
+            .unwrap();
+        assert!(result.message.contains("cannot be empty"));
+
+        let long_name = "x".repeat(MAX_TOPIC_NAME_LENGTH + 1);
+        let result = manager
+            .apply_topic_action(TopicAction::Create, -100, None, &long_name)
+            .await
+            .unwrap();
+        assert!(result.message.contains("Topic name too long"));
+
+        let long_text = "x".repeat(MAX_MESSAGE_LENGTH + 1);
+        let result = manager
+            .apply_topic_action(TopicAction::Send, -100, Some(5), &long_text)
+            .await
+            .unwrap();
+        assert!(result.message.contains("Message too long"));
+
+        let result = manager
+            .apply_topic_action(TopicAction::Delete, -100, None, "")
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.message.contains("General topic cannot be deleted"));
+    }
+}
@@ -21,8 +21,9 @@ pub enum KeyAction {
 /// Handles global navigation keys that work across all screens.
 ///
 /// These keys provide consistent navigation regardless of the current screen:
-/// - Number keys 1-4 for screen switching
+/// - Number keys 1-9 for screen switching
 /// - 'm' to open test message screen (send messages to any chat ID)
+/// - 'g' to open the album composer (send media groups)
 /// - 'q' to go back home
 /// - 'h' for help
 /// - F5 to toggle monitoring
@@ -67,10 +68,30 @@ pub async fn try_handle_global_keys(
             app.switch_screen(Screen::WebhookManagement);
             Ok(KeyAction::Handled)
         }
+        KeyCode::Char('6') => {
+            app.switch_screen(Screen::Commands);
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('7') => {
+            app.switch_screen(Screen::BotProfile);
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('8') => {
+            app.switch_screen(Screen::Users);
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('9') => {
+            app.switch_screen(Screen::SchemaDrift);
+            Ok(KeyAction::Handled)
+        }
         KeyCode::Char('m') | KeyCode::Char('M') => {
             app.switch_screen(Screen::TestMessage);
             Ok(KeyAction::Handled)
         }
+        KeyCode::Char('g') | KeyCode::Char('G') => {
+            app.switch_screen(Screen::MediaGroup);
+            Ok(KeyAction::Handled)
+        }
         KeyCode::F(5) => {
             app.toggle_monitoring().await;
             Ok(KeyAction::Handled)
@@ -133,6 +154,14 @@ pub fn try_handle_raw_json_keys(app: &mut App, key: KeyCode) -> Result<KeyAction
             app.export_selected_update()?;
             Ok(KeyAction::Handled)
         }
+        KeyCode::Char('v') | KeyCode::Char('V') => {
+            app.toggle_raw_json_view();
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('a') | KeyCode::Char('A') => {
+            app.toggle_raw_json_source();
+            Ok(KeyAction::Handled)
+        }
         _ => Ok(KeyAction::NotHandled),
     }
 }
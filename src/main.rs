@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
     execute,
@@ -9,14 +9,33 @@ use std::io;
 use std::time::Duration;
 use tokio::time::sleep;
 
-use telegram_bot_debugger::app::{App, Screen};
+use telegram_bot_debugger::CacheManager;
+use telegram_bot_debugger::app::{
+    AnalyticsTab, App, ChatTab, CommandsInput, MessageAction, MessagePanel, ProfileInput, Screen,
+    TopicAction,
+};
 use telegram_bot_debugger::input::{
     KeyAction, try_handle_global_keys, try_handle_raw_json_keys, try_handle_webhook_keys,
 };
+use telegram_bot_debugger::telegram::TelegramClient;
+use telegram_bot_debugger::telegram::drift::{SchemaDrift, parse_updates};
 use telegram_bot_debugger::ui::render_frame;
 
+const USAGE: &str = "Usage: telegram-bot-debugger [--schema-drift [FILE...]]";
+
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(flag) = args.first() {
+        return match flag.as_str() {
+            "--schema-drift" => print_schema_drift(&args[1..]).await,
+            _ => {
+                eprintln!("{USAGE}");
+                std::process::exit(2);
+            }
+        };
+    }
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -46,6 +65,46 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Prints the fields unknown to the targeted Bot API version.
+///
+/// Checks the updates in the given JSON files (single updates, arrays or
+/// `getUpdates` responses), or the pending updates of the saved bot token.
+/// Pending updates are only peeked at, not confirmed. Exits with status 1 if
+/// unknown fields were found.
+async fn print_schema_drift(files: &[String]) -> Result<()> {
+    let updates = if files.is_empty() {
+        let token = CacheManager::new()
+            .load_token()?
+            .context("No saved bot token; run the TUI once or pass exported update files")?;
+        TelegramClient::new(token)
+            .get_updates(None, None)
+            .await?
+            .result
+    } else {
+        let mut updates = Vec::new();
+        for file in files {
+            let json =
+                std::fs::read_to_string(file).with_context(|| format!("Failed to read {file}"))?;
+            let value =
+                serde_json::from_str(&json).with_context(|| format!("Invalid JSON in {file}"))?;
+            updates.extend(parse_updates(value).with_context(|| format!("No updates in {file}"))?);
+        }
+        updates
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    let mut drift = SchemaDrift::new();
+    for update in &updates {
+        drift.record(update, now);
+    }
+    println!("Checked {} update(s).", updates.len());
+    print!("{}", drift.report());
+    if !drift.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 /// Main application event loop.
 ///
 /// Handles rendering, input processing, and background task coordination.
@@ -73,7 +132,14 @@ async fn run_app<B: ratatui::backend::Backend>(
                 Screen::Monitor => handle_monitor(app, key.code).await?,
                 Screen::Discovery => handle_discovery(app, key.code, key.modifiers).await?,
                 Screen::Messages => handle_messages(app, key.code, key.modifiers).await?,
-                _ => KeyAction::NotHandled, // Home, Help, Analytics, RawJson fall through
+                Screen::MediaGroup => handle_media_group(app, key.code).await?,
+                Screen::Home => handle_home(app, key.code).await?,
+                Screen::Commands => handle_commands(app, key.code).await?,
+                Screen::BotProfile => handle_bot_profile(app, key.code).await?,
+                Screen::Users => handle_users(app, key.code)?,
+                Screen::SchemaDrift => handle_schema_drift(app, key.code)?,
+                Screen::Analytics => handle_analytics(app, key.code).await?,
+                _ => KeyAction::NotHandled, // Help, RawJson fall through
             };
 
             // If not handled by screen-specific handler, try global keys
@@ -114,7 +180,7 @@ async fn run_app<B: ratatui::backend::Backend>(
         app.stop_monitoring().await;
     }
 
-    Ok(())
+    app.save_activity()
 }
 
 /// Handles input on the token input screen.
@@ -177,6 +243,14 @@ async fn handle_test_message(
             app.toggle_test_message_mode();
             Ok(KeyAction::Handled)
         }
+        KeyCode::Up if app.ui.test_message_mode == TestMessageMode::SelectedChat => {
+            app.previous_test_message_topic();
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Down if app.ui.test_message_mode == TestMessageMode::SelectedChat => {
+            app.next_test_message_topic();
+            Ok(KeyAction::Handled)
+        }
         KeyCode::Char(c) => {
             // Insert character into the currently focused field
             match app.ui.test_message_mode {
@@ -216,6 +290,213 @@ async fn handle_test_message(
     }
 }
 
+/// Handles input on the home screen.
+///
+/// 'd' runs the permission and privacy-mode diagnostics, 'p' loads the bot profile.
+/// Global keys handled by common handler.
+async fn handle_home(app: &mut App, key: KeyCode) -> Result<KeyAction> {
+    match key {
+        KeyCode::Char('d') | KeyCode::Char('D') => {
+            app.run_diagnostics().await?;
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('p') | KeyCode::Char('P') => {
+            app.load_profile().await?;
+            Ok(KeyAction::Handled)
+        }
+        _ => Ok(KeyAction::NotHandled),
+    }
+}
+
+/// Handles input on the bot commands screen.
+///
+/// Arrows select a matrix cell, 'r' loads all lists, Enter edits the selected cell,
+/// 'a' applies the drafts after confirmation. Global keys handled by common handler.
+async fn handle_commands(app: &mut App, key: KeyCode) -> Result<KeyAction> {
+    if let Some(mode) = app.ui.commands_input_mode {
+        return handle_commands_input(app, mode, key).await;
+    }
+
+    match key {
+        KeyCode::Up => app.ui.move_commands_selection(-1, 0),
+        KeyCode::Down => app.ui.move_commands_selection(1, 0),
+        KeyCode::Left => app.ui.move_commands_selection(0, -1),
+        KeyCode::Right => app.ui.move_commands_selection(0, 1),
+        KeyCode::Char('r') | KeyCode::Char('R') => app.load_commands().await?,
+        KeyCode::Enter => app.start_commands_input(CommandsInput::Edit),
+        KeyCode::Char('l') | KeyCode::Char('L') => {
+            app.start_commands_input(CommandsInput::AddLanguage)
+        }
+        KeyCode::Char('i') | KeyCode::Char('I') => app.start_commands_input(CommandsInput::Import),
+        KeyCode::Char('a') | KeyCode::Char('A') => {
+            app.start_commands_input(CommandsInput::ConfirmApply)
+        }
+        KeyCode::Char('u') | KeyCode::Char('U') => app.discard_command_drafts(),
+        KeyCode::Char('x') | KeyCode::Char('X') => app.export_commands()?,
+        _ => return Ok(KeyAction::NotHandled),
+    }
+    Ok(KeyAction::Handled)
+}
+
+/// Handles input while a prompt on the bot commands screen is open.
+///
+/// Consumes every key so typed characters don't trigger global shortcuts.
+async fn handle_commands_input(
+    app: &mut App,
+    mode: CommandsInput,
+    key: KeyCode,
+) -> Result<KeyAction> {
+    let confirm = mode == CommandsInput::ConfirmApply;
+    match key {
+        KeyCode::Esc => app.cancel_commands_input(),
+        KeyCode::Char('n') | KeyCode::Char('N') if confirm => app.cancel_commands_input(),
+        KeyCode::Char('y') | KeyCode::Char('Y') if confirm => app.confirm_commands_input().await?,
+        KeyCode::Enter if !confirm => app.confirm_commands_input().await?,
+        KeyCode::Backspace if !confirm => {
+            app.ui.commands_input.pop();
+            app.mark_dirty();
+        }
+        KeyCode::Char(c) if !confirm => {
+            app.ui.commands_input.push(c);
+            app.mark_dirty();
+        }
+        _ => {}
+    }
+    Ok(KeyAction::Handled)
+}
+
+/// Handles input on the Users screen.
+///
+/// While searching, all keys edit the search; Enter keeps it and Esc clears it.
+fn handle_users(app: &mut App, key: KeyCode) -> Result<KeyAction> {
+    if app.ui.users_search_active {
+        match key {
+            KeyCode::Esc => {
+                app.ui.users_search_active = false;
+                app.edit_users_search(String::clear);
+            }
+            KeyCode::Enter => app.ui.users_search_active = false,
+            KeyCode::Backspace => app.edit_users_search(|search| {
+                search.pop();
+            }),
+            KeyCode::Char(c) => app.edit_users_search(|search| search.push(c)),
+            _ => {}
+        }
+        return Ok(KeyAction::Handled);
+    }
+
+    match key {
+        KeyCode::Up => app.previous_user(),
+        KeyCode::Down => app.next_user(),
+        KeyCode::Char('/') => app.ui.users_search_active = true,
+        KeyCode::Char('e') | KeyCode::Char('E') => app.export_users()?,
+        _ => return Ok(KeyAction::NotHandled),
+    }
+    Ok(KeyAction::Handled)
+}
+
+/// Handles input on the analytics screen: Tab switches tabs, 'w' cycles the
+/// throughput window. On the Commands tab ↑/↓ select a command and 'r' loads
+/// the registered commands. On the Engagement tab 'e' exports CSV.
+async fn handle_analytics(app: &mut App, key: KeyCode) -> Result<KeyAction> {
+    let on_commands = app.ui.analytics_tab == AnalyticsTab::Commands;
+    match key {
+        KeyCode::Tab => app.toggle_analytics_tab(),
+        KeyCode::Char('w') | KeyCode::Char('W') => app.next_throughput_window(),
+        KeyCode::Up if on_commands => app.previous_analytics_command(),
+        KeyCode::Down if on_commands => app.next_analytics_command(),
+        KeyCode::Char('r') | KeyCode::Char('R') if on_commands => {
+            app.load_registered_commands().await?
+        }
+        KeyCode::Char('e') | KeyCode::Char('E')
+            if app.ui.analytics_tab == AnalyticsTab::Engagement =>
+        {
+            app.export_engagement()?
+        }
+        _ => return Ok(KeyAction::NotHandled),
+    }
+    Ok(KeyAction::Handled)
+}
+
+/// Handles input on the schema drift screen.
+fn handle_schema_drift(app: &mut App, key: KeyCode) -> Result<KeyAction> {
+    match key {
+        KeyCode::Up => app.previous_drift_entry(),
+        KeyCode::Down => app.next_drift_entry(),
+        KeyCode::Char('e') | KeyCode::Char('E') => app.export_schema_drift()?,
+        _ => return Ok(KeyAction::NotHandled),
+    }
+    Ok(KeyAction::Handled)
+}
+
+/// Handles input on the bot profile screen.
+///
+/// 'r' loads all values, Enter edits the selected row, 'l' adds a language.
+/// While a prompt is open every key is consumed.
+async fn handle_bot_profile(app: &mut App, key: KeyCode) -> Result<KeyAction> {
+    if app.ui.profile_input_mode.is_some() {
+        match key {
+            KeyCode::Esc => app.cancel_profile_input(),
+            KeyCode::Enter => app.confirm_profile_input().await?,
+            KeyCode::Backspace => {
+                app.ui.profile_input.pop();
+                app.mark_dirty();
+            }
+            KeyCode::Char(c) => {
+                app.ui.profile_input.push(c);
+                app.mark_dirty();
+            }
+            _ => {}
+        }
+        return Ok(KeyAction::Handled);
+    }
+
+    match key {
+        KeyCode::Up => app.previous_profile_row(),
+        KeyCode::Down => app.next_profile_row(),
+        KeyCode::Char('r') | KeyCode::Char('R') => app.load_profile().await?,
+        KeyCode::Enter => app.start_profile_input(ProfileInput::Edit),
+        KeyCode::Char('l') | KeyCode::Char('L') => {
+            app.start_profile_input(ProfileInput::AddLanguage)
+        }
+        _ => return Ok(KeyAction::NotHandled),
+    }
+    Ok(KeyAction::Handled)
+}
+
+/// Handles input on the album composer screen.
+///
+/// Enter adds the typed item, or sends the album when the input is empty.
+async fn handle_media_group(app: &mut App, key: KeyCode) -> Result<KeyAction> {
+    match key {
+        KeyCode::Enter => {
+            if app.ui.media_group_input.trim().is_empty() {
+                app.send_media_group().await?;
+            } else {
+                app.add_media_group_item();
+            }
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Tab => {
+            app.ui.media_group_kind = app.ui.media_group_kind.next();
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Delete => {
+            app.remove_last_media_group_item();
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char(c) => {
+            app.ui.media_group_input.push(c);
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Backspace => {
+            app.ui.media_group_input.pop();
+            Ok(KeyAction::Handled)
+        }
+        _ => Ok(KeyAction::NotHandled), // Esc handled by global handler
+    }
+}
+
 /// Handles input on the monitor screen (live updates view).
 ///
 /// Handles monitor-specific keys (m, p, c), delegates global keys to common handler.
@@ -258,6 +539,9 @@ async fn handle_discovery(
         KeyCode::Enter => {
             // Navigate to messages screen
             app.ui.selected_message_index = 0; // Reset message index
+            app.ui.selected_topic_index = 0;
+            app.ui.test_message_topic_index = 0;
+            app.ui.chat_tab = ChatTab::Messages;
             app.switch_screen(Screen::Messages); // This calls mark_dirty internally
             Ok(KeyAction::Handled)
         }
@@ -277,6 +561,32 @@ async fn handle_messages(
     key: KeyCode,
     _modifiers: KeyModifiers,
 ) -> Result<KeyAction> {
+    if let Some(action) = app.ui.message_action {
+        return handle_message_action(app, action, key).await;
+    }
+    if let Some(action) = app.ui.topic_action {
+        return handle_topic_action(app, action, key).await;
+    }
+
+    if key == KeyCode::Tab {
+        app.toggle_chat_tab().await?;
+        return Ok(KeyAction::Handled);
+    }
+
+    if app.ui.chat_tab == ChatTab::Details {
+        return match key {
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                app.refresh_chat_inspection().await?;
+                Ok(KeyAction::Handled)
+            }
+            _ => Ok(KeyAction::NotHandled),
+        };
+    }
+
+    if app.ui.chat_tab == ChatTab::Topics {
+        return handle_topics(app, key).await;
+    }
+
     match key {
         KeyCode::Up => {
             app.previous_message();
@@ -286,10 +596,135 @@ async fn handle_messages(
             app.next_message();
             Ok(KeyAction::Handled)
         }
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            app.start_message_action(MessageAction::EditText);
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('k') | KeyCode::Char('K') => {
+            app.start_message_action(MessageAction::EditReplyMarkup);
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('w') | KeyCode::Char('W') => {
+            app.start_message_action(MessageAction::Forward);
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            app.start_message_action(MessageAction::Copy);
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Delete => {
+            app.start_message_action(MessageAction::Delete);
+            Ok(KeyAction::Handled)
+        }
         KeyCode::Char('e') | KeyCode::Char('E') => {
             app.export_selected_message()?;
             Ok(KeyAction::Handled)
         }
+        KeyCode::Char('f') | KeyCode::Char('F') => {
+            app.inspect_selected_message_files().await?;
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('v') | KeyCode::Char('V') => {
+            app.toggle_message_panel(MessagePanel::EditHistory);
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.toggle_message_panel(MessagePanel::Conversation);
+            Ok(KeyAction::Handled)
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') => {
+            app.toggle_message_panel(MessagePanel::Entities);
+            Ok(KeyAction::Handled)
+        }
         _ => Ok(KeyAction::NotHandled), // Let global handler process navigation keys (including Esc)
     }
 }
+
+/// Handles the Topics tab of a forum supergroup.
+///
+/// Hiding and showing apply to the General topic only.
+async fn handle_topics(app: &mut App, key: KeyCode) -> Result<KeyAction> {
+    let action = match key {
+        KeyCode::Up => {
+            app.previous_topic();
+            return Ok(KeyAction::Handled);
+        }
+        KeyCode::Down => {
+            app.next_topic();
+            return Ok(KeyAction::Handled);
+        }
+        KeyCode::Char('i') | KeyCode::Char('I') => {
+            app.load_topic_icon_stickers().await?;
+            return Ok(KeyAction::Handled);
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') => TopicAction::Create,
+        KeyCode::Char('e') | KeyCode::Char('E') => TopicAction::Edit,
+        KeyCode::Char('s') | KeyCode::Char('S') => TopicAction::Send,
+        KeyCode::Char('c') | KeyCode::Char('C') => TopicAction::Close,
+        KeyCode::Char('o') | KeyCode::Char('O') => TopicAction::Reopen,
+        KeyCode::Char('d') | KeyCode::Char('D') => TopicAction::Delete,
+        KeyCode::Char('x') | KeyCode::Char('X') => TopicAction::Hide,
+        KeyCode::Char('u') | KeyCode::Char('U') => TopicAction::Unhide,
+        _ => return Ok(KeyAction::NotHandled),
+    };
+    app.start_topic_action(action);
+    Ok(KeyAction::Handled)
+}
+
+/// Handles the prompt of a pending topic action.
+///
+/// Text actions take input until Enter; the others wait for y/n.
+async fn handle_topic_action(
+    app: &mut App,
+    action: TopicAction,
+    key: KeyCode,
+) -> Result<KeyAction> {
+    let confirm = !action.needs_input();
+    match key {
+        KeyCode::Esc => app.cancel_topic_action(),
+        KeyCode::Char('n') | KeyCode::Char('N') if confirm => app.cancel_topic_action(),
+        KeyCode::Char('y') | KeyCode::Char('Y') if confirm => app.confirm_topic_action().await?,
+        KeyCode::Enter if !confirm => app.confirm_topic_action().await?,
+        KeyCode::Backspace if !confirm => {
+            app.ui.topic_action_input.pop();
+            app.mark_dirty();
+        }
+        KeyCode::Char(c) if !confirm => {
+            app.ui.topic_action_input.push(c);
+            app.mark_dirty();
+        }
+        _ => {}
+    }
+    Ok(KeyAction::Handled)
+}
+
+/// Handles input while an action on the selected message is pending.
+///
+/// Destructive actions only accept y/n; the others edit a single-line input.
+/// All keys are consumed so that typing does not trigger global shortcuts.
+async fn handle_message_action(
+    app: &mut App,
+    action: MessageAction,
+    key: KeyCode,
+) -> Result<KeyAction> {
+    match key {
+        KeyCode::Esc => app.cancel_message_action(),
+        KeyCode::Char('n') | KeyCode::Char('N') if action.is_destructive() => {
+            app.cancel_message_action()
+        }
+        KeyCode::Char('y') | KeyCode::Char('Y') if action.is_destructive() => {
+            app.confirm_message_action().await?
+        }
+        KeyCode::Enter if !action.is_destructive() => app.confirm_message_action().await?,
+        KeyCode::Backspace if !action.is_destructive() => {
+            app.ui.message_action_input.pop();
+            app.mark_dirty();
+        }
+        KeyCode::Char(c) if !action.is_destructive() => {
+            app.ui.message_action_input.push(c);
+            app.mark_dirty();
+        }
+        _ => {}
+    }
+    Ok(KeyAction::Handled)
+}
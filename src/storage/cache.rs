@@ -1,8 +1,9 @@
 use anyhow::{Context, Result};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use super::models::CacheData;
+use super::models::{AnalyticsData, CacheData};
 
 /// Manages persistent storage of application data.
 ///
@@ -110,6 +111,10 @@ impl CacheManager {
     /// Returns an error if loading or saving fails.
     pub fn save_token(&self, token: String) -> Result<()> {
         let mut data = self.load()?;
+        if data.token.as_ref() != Some(&token) {
+            // Update IDs belong to one bot
+            data.analytics.last_update_id = None;
+        }
         data.token = Some(token);
         self.save(&data)
     }
@@ -128,6 +133,43 @@ impl CacheManager {
         Ok(data.token)
     }
 
+    /// Loads the configured file download endpoint, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache file cannot be read or parsed.
+    pub fn load_file_base_url(&self) -> Result<Option<String>> {
+        let data = self.load()?;
+        Ok(data.file_base_url)
+    }
+
+    /// Loads the persisted analytics data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache file cannot be read or parsed.
+    pub fn load_analytics(&self) -> Result<AnalyticsData> {
+        let data = self.load()?;
+        Ok(data.analytics)
+    }
+
+    /// Saves the hourly message counts of the activity timeline together with
+    /// the ID of the last update counted, keeping the rest of the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if loading or saving fails.
+    pub fn save_activity(
+        &self,
+        distribution: HashMap<i64, usize>,
+        last_update_id: i64,
+    ) -> Result<()> {
+        let mut data = self.load()?;
+        data.analytics.hourly_distribution = distribution;
+        data.analytics.last_update_id = Some(last_update_id).filter(|id| *id > 0);
+        self.save(&data)
+    }
+
     /// Deletes the cache file from disk.
     ///
     /// Useful for testing or resetting the application state.
@@ -243,12 +285,17 @@ mod tests {
                 topics: vec![],
             }],
             analytics: Default::default(),
+            file_base_url: Some("http://localhost:8081/file".to_string()),
         };
 
         manager.save(&data).unwrap();
         let loaded = manager.load().unwrap();
 
         assert_eq!(loaded.token, Some("test_token".to_string()));
+        assert_eq!(
+            manager.load_file_base_url().unwrap().as_deref(),
+            Some("http://localhost:8081/file")
+        );
         assert_eq!(loaded.chats.len(), 1);
         assert_eq!(loaded.chats[0].chat_id, 100);
         assert_eq!(loaded.chats[0].message_count, 5);
@@ -271,6 +318,39 @@ mod tests {
         assert_eq!(second, Some("token2".to_string()));
     }
 
+    #[test]
+    fn test_save_activity_keeps_token_and_timezone() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_path = temp_dir.path().join("cache.json");
+        let manager = CacheManager::with_path(&cache_path);
+
+        let data = CacheData {
+            token: Some("token".to_string()),
+            analytics: AnalyticsData {
+                timezone: Some("+02:00".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        manager.save(&data).unwrap();
+
+        manager
+            .save_activity(HashMap::from([(1609459200, 3)]), 42)
+            .unwrap();
+
+        let analytics = manager.load_analytics().unwrap();
+        assert_eq!(analytics.hourly_distribution[&1609459200], 3);
+        assert_eq!(analytics.last_update_id, Some(42));
+        assert_eq!(analytics.timezone.as_deref(), Some("+02:00"));
+        assert_eq!(manager.load_token().unwrap().as_deref(), Some("token"));
+
+        // The same token keeps the update ID, another bot's token clears it
+        manager.save_token("token".to_string()).unwrap();
+        assert_eq!(manager.load_analytics().unwrap().last_update_id, Some(42));
+        manager.save_token("other".to_string()).unwrap();
+        assert_eq!(manager.load_analytics().unwrap().last_update_id, None);
+    }
+
     #[test]
     fn test_load_token_from_empty_cache() {
         let temp_dir = TempDir::new().unwrap();
@@ -16,6 +16,9 @@ pub struct CacheData {
     pub chats: Vec<CachedChat>,
     /// Aggregated analytics data
     pub analytics: AnalyticsData,
+    /// File endpoint of a self-hosted Bot API server (e.g. `http://localhost:8081/file`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_base_url: Option<String>,
 }
 
 /// Serializable representation of a discovered chat for caching.
@@ -59,8 +62,17 @@ pub struct AnalyticsData {
     pub total_messages: usize,
     /// Message count per chat ID
     pub messages_per_chat: HashMap<i64, usize>,
-    /// Message distribution by hour of day (0-23)
+    /// Messages per hour, keyed by the Unix time the UTC hour starts at
+    /// (see [`ActivityTimeline`](crate::analytics::ActivityTimeline))
     pub hourly_distribution: HashMap<i64, usize>,
+    /// Timezone of the activity charts: `local`, `UTC` or an offset like `+02:00`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// ID of the last update counted into `hourly_distribution`; monitoring
+    /// resumes after it so re-delivered updates aren't counted twice.
+    /// Cleared when a different token is saved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_update_id: Option<i64>,
 }
 
 impl AnalyticsData {
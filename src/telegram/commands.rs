@@ -0,0 +1,669 @@
+//! Bot command lists per scope and language.
+//!
+//! Telegram stores a separate command list for every (scope, language) pair
+//! and shows a user the first non-empty list along the scope's fallback chain.
+//! [`CommandMatrix`] mirrors that storage, keeps local drafts and computes
+//! which list a user would actually see.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+
+use super::types::{BotCommand, BotCommandScope};
+
+/// Maximum number of commands per list.
+pub const MAX_COMMANDS: usize = 100;
+
+/// Maximum length of a command name.
+pub const MAX_COMMAND_LENGTH: usize = 32;
+
+/// Maximum length of a command description.
+pub const MAX_DESCRIPTION_LENGTH: usize = 256;
+
+/// One stored command list, as used in JSON import/export files.
+///
+/// An empty `language_code` stands for "all languages".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandSetEntry {
+    pub scope: BotCommandScope,
+    #[serde(default)]
+    pub language_code: String,
+    pub commands: Vec<BotCommand>,
+}
+
+/// The complete command configuration of a bot.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandSet {
+    pub entries: Vec<CommandSetEntry>,
+}
+
+/// Difference between two versions of a command list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandChange {
+    Added(BotCommand),
+    Removed(BotCommand),
+    /// Same command name, new description
+    Changed {
+        command: String,
+        old: String,
+        new: String,
+    },
+}
+
+impl std::fmt::Display for CommandChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandChange::Added(c) => write!(f, "+ /{} - {}", c.command, c.description),
+            CommandChange::Removed(c) => write!(f, "- /{} - {}", c.command, c.description),
+            CommandChange::Changed { command, old, new } => {
+                write!(f, "~ /{command}: {old} → {new}")
+            }
+        }
+    }
+}
+
+/// A draft that differs from the list currently stored in Telegram.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingCommandChange {
+    pub scope: BotCommandScope,
+    pub language_code: String,
+    /// New list; empty means `deleteMyCommands`
+    pub commands: Vec<BotCommand>,
+    pub changes: Vec<CommandChange>,
+}
+
+/// The list a user sees for a (scope, language) pair and where it comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectiveCommands<'a> {
+    pub scope: BotCommandScope,
+    pub language_code: String,
+    pub commands: &'a [BotCommand],
+}
+
+/// Validates a command list against the Bot API limits.
+///
+/// # Errors
+///
+/// Returns an error naming the first invalid command.
+pub fn validate_commands(commands: &[BotCommand]) -> Result<()> {
+    if commands.len() > MAX_COMMANDS {
+        bail!(
+            "At most {MAX_COMMANDS} commands allowed, got {}",
+            commands.len()
+        );
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    for command in commands {
+        let name = &command.command;
+        if name.is_empty() || name.len() > MAX_COMMAND_LENGTH {
+            bail!("/{name}: command must be 1-{MAX_COMMAND_LENGTH} characters");
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            bail!("/{name}: only lowercase letters, digits and underscores allowed");
+        }
+        let description_length = command.description.chars().count();
+        if description_length == 0 || description_length > MAX_DESCRIPTION_LENGTH {
+            bail!("/{name}: description must be 1-{MAX_DESCRIPTION_LENGTH} characters");
+        }
+        if !seen.insert(name.as_str()) {
+            bail!("/{name}: duplicate command");
+        }
+    }
+    Ok(())
+}
+
+/// Parses the editor format `start - Start the bot; help - Show help`.
+///
+/// Entries are separated by `;` or newlines, like the list sent to @BotFather.
+/// A leading `/` is ignored.
+///
+/// # Errors
+///
+/// Returns an error if an entry has no ` - ` separator or the list is invalid.
+///
+/// # Examples
+///
+/// ```
+/// # use telegram_bot_debugger::telegram::commands::parse_commands_text;
+/// let commands = parse_commands_text("/start - Start the bot; help - Show help").unwrap();
+/// assert_eq!(commands[0].command, "start");
+/// assert_eq!(commands[1].description, "Show help");
+/// ```
+pub fn parse_commands_text(text: &str) -> Result<Vec<BotCommand>> {
+    let mut commands = Vec::new();
+    for entry in text
+        .split([';', '\n'])
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+    {
+        let Some((command, description)) = entry.split_once(" - ") else {
+            bail!("\"{entry}\": expected \"command - description\"");
+        };
+        commands.push(BotCommand {
+            command: command.trim().trim_start_matches('/').to_string(),
+            description: description.trim().to_string(),
+        });
+    }
+    validate_commands(&commands)?;
+    Ok(commands)
+}
+
+/// Formats a command list in the editor format read by [`parse_commands_text`].
+pub fn format_commands_text(commands: &[BotCommand]) -> String {
+    commands
+        .iter()
+        .map(|c| format!("{} - {}", c.command, c.description))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Compares two command lists by command name.
+pub fn diff_commands(current: &[BotCommand], desired: &[BotCommand]) -> Vec<CommandChange> {
+    let mut changes = Vec::new();
+    for old in current {
+        match desired.iter().find(|c| c.command == old.command) {
+            None => changes.push(CommandChange::Removed(old.clone())),
+            Some(new) if new.description != old.description => {
+                changes.push(CommandChange::Changed {
+                    command: old.command.clone(),
+                    old: old.description.clone(),
+                    new: new.description.clone(),
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    for new in desired {
+        if !current.iter().any(|c| c.command == new.command) {
+            changes.push(CommandChange::Added(new.clone()));
+        }
+    }
+    changes
+}
+
+type CellKey = (BotCommandScope, String);
+
+/// Command lists of a bot arranged as scopes × languages, with local drafts.
+///
+/// The empty language code (`""`) is the list for all languages and is always
+/// the first column.
+#[derive(Debug, Clone)]
+pub struct CommandMatrix {
+    pub scopes: Vec<BotCommandScope>,
+    pub languages: Vec<String>,
+    loaded: HashMap<CellKey, Vec<BotCommand>>,
+    /// Why the last fetch of a cell failed
+    errors: HashMap<CellKey, String>,
+    drafts: HashMap<CellKey, Vec<BotCommand>>,
+}
+
+impl CommandMatrix {
+    /// Creates a matrix with the four chat-independent scopes and the all-languages column.
+    pub fn new() -> Self {
+        Self {
+            scopes: vec![
+                BotCommandScope::Default,
+                BotCommandScope::AllPrivateChats,
+                BotCommandScope::AllGroupChats,
+                BotCommandScope::AllChatAdministrators,
+            ],
+            languages: vec![String::new()],
+            loaded: HashMap::new(),
+            errors: HashMap::new(),
+            drafts: HashMap::new(),
+        }
+    }
+
+    /// Adds a scope row if it is not present yet.
+    pub fn add_scope(&mut self, scope: BotCommandScope) {
+        if !self.scopes.contains(&scope) {
+            self.scopes.push(scope);
+        }
+    }
+
+    /// Adds a language column if it is not present yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error unless the code is two lowercase ASCII letters.
+    pub fn add_language(&mut self, language_code: &str) -> Result<()> {
+        let code = language_code.trim();
+        if code.len() != 2 || !code.chars().all(|c| c.is_ascii_lowercase()) {
+            bail!("\"{code}\" is not a two-letter ISO 639-1 language code");
+        }
+        if !self.languages.iter().any(|l| l == code) {
+            self.languages.push(code.to_string());
+        }
+        Ok(())
+    }
+
+    /// Stores the list fetched from Telegram and drops a draft equal to it.
+    pub fn set_loaded(
+        &mut self,
+        scope: &BotCommandScope,
+        language_code: &str,
+        commands: Vec<BotCommand>,
+    ) {
+        let key = (scope.clone(), language_code.to_string());
+        if self.drafts.get(&key) == Some(&commands) {
+            self.drafts.remove(&key);
+        }
+        self.errors.remove(&key);
+        self.loaded.insert(key, commands);
+    }
+
+    /// Records that fetching a cell failed; its previously fetched list is dropped.
+    pub fn set_error(&mut self, scope: &BotCommandScope, language_code: &str, error: String) {
+        let key = (scope.clone(), language_code.to_string());
+        self.loaded.remove(&key);
+        self.errors.insert(key, error);
+    }
+
+    /// Returns why the last fetch of a cell failed.
+    pub fn error(&self, scope: &BotCommandScope, language_code: &str) -> Option<&str> {
+        self.errors
+            .get(&(scope.clone(), language_code.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Number of cells whose last fetch failed.
+    pub fn failed_cells(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns the commands of every list fetched from Telegram, lowercased,
+    /// or `None` if nothing was fetched yet.
+    pub fn registered_commands(&self) -> Option<HashSet<String>> {
+        if self.loaded.is_empty() {
+            return None;
+        }
+        Some(
+            self.loaded
+                .values()
+                .flatten()
+                .map(|command| command.command.to_lowercase())
+                .collect(),
+        )
+    }
+
+    /// Returns the list last fetched from Telegram, if it was fetched.
+    pub fn loaded(&self, scope: &BotCommandScope, language_code: &str) -> Option<&[BotCommand]> {
+        self.loaded
+            .get(&(scope.clone(), language_code.to_string()))
+            .map(Vec::as_slice)
+    }
+
+    /// Stores a local draft for a cell.
+    pub fn set_draft(
+        &mut self,
+        scope: &BotCommandScope,
+        language_code: &str,
+        commands: Vec<BotCommand>,
+    ) {
+        self.drafts
+            .insert((scope.clone(), language_code.to_string()), commands);
+    }
+
+    /// Returns the local draft of a cell.
+    pub fn draft(&self, scope: &BotCommandScope, language_code: &str) -> Option<&[BotCommand]> {
+        self.drafts
+            .get(&(scope.clone(), language_code.to_string()))
+            .map(Vec::as_slice)
+    }
+
+    /// Discards all drafts.
+    pub fn clear_drafts(&mut self) {
+        self.drafts.clear();
+    }
+
+    /// Returns the list of a cell, preferring the draft when `with_drafts` is set.
+    pub fn cell(
+        &self,
+        scope: &BotCommandScope,
+        language_code: &str,
+        with_drafts: bool,
+    ) -> Option<&[BotCommand]> {
+        with_drafts
+            .then(|| self.draft(scope, language_code))
+            .flatten()
+            .or_else(|| self.loaded(scope, language_code))
+    }
+
+    /// Resolves the list a user with `language_code` sees in `scope`.
+    ///
+    /// Walks the scope's fallback chain and, for every scope, checks the
+    /// language-specific list before the list for all languages.
+    /// Returns `None` if every list along the chain is empty.
+    pub fn effective(
+        &self,
+        scope: &BotCommandScope,
+        language_code: &str,
+        with_drafts: bool,
+    ) -> Option<EffectiveCommands<'_>> {
+        for candidate in scope.fallback_chain() {
+            let mut languages = vec![language_code];
+            if !language_code.is_empty() {
+                languages.push("");
+            }
+            for language in languages {
+                if let Some(commands) = self
+                    .cell(&candidate, language, with_drafts)
+                    .filter(|c| !c.is_empty())
+                {
+                    return Some(EffectiveCommands {
+                        scope: candidate,
+                        language_code: language.to_string(),
+                        commands,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns all drafts that differ from the loaded lists, in matrix order.
+    pub fn pending_changes(&self) -> Vec<PendingCommandChange> {
+        let mut pending = Vec::new();
+        for scope in &self.scopes {
+            for language in &self.languages {
+                let Some(draft) = self.draft(scope, language) else {
+                    continue;
+                };
+                let current = self.loaded(scope, language).unwrap_or_default();
+                let changes = diff_commands(current, draft);
+                if !changes.is_empty() || current != draft {
+                    pending.push(PendingCommandChange {
+                        scope: scope.clone(),
+                        language_code: language.clone(),
+                        commands: draft.to_vec(),
+                        changes,
+                    });
+                }
+            }
+        }
+        pending
+    }
+
+    /// Exports all non-empty loaded lists.
+    pub fn to_command_set(&self) -> CommandSet {
+        let mut entries = Vec::new();
+        for scope in &self.scopes {
+            for language in &self.languages {
+                if let Some(commands) = self.loaded(scope, language).filter(|c| !c.is_empty()) {
+                    entries.push(CommandSetEntry {
+                        scope: scope.clone(),
+                        language_code: language.clone(),
+                        commands: commands.to_vec(),
+                    });
+                }
+            }
+        }
+        CommandSet { entries }
+    }
+
+    /// Turns an imported set into drafts, adding missing scopes and languages.
+    ///
+    /// Loaded cells missing from the set get an empty draft, so applying the
+    /// import reproduces the file exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a list or language code in the set is invalid.
+    /// Nothing is changed in that case.
+    pub fn import(&mut self, set: &CommandSet) -> Result<()> {
+        for entry in &set.entries {
+            validate_commands(&entry.commands).map_err(|e| {
+                anyhow::anyhow!("{} [{}]: {e}", entry.scope.label(), entry.language_code)
+            })?;
+        }
+
+        let mut updated = self.clone();
+        for entry in &set.entries {
+            updated.add_scope(entry.scope.clone());
+            if !entry.language_code.is_empty() {
+                updated.add_language(&entry.language_code)?;
+            }
+        }
+        for ((scope, language), commands) in &self.loaded {
+            if !commands.is_empty() {
+                updated.set_draft(scope, language, Vec::new());
+            }
+        }
+        for entry in &set.entries {
+            updated.set_draft(&entry.scope, &entry.language_code, entry.commands.clone());
+        }
+
+        *self = updated;
+        Ok(())
+    }
+}
+
+impl Default for CommandMatrix {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(name: &str, description: &str) -> BotCommand {
+        BotCommand {
+            command: name.to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_commands() {
+        assert!(validate_commands(&[command("start", "Start")]).is_ok());
+        assert!(validate_commands(&[command("Start", "Start")]).is_err());
+        assert!(validate_commands(&[command("start", "")]).is_err());
+        assert!(validate_commands(&[command("a", "x"), command("a", "y")]).is_err());
+        assert!(validate_commands(&[command(&"a".repeat(33), "x")]).is_err());
+    }
+
+    #[test]
+    fn test_parse_and_format_roundtrip() {
+        let commands = parse_commands_text("start - Start the bot\n/help - Show - help").unwrap();
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[1].description, "Show - help");
+
+        let text = format_commands_text(&commands);
+        assert_eq!(parse_commands_text(&text).unwrap(), commands);
+        assert!(parse_commands_text("start").is_err());
+        assert!(parse_commands_text("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_registered_commands_merge_all_loaded_lists() {
+        let mut matrix = CommandMatrix::new();
+        assert_eq!(matrix.registered_commands(), None);
+
+        matrix.set_loaded(
+            &BotCommandScope::Default,
+            "",
+            vec![command("start", "Start")],
+        );
+        matrix.set_loaded(
+            &BotCommandScope::AllGroupChats,
+            "",
+            vec![command("stats", "Stats")],
+        );
+        matrix.set_draft(
+            &BotCommandScope::Default,
+            "",
+            vec![command("draft", "Draft")],
+        );
+
+        let registered = matrix.registered_commands().unwrap();
+        assert_eq!(registered.len(), 2);
+        assert!(registered.contains("start") && registered.contains("stats"));
+    }
+
+    #[test]
+    fn test_cell_errors_replace_and_are_cleared_by_loads() {
+        let mut matrix = CommandMatrix::new();
+        let scope = BotCommandScope::ChatAdministrators { chat_id: 5 };
+        matrix.set_loaded(&scope, "", vec![command("ban", "Ban")]);
+        matrix.set_error(&scope, "", "Bad Request: chat not found".to_string());
+        assert_eq!(matrix.loaded(&scope, ""), None);
+        assert_eq!(
+            matrix.error(&scope, ""),
+            Some("Bad Request: chat not found")
+        );
+        assert_eq!(matrix.failed_cells(), 1);
+
+        matrix.set_loaded(&scope, "", vec![command("ban", "Ban")]);
+        assert_eq!(matrix.error(&scope, ""), None);
+        assert_eq!(matrix.failed_cells(), 0);
+    }
+
+    #[test]
+    fn test_diff_commands() {
+        let current = vec![command("start", "Start"), command("help", "Help")];
+        let desired = vec![command("start", "Begin"), command("settings", "Settings")];
+
+        let changes = diff_commands(&current, &desired);
+        assert_eq!(changes.len(), 3);
+        assert!(changes.contains(&CommandChange::Removed(command("help", "Help"))));
+        assert!(changes.contains(&CommandChange::Added(command("settings", "Settings"))));
+        assert_eq!(changes[0].to_string(), "~ /start: Start → Begin");
+        assert!(diff_commands(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn test_effective_resolution_falls_back_along_scopes_and_languages() {
+        let mut matrix = CommandMatrix::new();
+        matrix.add_language("de").unwrap();
+        matrix.set_loaded(
+            &BotCommandScope::Default,
+            "",
+            vec![command("start", "Start")],
+        );
+        matrix.set_loaded(
+            &BotCommandScope::AllGroupChats,
+            "de",
+            vec![command("hilfe", "Hilfe")],
+        );
+
+        let effective = matrix
+            .effective(&BotCommandScope::AllChatAdministrators, "de", false)
+            .unwrap();
+        assert_eq!(effective.scope, BotCommandScope::AllGroupChats);
+        assert_eq!(effective.language_code, "de");
+
+        let effective = matrix
+            .effective(&BotCommandScope::AllChatAdministrators, "", false)
+            .unwrap();
+        assert_eq!(effective.scope, BotCommandScope::Default);
+
+        let private = BotCommandScope::Chat { chat_id: 42 };
+        let effective = matrix.effective(&private, "de", false).unwrap();
+        assert_eq!(effective.scope, BotCommandScope::Default);
+        assert_eq!(effective.language_code, "");
+    }
+
+    #[test]
+    fn test_drafts_and_pending_changes() {
+        let mut matrix = CommandMatrix::new();
+        matrix.set_loaded(
+            &BotCommandScope::Default,
+            "",
+            vec![command("start", "Start")],
+        );
+        matrix.set_draft(
+            &BotCommandScope::AllPrivateChats,
+            "",
+            vec![command("help", "Help")],
+        );
+        matrix.set_draft(
+            &BotCommandScope::Default,
+            "",
+            vec![command("start", "Start")],
+        );
+
+        let pending = matrix.pending_changes();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].scope, BotCommandScope::AllPrivateChats);
+
+        let effective = matrix
+            .effective(&BotCommandScope::AllPrivateChats, "", true)
+            .unwrap();
+        assert_eq!(effective.commands[0].command, "help");
+        let effective = matrix
+            .effective(&BotCommandScope::AllPrivateChats, "", false)
+            .unwrap();
+        assert_eq!(effective.scope, BotCommandScope::Default);
+
+        // Reloading the applied list drops the draft
+        matrix.set_loaded(
+            &BotCommandScope::AllPrivateChats,
+            "",
+            vec![command("help", "Help")],
+        );
+        assert!(matrix.pending_changes().is_empty());
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let mut matrix = CommandMatrix::new();
+        matrix.set_loaded(
+            &BotCommandScope::Default,
+            "",
+            vec![command("start", "Start")],
+        );
+        matrix.set_loaded(
+            &BotCommandScope::AllGroupChats,
+            "",
+            vec![command("ban", "Ban")],
+        );
+
+        let json = serde_json::to_string(&matrix.to_command_set()).unwrap();
+        let mut set: CommandSet = serde_json::from_str(&json).unwrap();
+        assert_eq!(set.entries.len(), 2);
+
+        set.entries.remove(1);
+        set.entries.push(CommandSetEntry {
+            scope: BotCommandScope::Chat { chat_id: -100 },
+            language_code: "fr".to_string(),
+            commands: vec![command("aide", "Aide")],
+        });
+        matrix.import(&set).unwrap();
+
+        assert!(matrix.languages.contains(&"fr".to_string()));
+        assert!(
+            matrix
+                .scopes
+                .contains(&BotCommandScope::Chat { chat_id: -100 })
+        );
+        let pending = matrix.pending_changes();
+        assert_eq!(pending.len(), 2);
+        assert!(
+            pending
+                .iter()
+                .any(|p| p.scope == BotCommandScope::AllGroupChats && p.commands.is_empty())
+        );
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_set_without_changes() {
+        let mut matrix = CommandMatrix::new();
+        let set = CommandSet {
+            entries: vec![CommandSetEntry {
+                scope: BotCommandScope::Default,
+                language_code: "english".to_string(),
+                commands: vec![command("start", "Start")],
+            }],
+        };
+        assert!(matrix.import(&set).is_err());
+        assert_eq!(matrix.languages.len(), 1);
+        assert!(matrix.pending_changes().is_empty());
+    }
+}
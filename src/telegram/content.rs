@@ -0,0 +1,931 @@
+//! Typed classification of what a message carries.
+//!
+//! [`Message`](super::Message) only models `text`; media, polls, locations and
+//! service messages live in its `other` map. [`MessageContent`] sorts them into
+//! kinds with the fields worth showing, and [`MessageContent::summary`] turns
+//! them into one line like `📷 photo 1280x720 (caption: ...)`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::types::{ChannelPost, Message, User};
+
+/// Number of characters of captions and pinned texts shown in summaries.
+const SUMMARY_TEXT_LENGTH: usize = 40;
+
+/// What a message carries.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageContent {
+    Text(String),
+    Photo {
+        width: i64,
+        height: i64,
+        caption: Option<String>,
+    },
+    Video {
+        width: i64,
+        height: i64,
+        duration: i64,
+        caption: Option<String>,
+    },
+    Animation {
+        width: i64,
+        height: i64,
+        caption: Option<String>,
+    },
+    Audio {
+        performer: Option<String>,
+        title: Option<String>,
+        duration: i64,
+        caption: Option<String>,
+    },
+    Voice {
+        duration: i64,
+    },
+    VideoNote {
+        duration: i64,
+    },
+    Document {
+        file_name: Option<String>,
+        mime_type: Option<String>,
+        caption: Option<String>,
+    },
+    Sticker {
+        emoji: Option<String>,
+        set_name: Option<String>,
+    },
+    Poll {
+        question: String,
+        options: usize,
+    },
+    Dice {
+        emoji: String,
+        value: i64,
+    },
+    Location {
+        latitude: f64,
+        longitude: f64,
+    },
+    Venue {
+        title: String,
+        address: String,
+    },
+    Contact {
+        name: String,
+        phone_number: String,
+    },
+    Game {
+        title: String,
+    },
+    Invoice {
+        title: String,
+    },
+    Story,
+    PaidMedia {
+        star_count: i64,
+    },
+    Service(ServiceMessage),
+    /// Nothing this version recognizes
+    Unknown,
+}
+
+/// Service messages: members joining or leaving, chat changes, pins, topics.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServiceMessage {
+    NewChatMembers(Vec<User>),
+    LeftChatMember(User),
+    NewChatTitle(String),
+    NewChatPhoto,
+    DeleteChatPhoto,
+    ChatCreated,
+    /// Text or caption of the pinned message
+    PinnedMessage(Option<String>),
+    MigrateToChatId(i64),
+    MigrateFromChatId(i64),
+    ForumTopicCreated(String),
+    ForumTopicEdited,
+    ForumTopicClosed,
+    ForumTopicReopened,
+    VideoChatStarted,
+    VideoChatEnded,
+    SuccessfulPayment {
+        currency: String,
+        total_amount: i64,
+    },
+    /// Any other service message, by field name
+    Other(String),
+}
+
+/// Field names of service messages without a dedicated variant.
+const OTHER_SERVICE_KEYS: [&str; 19] = [
+    "message_auto_delete_timer_changed",
+    "refunded_payment",
+    "users_shared",
+    "chat_shared",
+    "connected_website",
+    "write_access_allowed",
+    "passport_data",
+    "proximity_alert_triggered",
+    "boost_added",
+    "chat_background_set",
+    "general_forum_topic_hidden",
+    "general_forum_topic_unhidden",
+    "giveaway_created",
+    "giveaway",
+    "giveaway_winners",
+    "giveaway_completed",
+    "video_chat_scheduled",
+    "video_chat_participants_invited",
+    "web_app_data",
+];
+
+impl MessageContent {
+    /// Classifies a message from its `text` and the fields in its `other` map.
+    pub fn from_parts(text: Option<&str>, other: &HashMap<String, Value>) -> Self {
+        if let Some(text) = text {
+            return Self::Text(text.to_string());
+        }
+
+        let caption = other
+            .get("caption")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let int = |value: &Value, key: &str| value.get(key).and_then(Value::as_i64).unwrap_or(0);
+        let string =
+            |value: &Value, key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+
+        // Photos come in several sizes, the largest last
+        if let Some(photo) = other
+            .get("photo")
+            .and_then(Value::as_array)
+            .and_then(|sizes| sizes.last())
+        {
+            return Self::Photo {
+                width: int(photo, "width"),
+                height: int(photo, "height"),
+                caption,
+            };
+        }
+        if let Some(video) = other.get("video") {
+            return Self::Video {
+                width: int(video, "width"),
+                height: int(video, "height"),
+                duration: int(video, "duration"),
+                caption,
+            };
+        }
+        // Animations also carry a `document` field, so they go first
+        if let Some(animation) = other.get("animation") {
+            return Self::Animation {
+                width: int(animation, "width"),
+                height: int(animation, "height"),
+                caption,
+            };
+        }
+        if let Some(audio) = other.get("audio") {
+            return Self::Audio {
+                performer: string(audio, "performer"),
+                title: string(audio, "title"),
+                duration: int(audio, "duration"),
+                caption,
+            };
+        }
+        if let Some(voice) = other.get("voice") {
+            return Self::Voice {
+                duration: int(voice, "duration"),
+            };
+        }
+        if let Some(note) = other.get("video_note") {
+            return Self::VideoNote {
+                duration: int(note, "duration"),
+            };
+        }
+        if let Some(document) = other.get("document") {
+            return Self::Document {
+                file_name: string(document, "file_name"),
+                mime_type: string(document, "mime_type"),
+                caption,
+            };
+        }
+        if let Some(sticker) = other.get("sticker") {
+            return Self::Sticker {
+                emoji: string(sticker, "emoji"),
+                set_name: string(sticker, "set_name"),
+            };
+        }
+        if let Some(poll) = other.get("poll") {
+            return Self::Poll {
+                question: string(poll, "question").unwrap_or_default(),
+                options: poll
+                    .get("options")
+                    .and_then(Value::as_array)
+                    .map_or(0, Vec::len),
+            };
+        }
+        if let Some(dice) = other.get("dice") {
+            return Self::Dice {
+                emoji: string(dice, "emoji").unwrap_or_else(|| "🎲".to_string()),
+                value: int(dice, "value"),
+            };
+        }
+        // Venues also carry a `location` field, so they go first
+        if let Some(venue) = other.get("venue") {
+            return Self::Venue {
+                title: string(venue, "title").unwrap_or_default(),
+                address: string(venue, "address").unwrap_or_default(),
+            };
+        }
+        if let Some(location) = other.get("location") {
+            let coordinate = |key: &str| location.get(key).and_then(Value::as_f64).unwrap_or(0.0);
+            return Self::Location {
+                latitude: coordinate("latitude"),
+                longitude: coordinate("longitude"),
+            };
+        }
+        if let Some(contact) = other.get("contact") {
+            let first = string(contact, "first_name").unwrap_or_default();
+            let name = match string(contact, "last_name") {
+                Some(last) => format!("{first} {last}"),
+                None => first,
+            };
+            return Self::Contact {
+                name,
+                phone_number: string(contact, "phone_number").unwrap_or_default(),
+            };
+        }
+        if let Some(game) = other.get("game") {
+            return Self::Game {
+                title: string(game, "title").unwrap_or_default(),
+            };
+        }
+        if let Some(invoice) = other.get("invoice") {
+            return Self::Invoice {
+                title: string(invoice, "title").unwrap_or_default(),
+            };
+        }
+        if other.contains_key("story") {
+            return Self::Story;
+        }
+        if let Some(paid) = other.get("paid_media") {
+            return Self::PaidMedia {
+                star_count: int(paid, "star_count"),
+            };
+        }
+
+        ServiceMessage::from_other(other)
+            .map(Self::Service)
+            .unwrap_or(Self::Unknown)
+    }
+
+    /// Returns the caption of a media message.
+    pub fn caption(&self) -> Option<&str> {
+        match self {
+            Self::Photo { caption, .. }
+            | Self::Video { caption, .. }
+            | Self::Animation { caption, .. }
+            | Self::Audio { caption, .. }
+            | Self::Document { caption, .. } => caption.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns a one-line description, e.g. `📷 photo 1280x720 (caption: ...)`.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            Self::Photo {
+                width,
+                height,
+                caption,
+            } => with_caption(format!("📷 photo {width}x{height}"), caption),
+            Self::Video {
+                width,
+                height,
+                duration,
+                caption,
+            } => with_caption(format!("🎬 video {width}x{height} {duration}s"), caption),
+            Self::Animation {
+                width,
+                height,
+                caption,
+            } => with_caption(format!("🎞 GIF {width}x{height}"), caption),
+            Self::Audio {
+                performer,
+                title,
+                duration,
+                caption,
+            } => {
+                let name = match (performer, title) {
+                    (Some(performer), Some(title)) => format!(" {performer} - {title}"),
+                    (None, Some(name)) | (Some(name), None) => format!(" {name}"),
+                    (None, None) => String::new(),
+                };
+                with_caption(format!("🎵 audio{name} {duration}s"), caption)
+            }
+            Self::Voice { duration } => format!("🎤 voice {duration}s"),
+            Self::VideoNote { duration } => format!("⏺ video note {duration}s"),
+            Self::Document {
+                file_name,
+                mime_type,
+                caption,
+            } => {
+                let name = file_name.as_deref().unwrap_or("unnamed");
+                let mime = mime_type
+                    .as_ref()
+                    .map(|m| format!(" ({m})"))
+                    .unwrap_or_default();
+                with_caption(format!("📄 document {name}{mime}"), caption)
+            }
+            Self::Sticker { emoji, set_name } => {
+                let set = set_name
+                    .as_ref()
+                    .map(|s| format!(" ({s})"))
+                    .unwrap_or_default();
+                format!("🏷 sticker {}{set}", emoji.as_deref().unwrap_or(""))
+            }
+            Self::Poll { question, options } => {
+                format!("📊 poll: {question} ({options} options)")
+            }
+            Self::Dice { emoji, value } => format!("{emoji} dice = {value}"),
+            Self::Location {
+                latitude,
+                longitude,
+            } => format!("📍 location {latitude:.5}, {longitude:.5}"),
+            Self::Venue { title, address } => format!("📍 venue: {title}, {address}"),
+            Self::Contact { name, phone_number } => format!("👤 contact: {name} {phone_number}"),
+            Self::Game { title } => format!("🎮 game: {title}"),
+            Self::Invoice { title } => format!("🧾 invoice: {title}"),
+            Self::Story => "📖 story".to_string(),
+            Self::PaidMedia { star_count } => format!("⭐ paid media ({star_count} stars)"),
+            Self::Service(service) => service.summary(),
+            Self::Unknown => "[No text]".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for MessageContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.summary())
+    }
+}
+
+impl ServiceMessage {
+    /// Detects the service message in the fields of a message, if any.
+    pub fn from_other(other: &HashMap<String, Value>) -> Option<Self> {
+        let parse_user = |value: &Value| serde_json::from_value::<User>(value.clone()).ok();
+
+        if let Some(members) = other.get("new_chat_members").and_then(Value::as_array) {
+            return Some(Self::NewChatMembers(
+                members.iter().filter_map(parse_user).collect(),
+            ));
+        }
+        if let Some(member) = other.get("left_chat_member").and_then(parse_user) {
+            return Some(Self::LeftChatMember(member));
+        }
+        if let Some(title) = other.get("new_chat_title").and_then(Value::as_str) {
+            return Some(Self::NewChatTitle(title.to_string()));
+        }
+        if other.contains_key("new_chat_photo") {
+            return Some(Self::NewChatPhoto);
+        }
+        if other.contains_key("delete_chat_photo") {
+            return Some(Self::DeleteChatPhoto);
+        }
+        if [
+            "group_chat_created",
+            "supergroup_chat_created",
+            "channel_chat_created",
+        ]
+        .iter()
+        .any(|key| other.contains_key(*key))
+        {
+            return Some(Self::ChatCreated);
+        }
+        if let Some(pinned) = other.get("pinned_message") {
+            let text = pinned
+                .get("text")
+                .or_else(|| pinned.get("caption"))
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            return Some(Self::PinnedMessage(text));
+        }
+        if let Some(id) = other.get("migrate_to_chat_id").and_then(Value::as_i64) {
+            return Some(Self::MigrateToChatId(id));
+        }
+        if let Some(id) = other.get("migrate_from_chat_id").and_then(Value::as_i64) {
+            return Some(Self::MigrateFromChatId(id));
+        }
+        if let Some(topic) = other.get("forum_topic_created") {
+            let name = topic.get("name").and_then(Value::as_str).unwrap_or("");
+            return Some(Self::ForumTopicCreated(name.to_string()));
+        }
+        if other.contains_key("forum_topic_edited") {
+            return Some(Self::ForumTopicEdited);
+        }
+        if other.contains_key("forum_topic_closed") {
+            return Some(Self::ForumTopicClosed);
+        }
+        if other.contains_key("forum_topic_reopened") {
+            return Some(Self::ForumTopicReopened);
+        }
+        if other.contains_key("video_chat_started") {
+            return Some(Self::VideoChatStarted);
+        }
+        if other.contains_key("video_chat_ended") {
+            return Some(Self::VideoChatEnded);
+        }
+        if let Some(payment) = other.get("successful_payment") {
+            return Some(Self::SuccessfulPayment {
+                currency: payment
+                    .get("currency")
+                    .and_then(Value::as_str)
+                    .unwrap_or("")
+                    .to_string(),
+                total_amount: payment
+                    .get("total_amount")
+                    .and_then(Value::as_i64)
+                    .unwrap_or(0),
+            });
+        }
+        OTHER_SERVICE_KEYS
+            .iter()
+            .find(|key| other.contains_key(**key))
+            .map(|key| Self::Other(key.to_string()))
+    }
+
+    /// Returns a one-line description, e.g. `👥 joined: @alice`.
+    pub fn summary(&self) -> String {
+        let names = |users: &[User]| {
+            users
+                .iter()
+                .map(User::display_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            Self::NewChatMembers(users) => format!("👥 joined: {}", names(users)),
+            Self::LeftChatMember(user) => format!("👋 left: {}", user.display_name()),
+            Self::NewChatTitle(title) => format!("✏ title changed: {title}"),
+            Self::NewChatPhoto => "🖼 chat photo changed".to_string(),
+            Self::DeleteChatPhoto => "🖼 chat photo removed".to_string(),
+            Self::ChatCreated => "✨ chat created".to_string(),
+            Self::PinnedMessage(text) => match text {
+                Some(text) => format!("📌 pinned: {}", shorten(text)),
+                None => "📌 pinned a message".to_string(),
+            },
+            Self::MigrateToChatId(id) => format!("⬆ upgraded to supergroup {id}"),
+            Self::MigrateFromChatId(id) => format!("⬆ upgraded from group {id}"),
+            Self::ForumTopicCreated(name) => format!("🗂 topic created: {name}"),
+            Self::ForumTopicEdited => "🗂 topic edited".to_string(),
+            Self::ForumTopicClosed => "🗂 topic closed".to_string(),
+            Self::ForumTopicReopened => "🗂 topic reopened".to_string(),
+            Self::VideoChatStarted => "📞 video chat started".to_string(),
+            Self::VideoChatEnded => "📞 video chat ended".to_string(),
+            Self::SuccessfulPayment {
+                currency,
+                total_amount,
+            } => format!("💰 payment received: {total_amount} {currency} (minor units)"),
+            Self::Other(key) => format!("⚙ {key}"),
+        }
+    }
+}
+
+/// A formatted part of a message text.
+///
+/// `offset` and `length` count UTF-16 code units, as in the Bot API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageEntity {
+    #[serde(rename = "type")]
+    pub kind: EntityKind,
+    pub offset: usize,
+    pub length: usize,
+    /// URL opened by a `text_link`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// User mentioned by a `text_mention`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Programming language of a `pre` block
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Custom emoji of a `custom_emoji`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<String>,
+}
+
+/// Type of a [`MessageEntity`]; types added to the Bot API later are kept as `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum EntityKind {
+    Mention,
+    Hashtag,
+    Cashtag,
+    BotCommand,
+    Url,
+    Email,
+    PhoneNumber,
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    Blockquote,
+    ExpandableBlockquote,
+    Code,
+    Pre,
+    TextLink,
+    TextMention,
+    CustomEmoji,
+    Other(String),
+}
+
+impl EntityKind {
+    /// Returns the Bot API name of the type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Mention => "mention",
+            Self::Hashtag => "hashtag",
+            Self::Cashtag => "cashtag",
+            Self::BotCommand => "bot_command",
+            Self::Url => "url",
+            Self::Email => "email",
+            Self::PhoneNumber => "phone_number",
+            Self::Bold => "bold",
+            Self::Italic => "italic",
+            Self::Underline => "underline",
+            Self::Strikethrough => "strikethrough",
+            Self::Spoiler => "spoiler",
+            Self::Blockquote => "blockquote",
+            Self::ExpandableBlockquote => "expandable_blockquote",
+            Self::Code => "code",
+            Self::Pre => "pre",
+            Self::TextLink => "text_link",
+            Self::TextMention => "text_mention",
+            Self::CustomEmoji => "custom_emoji",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for EntityKind {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "mention" => Self::Mention,
+            "hashtag" => Self::Hashtag,
+            "cashtag" => Self::Cashtag,
+            "bot_command" => Self::BotCommand,
+            "url" => Self::Url,
+            "email" => Self::Email,
+            "phone_number" => Self::PhoneNumber,
+            "bold" => Self::Bold,
+            "italic" => Self::Italic,
+            "underline" => Self::Underline,
+            "strikethrough" => Self::Strikethrough,
+            "spoiler" => Self::Spoiler,
+            "blockquote" => Self::Blockquote,
+            "expandable_blockquote" => Self::ExpandableBlockquote,
+            "code" => Self::Code,
+            "pre" => Self::Pre,
+            "text_link" => Self::TextLink,
+            "text_mention" => Self::TextMention,
+            "custom_emoji" => Self::CustomEmoji,
+            _ => Self::Other(name),
+        }
+    }
+}
+
+impl From<EntityKind> for String {
+    fn from(kind: EntityKind) -> Self {
+        kind.as_str().to_string()
+    }
+}
+
+impl Message {
+    /// Classifies what this message carries.
+    pub fn content(&self) -> MessageContent {
+        MessageContent::from_parts(self.text.as_deref(), &self.other)
+    }
+
+    /// Returns the entities of the text, or of the caption of a media message.
+    pub fn entities(&self) -> Vec<MessageEntity> {
+        parse_entities(&self.other)
+    }
+}
+
+impl ChannelPost {
+    /// Classifies what this post carries.
+    pub fn content(&self) -> MessageContent {
+        MessageContent::from_parts(self.text.as_deref(), &self.other)
+    }
+
+    /// Returns the entities of the text, or of the caption of a media post.
+    pub fn entities(&self) -> Vec<MessageEntity> {
+        parse_entities(&self.other)
+    }
+}
+
+/// Why an entity can't be located in its text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityRangeError {
+    /// The entity ends after the text, which is this many UTF-16 units long
+    OutOfBounds { text_length: usize },
+    /// An edge of the entity falls between the two halves of a surrogate pair,
+    /// typically because the offset was counted in code points instead of UTF-16 units
+    SplitsSurrogatePair { utf16_offset: usize },
+}
+
+impl fmt::Display for EntityRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { text_length } => {
+                write!(f, "ends after the text ({text_length} UTF-16 units)")
+            }
+            Self::SplitsSurrogatePair { utf16_offset } => {
+                write!(f, "UTF-16 offset {utf16_offset} splits a surrogate pair")
+            }
+        }
+    }
+}
+
+impl MessageEntity {
+    /// Returns the covered range in UTF-16 code units, as sent by Telegram.
+    pub fn utf16_range(&self) -> Range<usize> {
+        self.offset..self.offset + self.length
+    }
+
+    /// Converts the UTF-16 range of the entity to a byte range of `text`.
+    pub fn byte_range(&self, text: &str) -> Result<Range<usize>, EntityRangeError> {
+        let range = self.utf16_range();
+        Ok(utf16_to_byte(text, range.start)?..utf16_to_byte(text, range.end)?)
+    }
+
+    /// Returns the range the entity would cover if its offsets counted code
+    /// points, the usual mistake when building entities without UTF-16.
+    ///
+    /// `None` if that range is outside the text.
+    pub fn code_point_range(&self, text: &str) -> Option<Range<usize>> {
+        let mut boundaries = text
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(text.len()));
+        let start = boundaries.nth(self.offset)?;
+        let end = if self.length == 0 {
+            start
+        } else {
+            boundaries.nth(self.length - 1)?
+        };
+        Some(start..end)
+    }
+}
+
+/// Returns the length of a text in UTF-16 code units, the unit of entity offsets.
+pub fn utf16_length(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+/// Converts a UTF-16 offset into a byte offset of `text`.
+pub fn utf16_to_byte(text: &str, utf16_offset: usize) -> Result<usize, EntityRangeError> {
+    let mut units = 0;
+    for (index, c) in text.char_indices() {
+        if units == utf16_offset {
+            return Ok(index);
+        }
+        units += c.len_utf16();
+        if units > utf16_offset {
+            return Err(EntityRangeError::SplitsSurrogatePair { utf16_offset });
+        }
+    }
+    if units == utf16_offset {
+        Ok(text.len())
+    } else {
+        Err(EntityRangeError::OutOfBounds { text_length: units })
+    }
+}
+
+/// A piece of text covered by the same set of entities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextRun<'a> {
+    pub text: &'a str,
+    /// Indices of the entities covering this piece
+    pub entities: Vec<usize>,
+}
+
+/// Splits a text at every entity edge.
+///
+/// Entities that can't be located (see [`MessageEntity::byte_range`]) are
+/// left out; the runs always concatenate back to the whole text.
+pub fn split_by_entities<'a>(text: &'a str, entities: &[MessageEntity]) -> Vec<TextRun<'a>> {
+    let ranges: Vec<(usize, Range<usize>)> = entities
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entity)| Some((index, entity.byte_range(text).ok()?)))
+        .collect();
+
+    let mut edges: Vec<usize> = vec![0, text.len()];
+    for (_, range) in &ranges {
+        edges.push(range.start);
+        edges.push(range.end);
+    }
+    edges.sort_unstable();
+    edges.dedup();
+
+    edges
+        .windows(2)
+        .map(|pair| TextRun {
+            text: &text[pair[0]..pair[1]],
+            entities: ranges
+                .iter()
+                .filter(|(_, range)| range.start <= pair[0] && pair[1] <= range.end)
+                .map(|(index, _)| *index)
+                .collect(),
+        })
+        .collect()
+}
+
+/// Parses the `entities`, or the `caption_entities` of a media message.
+///
+/// Entities that don't parse are skipped.
+pub fn parse_entities(other: &HashMap<String, Value>) -> Vec<MessageEntity> {
+    other
+        .get("entities")
+        .or_else(|| other.get("caption_entities"))
+        .and_then(Value::as_array)
+        .map(|entities| {
+            entities
+                .iter()
+                .filter_map(|entity| serde_json::from_value(entity.clone()).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn with_caption(summary: String, caption: &Option<String>) -> String {
+    match caption {
+        Some(caption) => format!("{summary} (caption: {})", shorten(caption)),
+        None => summary,
+    }
+}
+
+/// Cuts a text to [`SUMMARY_TEXT_LENGTH`] characters.
+fn shorten(text: &str) -> String {
+    if text.chars().count() > SUMMARY_TEXT_LENGTH {
+        let cut: String = text.chars().take(SUMMARY_TEXT_LENGTH).collect();
+        format!("{cut}...")
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn other(value: Value) -> HashMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_media_summaries() {
+        let photo = other(json!({
+            "photo": [
+                {"file_id": "s", "width": 90, "height": 51},
+                {"file_id": "l", "width": 1280, "height": 720}
+            ],
+            "caption": "Sunset"
+        }));
+        assert_eq!(
+            MessageContent::from_parts(None, &photo).summary(),
+            "📷 photo 1280x720 (caption: Sunset)"
+        );
+
+        let animation = other(json!({
+            "animation": {"width": 320, "height": 240},
+            "document": {"file_name": "a.mp4"}
+        }));
+        assert!(matches!(
+            MessageContent::from_parts(None, &animation),
+            MessageContent::Animation { .. }
+        ));
+
+        let venue = other(json!({
+            "venue": {"title": "Cafe", "address": "Main St 1", "location": {}},
+            "location": {"latitude": 1.0, "longitude": 2.0}
+        }));
+        assert_eq!(
+            MessageContent::from_parts(None, &venue).summary(),
+            "📍 venue: Cafe, Main St 1"
+        );
+
+        assert_eq!(
+            MessageContent::from_parts(Some("hi"), &photo),
+            MessageContent::Text("hi".to_string())
+        );
+        assert_eq!(
+            MessageContent::from_parts(None, &HashMap::new()).summary(),
+            "[No text]"
+        );
+    }
+
+    #[test]
+    fn test_service_summaries() {
+        let joined = other(json!({
+            "new_chat_members": [
+                {"id": 1, "is_bot": false, "first_name": "Alice", "username": "alice"},
+                {"id": 2, "is_bot": false, "first_name": "Bob"}
+            ]
+        }));
+        assert_eq!(
+            MessageContent::from_parts(None, &joined).summary(),
+            "👥 joined: @alice, Bob"
+        );
+
+        let pinned = other(json!({"pinned_message": {"message_id": 1, "text": "Rules"}}));
+        assert_eq!(
+            MessageContent::from_parts(None, &pinned).summary(),
+            "📌 pinned: Rules"
+        );
+
+        let shared = other(json!({"chat_shared": {"request_id": 1, "chat_id": 5}}));
+        assert_eq!(
+            MessageContent::from_parts(None, &shared),
+            MessageContent::Service(ServiceMessage::Other("chat_shared".to_string()))
+        );
+    }
+
+    fn entity(kind: &str, offset: usize, length: usize) -> MessageEntity {
+        serde_json::from_value(json!({"type": kind, "offset": offset, "length": length})).unwrap()
+    }
+
+    #[test]
+    fn test_entity_ranges_count_utf16_units() {
+        // "👋" is one code point but two UTF-16 units and four bytes
+        let text = "👋 Hi @bob";
+        assert_eq!(utf16_length(text), 10);
+
+        let mention = entity("mention", 6, 4);
+        assert_eq!(mention.byte_range(text), Ok(8..12));
+        assert_eq!(&text[mention.byte_range(text).unwrap()], "@bob");
+        // Read as code points, the same offsets are shifted by one
+        let bold = entity("bold", 3, 2);
+        assert_eq!(&text[bold.byte_range(text).unwrap()], "Hi");
+        assert_eq!(&text[bold.code_point_range(text).unwrap()], "i ");
+        assert_eq!(mention.code_point_range(text), None);
+
+        assert_eq!(
+            entity("bold", 1, 2).byte_range(text),
+            Err(EntityRangeError::SplitsSurrogatePair { utf16_offset: 1 })
+        );
+        assert_eq!(
+            entity("bold", 8, 3).byte_range(text),
+            Err(EntityRangeError::OutOfBounds { text_length: 10 })
+        );
+    }
+
+    #[test]
+    fn test_split_by_entities_nests_and_skips_broken() {
+        let text = "bold link end";
+        let entities = [
+            entity("bold", 0, 9),
+            entity("text_link", 5, 4),
+            entity("italic", 20, 2),
+        ];
+        let runs = split_by_entities(text, &entities);
+        let summary: Vec<(&str, Vec<usize>)> = runs
+            .iter()
+            .map(|run| (run.text, run.entities.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("bold ", vec![0]), ("link", vec![0, 1]), (" end", vec![]),]
+        );
+        assert_eq!(split_by_entities("", &[]), vec![]);
+    }
+
+    #[test]
+    fn test_entities_keep_unknown_types() {
+        let fields = other(json!({
+            "entities": [
+                {"type": "bold", "offset": 0, "length": 4},
+                {"type": "text_link", "offset": 5, "length": 3, "url": "https://a.b"},
+                {"type": "future_style", "offset": 9, "length": 1}
+            ]
+        }));
+        let entities = parse_entities(&fields);
+        assert_eq!(entities.len(), 3);
+        assert_eq!(entities[0].kind, EntityKind::Bold);
+        assert_eq!(entities[1].url.as_deref(), Some("https://a.b"));
+        assert_eq!(
+            entities[2].kind,
+            EntityKind::Other("future_style".to_string())
+        );
+        assert_eq!(
+            serde_json::to_value(&entities[2]).unwrap(),
+            json!({"type": "future_style", "offset": 9, "length": 1})
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Reply threads of chat messages.
+//!
+//! Messages are linked to their parent through `reply_to_message`, or through
+//! `message_thread_id` for forum topic messages without an explicit reply.
+//! Messages sent by the bot itself are added from the API responses, so the
+//! bot's answers show up in the same threads.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::types::Message;
+
+/// Number of messages kept across all chats; the oldest are dropped first.
+const MAX_CONVERSATION_MESSAGES: usize = 5000;
+
+/// Deepest reply level walked when building a thread.
+const MAX_THREAD_DEPTH: usize = 64;
+
+/// One message in a conversation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversationNode {
+    pub message_id: i64,
+    pub date: i64,
+    /// Display name of the sender, or the chat for anonymous posts
+    pub sender: String,
+    /// Text, or the caption of a media message
+    pub text: Option<String>,
+    /// Message this one replies to
+    pub reply_to: Option<i64>,
+    /// Forum topic of the message
+    pub thread_id: Option<i64>,
+    /// Part of the parent message quoted in the reply
+    pub quote: Option<String>,
+    /// Sent by the bot through this debugger
+    pub outgoing: bool,
+    /// Only known as the target of a reply, never received itself
+    pub placeholder: bool,
+    /// Replies in arrival order
+    pub replies: Vec<i64>,
+}
+
+impl ConversationNode {
+    fn from_message(message: &Message, outgoing: bool) -> Self {
+        let reply_to = message
+            .reply_to_message
+            .as_ref()
+            .map(|reply| reply.message_id)
+            .or(message.message_thread_id)
+            .filter(|parent| *parent != message.message_id);
+        Self {
+            message_id: message.message_id,
+            date: message.date,
+            sender: message
+                .from
+                .as_ref()
+                .map(|user| user.display_name())
+                .unwrap_or_else(|| message.chat.display_name()),
+            text: message
+                .text
+                .clone()
+                .or_else(|| message.caption().map(str::to_string)),
+            reply_to,
+            thread_id: message.message_thread_id,
+            quote: message.quote_text().map(str::to_string),
+            outgoing,
+            placeholder: false,
+            replies: Vec::new(),
+        }
+    }
+}
+
+/// One row of a rendered thread.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThreadRow<'a> {
+    /// Reply level below the thread root
+    pub depth: usize,
+    pub node: &'a ConversationNode,
+}
+
+/// Reply trees of all chats, keyed by chat ID and message ID.
+#[derive(Debug, Default)]
+pub struct Conversations {
+    chats: HashMap<i64, HashMap<i64, ConversationNode>>,
+    /// Messages in the order they were first seen
+    order: VecDeque<(i64, i64)>,
+}
+
+impl Conversations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a received (`outgoing = false`) or sent message to its chat's tree.
+    ///
+    /// The replied-to message embedded in `reply_to_message` is added as a
+    /// placeholder if it hasn't been seen itself. Receiving a message again
+    /// keeps the replies already linked to it.
+    pub fn record(&mut self, message: &Message, outgoing: bool) {
+        let chat_id = message.chat.id;
+        if let Some(parent) = &message.reply_to_message
+            && !self.contains(chat_id, parent.message_id)
+        {
+            let mut node = ConversationNode::from_message(parent, false);
+            node.placeholder = true;
+            self.insert(chat_id, node);
+        }
+
+        let node = ConversationNode::from_message(message, outgoing);
+        let (message_id, reply_to) = (node.message_id, node.reply_to);
+        self.insert(chat_id, node);
+
+        if let Some(parent_id) = reply_to
+            && let Some(parent) = self
+                .chats
+                .get_mut(&chat_id)
+                .and_then(|chat| chat.get_mut(&parent_id))
+            && !parent.replies.contains(&message_id)
+        {
+            parent.replies.push(message_id);
+        }
+    }
+
+    /// Returns a message of a chat.
+    pub fn get(&self, chat_id: i64, message_id: i64) -> Option<&ConversationNode> {
+        self.chats.get(&chat_id)?.get(&message_id)
+    }
+
+    /// Returns the whole thread a message belongs to, depth-first from its root.
+    ///
+    /// The root is found by following replies up as far as they are known.
+    pub fn thread(&self, chat_id: i64, message_id: i64) -> Vec<ThreadRow<'_>> {
+        let Some(chat) = self.chats.get(&chat_id) else {
+            return Vec::new();
+        };
+        let Some(mut root) = chat.get(&message_id) else {
+            return Vec::new();
+        };
+
+        let mut visited = HashSet::from([root.message_id]);
+        while let Some(parent) = root.reply_to.and_then(|id| chat.get(&id)) {
+            if !visited.insert(parent.message_id) {
+                break;
+            }
+            root = parent;
+        }
+
+        let mut rows = Vec::new();
+        let mut stack = vec![(0, root)];
+        let mut emitted = HashSet::new();
+        while let Some((depth, node)) = stack.pop() {
+            if !emitted.insert(node.message_id) {
+                continue;
+            }
+            rows.push(ThreadRow { depth, node });
+            if depth >= MAX_THREAD_DEPTH {
+                continue;
+            }
+            for reply in node.replies.iter().rev() {
+                if let Some(child) = chat.get(reply) {
+                    stack.push((depth + 1, child));
+                }
+            }
+        }
+        rows
+    }
+
+    fn contains(&self, chat_id: i64, message_id: i64) -> bool {
+        self.get(chat_id, message_id).is_some()
+    }
+
+    fn insert(&mut self, chat_id: i64, mut node: ConversationNode) {
+        let chat = self.chats.entry(chat_id).or_default();
+        match chat.get_mut(&node.message_id) {
+            Some(known) => {
+                node.replies = std::mem::take(&mut known.replies);
+                node.outgoing |= known.outgoing;
+                *known = node;
+            }
+            None => {
+                self.order.push_back((chat_id, node.message_id));
+                chat.insert(node.message_id, node);
+                if self.order.len() > MAX_CONVERSATION_MESSAGES
+                    && let Some((old_chat, old_id)) = self.order.pop_front()
+                    && let Some(chat) = self.chats.get_mut(&old_chat)
+                {
+                    chat.remove(&old_id);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telegram::types::{Chat, User};
+
+    fn message(id: i64, from: &str, reply_to: Option<Message>) -> Message {
+        Message {
+            message_id: id,
+            from: Some(User {
+                id: 1,
+                is_bot: false,
+                first_name: from.to_string(),
+                last_name: None,
+                username: None,
+                can_join_groups: None,
+                can_read_all_group_messages: None,
+                supports_inline_queries: None,
+                language_code: None,
+                is_premium: None,
+            }),
+            chat: Chat {
+                id: -100,
+                chat_type: "supergroup".to_string(),
+                title: Some("Group".to_string()),
+                username: None,
+                first_name: None,
+                last_name: None,
+            },
+            date: 1000 + id,
+            text: Some(format!("message {id}")),
+            message_thread_id: None,
+            reply_to_message: reply_to.map(Box::new),
+            other: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_replies_form_a_thread() {
+        let mut conversations = Conversations::new();
+        let root = message(1, "Alice", None);
+        let reply = message(2, "Bob", Some(root.clone()));
+        let mut quoted = message(3, "Bot", Some(reply.clone()));
+        quoted.other.insert(
+            "quote".to_string(),
+            serde_json::json!({"text": "message", "position": 0}),
+        );
+        let second_reply = message(4, "Carol", Some(root.clone()));
+
+        conversations.record(&root, false);
+        conversations.record(&reply, false);
+        conversations.record(&quoted, true);
+        conversations.record(&second_reply, false);
+
+        let thread = conversations.thread(-100, 3);
+        let rows: Vec<(usize, i64)> = thread
+            .iter()
+            .map(|row| (row.depth, row.node.message_id))
+            .collect();
+        assert_eq!(rows, vec![(0, 1), (1, 2), (2, 3), (1, 4)]);
+
+        let bot_reply = conversations.get(-100, 3).unwrap();
+        assert!(bot_reply.outgoing);
+        assert_eq!(bot_reply.quote.as_deref(), Some("message"));
+        assert_eq!(conversations.get(-100, 1).unwrap().replies, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_unseen_parent_becomes_placeholder() {
+        let mut conversations = Conversations::new();
+        let parent = message(10, "Alice", None);
+        conversations.record(&message(11, "Bob", Some(parent.clone())), false);
+
+        let placeholder = conversations.get(-100, 10).unwrap();
+        assert!(placeholder.placeholder);
+        assert_eq!(placeholder.replies, vec![11]);
+
+        // Receiving the parent later keeps its replies
+        conversations.record(&parent, false);
+        let parent = conversations.get(-100, 10).unwrap();
+        assert!(!parent.placeholder);
+        assert_eq!(parent.replies, vec![11]);
+        assert_eq!(conversations.thread(-100, 10).len(), 2);
+    }
+}
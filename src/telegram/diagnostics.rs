@@ -0,0 +1,366 @@
+//! Bot permission and privacy-mode diagnostics.
+//!
+//! Combines `getMe`, `getWebhookInfo` and `getChatMember` results into
+//! checklists explaining why certain updates will or won't arrive.
+
+use std::fmt;
+
+use super::types::{ChatMember, User, WebhookInfo};
+
+/// Severity of a single diagnostic check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckLevel {
+    /// Works as expected
+    Ok,
+    /// Works, but some updates or actions are limited
+    Warning,
+    /// Blocks updates or actions entirely
+    Error,
+}
+
+impl CheckLevel {
+    /// Returns the symbol used in front of the check message.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            CheckLevel::Ok => "✓",
+            CheckLevel::Warning => "⚠",
+            CheckLevel::Error => "✗",
+        }
+    }
+}
+
+/// One line of a diagnostics checklist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckItem {
+    pub level: CheckLevel,
+    pub message: String,
+}
+
+impl CheckItem {
+    pub fn ok(message: impl Into<String>) -> Self {
+        Self {
+            level: CheckLevel::Ok,
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self {
+            level: CheckLevel::Warning,
+            message: message.into(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            level: CheckLevel::Error,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for CheckItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.level.symbol(), self.message)
+    }
+}
+
+/// Checklist for one discovered chat.
+#[derive(Debug, Clone)]
+pub struct ChatDiagnostics {
+    pub chat_id: i64,
+    pub chat_name: String,
+    pub chat_type: String,
+    pub checks: Vec<CheckItem>,
+}
+
+impl ChatDiagnostics {
+    /// Returns the most severe level of all checks.
+    pub fn worst_level(&self) -> CheckLevel {
+        worst_level(&self.checks)
+    }
+}
+
+/// Result of a full diagnostics run.
+#[derive(Debug, Clone)]
+pub struct BotDiagnostics {
+    /// Checks about the bot account and update delivery
+    pub general: Vec<CheckItem>,
+    /// Checks per discovered chat
+    pub chats: Vec<ChatDiagnostics>,
+    /// Unix timestamp of the run
+    pub generated_at: i64,
+}
+
+/// Returns the most severe level in a list of checks (`Ok` for an empty list).
+pub fn worst_level(checks: &[CheckItem]) -> CheckLevel {
+    if checks.iter().any(|c| c.level == CheckLevel::Error) {
+        CheckLevel::Error
+    } else if checks.iter().any(|c| c.level == CheckLevel::Warning) {
+        CheckLevel::Warning
+    } else {
+        CheckLevel::Ok
+    }
+}
+
+/// Checks the bot account (`getMe`) and update delivery (`getWebhookInfo`).
+///
+/// `webhook` is `None` when the webhook status could not be fetched.
+///
+/// # Examples
+///
+/// ```
+/// # use telegram_bot_debugger::telegram::diagnostics::{general_checks, CheckLevel};
+/// # use telegram_bot_debugger::telegram::User;
+/// let bot: User = serde_json::from_value(serde_json::json!({
+///     "id": 1, "is_bot": true, "first_name": "Bot",
+///     "can_join_groups": false, "can_read_all_group_messages": false,
+///     "supports_inline_queries": false
+/// })).unwrap();
+/// let checks = general_checks(&bot, None);
+/// assert!(checks.iter().any(|c| c.level == CheckLevel::Error));
+/// ```
+pub fn general_checks(bot: &User, webhook: Option<&WebhookInfo>) -> Vec<CheckItem> {
+    let mut checks = Vec::new();
+
+    match bot.can_join_groups {
+        Some(false) => checks.push(CheckItem::error(
+            "Joining groups is disabled in @BotFather - the bot can't be added to new groups",
+        )),
+        Some(true) => checks.push(CheckItem::ok("The bot can be added to groups")),
+        None => {}
+    }
+
+    match bot.can_read_all_group_messages {
+        Some(false) => checks.push(CheckItem::warning(
+            "Privacy mode is on - in groups where the bot isn't admin it only sees commands, \
+             replies to its messages and mentions",
+        )),
+        Some(true) => checks.push(CheckItem::ok(
+            "Privacy mode is off - the bot sees all group messages \
+             (re-add it to groups joined before the change)",
+        )),
+        None => {}
+    }
+
+    match bot.supports_inline_queries {
+        Some(false) => checks.push(CheckItem::warning(
+            "Inline mode is disabled - inline_query updates will never arrive",
+        )),
+        Some(true) => checks.push(CheckItem::ok("Inline mode is enabled")),
+        None => {}
+    }
+
+    match webhook {
+        None => checks.push(CheckItem::warning("Webhook status unknown")),
+        Some(info) if info.url.is_empty() => {
+            checks.push(CheckItem::ok(
+                "No webhook set - updates are fetched with getUpdates",
+            ));
+        }
+        Some(info) => {
+            checks.push(CheckItem::error(format!(
+                "Webhook set to {} - getUpdates polling (Live Monitor) receives nothing",
+                info.url
+            )));
+            if let Some(error) = &info.last_error_message {
+                checks.push(CheckItem::error(format!(
+                    "Last webhook delivery error: {error}"
+                )));
+            }
+        }
+    }
+
+    if let Some(info) = webhook {
+        if info.pending_update_count > 0 {
+            checks.push(CheckItem::warning(format!(
+                "{} updates are waiting to be delivered",
+                info.pending_update_count
+            )));
+        }
+        if let Some(allowed) = info.allowed_updates.as_ref().filter(|a| !a.is_empty()) {
+            let missing: Vec<&str> = ["message", "callback_query", "my_chat_member"]
+                .into_iter()
+                .filter(|kind| !allowed.iter().any(|a| a == kind))
+                .collect();
+            if !missing.is_empty() {
+                checks.push(CheckItem::warning(format!(
+                    "allowed_updates excludes {} - these updates are not delivered",
+                    missing.join(", ")
+                )));
+            }
+        }
+    }
+
+    checks
+}
+
+/// Checks what the bot's membership in a chat means for update delivery.
+///
+/// # Arguments
+///
+/// * `chat_type` - "private", "group", "supergroup" or "channel"
+/// * `member` - Result of `getChatMember` for the bot, or the error message
+/// * `can_read_all_group_messages` - Privacy mode setting from `getMe`, if known
+pub fn membership_checks(
+    chat_type: &str,
+    member: Result<&ChatMember, &str>,
+    can_read_all_group_messages: Option<bool>,
+) -> Vec<CheckItem> {
+    let member = match member {
+        Ok(member) => member,
+        Err(error) => return vec![CheckItem::error(format!("Bot membership unknown: {error}"))],
+    };
+
+    if !member.is_present() {
+        return vec![CheckItem::error(format!(
+            "Bot status is \"{}\" - no updates arrive from this chat",
+            member.status
+        ))];
+    }
+
+    let mut checks = Vec::new();
+    match chat_type {
+        "private" => checks.push(CheckItem::ok(
+            "Private chat - the bot receives all messages",
+        )),
+        "channel" if member.is_admin() => {
+            checks.push(CheckItem::ok(
+                "Bot is an administrator - receives channel posts",
+            ));
+            if member.can_post_messages == Some(false) {
+                checks.push(CheckItem::warning(
+                    "can_post_messages missing - the bot cannot post here",
+                ));
+            }
+        }
+        "channel" => checks.push(CheckItem::error(
+            "Bot is not an administrator - channel posts are not delivered",
+        )),
+        "group" | "supergroup" if member.is_admin() => checks.push(CheckItem::ok(
+            "Bot is an administrator - receives all messages regardless of privacy mode",
+        )),
+        "group" | "supergroup" => match can_read_all_group_messages {
+            Some(true) => checks.push(CheckItem::ok(
+                "Privacy mode is off - the bot receives all messages",
+            )),
+            Some(false) => checks.push(CheckItem::warning(
+                "Privacy mode is on and the bot isn't admin - only commands, \
+                 replies to its messages and mentions arrive",
+            )),
+            None => checks.push(CheckItem::warning(
+                "Bot is not an administrator - with privacy mode on it only sees commands, \
+                 replies to its messages and mentions",
+            )),
+        },
+        _ => {}
+    }
+
+    if matches!(chat_type, "group" | "supergroup") && !member.is_admin() {
+        checks.push(CheckItem::warning(
+            "chat_member updates require admin rights - member joins/leaves of others are not reported",
+        ));
+    }
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn bot(privacy_off: Option<bool>) -> User {
+        let mut value = json!({
+            "id": 1, "is_bot": true, "first_name": "Bot",
+            "can_join_groups": true, "supports_inline_queries": true
+        });
+        if let Some(privacy_off) = privacy_off {
+            value["can_read_all_group_messages"] = json!(privacy_off);
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn member(status: &str) -> ChatMember {
+        serde_json::from_value(json!({
+            "status": status,
+            "user": {"id": 1, "is_bot": true, "first_name": "Bot"}
+        }))
+        .unwrap()
+    }
+
+    fn webhook(url: &str) -> WebhookInfo {
+        serde_json::from_value(json!({
+            "url": url,
+            "has_custom_certificate": false,
+            "pending_update_count": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_general_checks_with_webhook_set() {
+        let mut info = webhook("https://example.com/hook");
+        info.last_error_message = Some("Connection refused".to_string());
+        info.pending_update_count = 3;
+
+        let checks = general_checks(&bot(Some(true)), Some(&info));
+        let errors: Vec<_> = checks
+            .iter()
+            .filter(|c| c.level == CheckLevel::Error)
+            .collect();
+        assert_eq!(errors.len(), 2);
+        assert!(checks.iter().any(|c| c.message.contains("3 updates")));
+    }
+
+    #[test]
+    fn test_general_checks_with_polling_and_privacy_on() {
+        let checks = general_checks(&bot(Some(false)), Some(&webhook("")));
+        assert!(checks.iter().any(|c| c.message.contains("getUpdates")));
+        assert!(
+            checks
+                .iter()
+                .any(|c| c.level == CheckLevel::Warning && c.message.contains("Privacy mode is on"))
+        );
+    }
+
+    #[test]
+    fn test_general_checks_reports_filtered_allowed_updates() {
+        let mut info = webhook("");
+        info.allowed_updates = Some(vec!["message".to_string()]);
+        let checks = general_checks(&bot(None), Some(&info));
+        assert!(checks.iter().any(|c| c.message.contains("callback_query")));
+    }
+
+    #[test]
+    fn test_membership_checks_group_privacy() {
+        let checks = membership_checks("supergroup", Ok(&member("member")), Some(false));
+        assert_eq!(worst_level(&checks), CheckLevel::Warning);
+        assert!(checks[0].message.contains("Privacy mode is on"));
+
+        let checks = membership_checks("supergroup", Ok(&member("member")), Some(true));
+        assert_eq!(checks[0].level, CheckLevel::Ok);
+
+        let checks = membership_checks("group", Ok(&member("administrator")), Some(false));
+        assert_eq!(worst_level(&checks), CheckLevel::Ok);
+    }
+
+    #[test]
+    fn test_membership_checks_blocking_cases() {
+        let checks = membership_checks("group", Ok(&member("left")), None);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].level, CheckLevel::Error);
+
+        let checks = membership_checks("channel", Ok(&member("member")), None);
+        assert_eq!(worst_level(&checks), CheckLevel::Error);
+
+        let checks = membership_checks("private", Err("chat not found"), None);
+        assert!(checks[0].message.contains("chat not found"));
+    }
+
+    #[test]
+    fn test_check_item_display() {
+        assert_eq!(CheckItem::warning("careful").to_string(), "⚠ careful");
+        assert_eq!(worst_level(&[]), CheckLevel::Ok);
+    }
+}
@@ -0,0 +1,386 @@
+//! Detection of Bot API fields this tool doesn't know yet.
+//!
+//! [`Update`], [`Message`] and [`ChannelPost`] collect unmodeled fields in
+//! their flattened `other` maps, so new fields never fail to parse and go
+//! unnoticed. [`SchemaDrift`] compares those keys with the fields of the Bot
+//! API version this tool targets and keeps every unknown one with the first
+//! payload it was seen in.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::types::{ChannelPost, Message, Update};
+use super::update_kind::UpdateKind;
+
+/// Bot API version the known field lists are taken from.
+pub const KNOWN_API_VERSION: &str = "9.2";
+
+/// Fields of `Message` in the targeted Bot API version.
+///
+/// The `forward_*` fields were replaced by `forward_origin` in Bot API 7.0 but
+/// are still delivered, so they are listed too.
+pub const KNOWN_MESSAGE_FIELDS: [&str; 109] = [
+    "message_id",
+    "message_thread_id",
+    "direct_messages_topic",
+    "from",
+    "sender_chat",
+    "sender_boost_count",
+    "sender_business_bot",
+    "date",
+    "business_connection_id",
+    "chat",
+    "forward_origin",
+    "is_topic_message",
+    "is_automatic_forward",
+    "reply_to_message",
+    "external_reply",
+    "quote",
+    "reply_to_story",
+    "reply_to_checklist_task_id",
+    "via_bot",
+    "edit_date",
+    "has_protected_content",
+    "is_from_offline",
+    "is_paid_post",
+    "media_group_id",
+    "author_signature",
+    "paid_star_count",
+    "text",
+    "entities",
+    "link_preview_options",
+    "suggested_post_info",
+    "effect_id",
+    "animation",
+    "audio",
+    "document",
+    "paid_media",
+    "photo",
+    "sticker",
+    "story",
+    "video",
+    "video_note",
+    "voice",
+    "caption",
+    "caption_entities",
+    "show_caption_above_media",
+    "has_media_spoiler",
+    "checklist",
+    "contact",
+    "dice",
+    "game",
+    "poll",
+    "venue",
+    "location",
+    "new_chat_members",
+    "left_chat_member",
+    "new_chat_title",
+    "new_chat_photo",
+    "delete_chat_photo",
+    "group_chat_created",
+    "supergroup_chat_created",
+    "channel_chat_created",
+    "message_auto_delete_timer_changed",
+    "migrate_to_chat_id",
+    "migrate_from_chat_id",
+    "pinned_message",
+    "invoice",
+    "successful_payment",
+    "refunded_payment",
+    "users_shared",
+    "chat_shared",
+    "gift",
+    "unique_gift",
+    "connected_website",
+    "write_access_allowed",
+    "passport_data",
+    "proximity_alert_triggered",
+    "boost_added",
+    "chat_background_set",
+    "checklist_tasks_done",
+    "checklist_tasks_added",
+    "direct_message_price_changed",
+    "forum_topic_created",
+    "forum_topic_edited",
+    "forum_topic_closed",
+    "forum_topic_reopened",
+    "general_forum_topic_hidden",
+    "general_forum_topic_unhidden",
+    "giveaway_created",
+    "giveaway",
+    "giveaway_winners",
+    "giveaway_completed",
+    "paid_message_price_changed",
+    "suggested_post_approved",
+    "suggested_post_approval_failed",
+    "suggested_post_declined",
+    "suggested_post_paid",
+    "suggested_post_refunded",
+    "video_chat_scheduled",
+    "video_chat_started",
+    "video_chat_ended",
+    "video_chat_participants_invited",
+    "web_app_data",
+    "reply_markup",
+    "forward_from",
+    "forward_from_chat",
+    "forward_from_message_id",
+    "forward_signature",
+    "forward_sender_name",
+    "forward_date",
+    "user_shared",
+];
+
+/// An unknown field and where it was first seen.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriftEntry {
+    /// Type the field appeared in: `Update`, `Message` or `ChannelPost`
+    pub object: String,
+    pub field: String,
+    /// Update the field was first seen in
+    pub first_update_id: i64,
+    /// Unix time the field was first seen
+    pub first_seen: i64,
+    /// Number of objects the field was seen in
+    pub occurrences: usize,
+    /// Value of the field in the first object it was seen in
+    pub sample: Value,
+}
+
+impl DriftEntry {
+    /// Returns `Object.field`.
+    pub fn path(&self) -> String {
+        format!("{}.{}", self.object, self.field)
+    }
+}
+
+/// Unknown fields seen so far, in the order they first appeared.
+#[derive(Debug, Default)]
+pub struct SchemaDrift {
+    entries: Vec<DriftEntry>,
+    /// Index into `entries` by `(object, field)`
+    index: HashMap<(String, String), usize>,
+}
+
+impl SchemaDrift {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks an update, its messages and their replied-to messages for unknown fields.
+    ///
+    /// Keys of `Update::other` that are no [`UpdateKind`] this version models
+    /// are reported as unknown `Update` fields.
+    pub fn record(&mut self, update: &Update, now: i64) {
+        for message in [&update.message, &update.edited_message]
+            .into_iter()
+            .flatten()
+        {
+            self.record_message(message, update.update_id, now);
+        }
+        if let Some(post) = &update.channel_post {
+            self.record_channel_post(post, update.update_id, now);
+        }
+        for (key, value) in &update.other {
+            match UpdateKind::from_other(key, value) {
+                kind @ UpdateKind::Unknown { .. } if !kind.is_unparsed() => {
+                    self.add("Update", key, value, update.update_id, now);
+                }
+                UpdateKind::EditedChannelPost(post) => {
+                    self.record_fields("ChannelPost", &post.other, update.update_id, now);
+                }
+                UpdateKind::BusinessMessage(message)
+                | UpdateKind::EditedBusinessMessage(message) => {
+                    self.record_message(&message, update.update_id, now);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns all unknown fields, oldest first.
+    pub fn entries(&self) -> &[DriftEntry] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Formats the unknown fields as a plain text report.
+    pub fn report(&self) -> String {
+        if self.entries.is_empty() {
+            return format!("No fields unknown to Bot API {KNOWN_API_VERSION} found.\n");
+        }
+        let mut report = format!(
+            "{} field(s) unknown to Bot API {KNOWN_API_VERSION}:\n",
+            self.entries.len()
+        );
+        for entry in &self.entries {
+            let first_seen = chrono::DateTime::from_timestamp(entry.first_seen, 0)
+                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_else(|| "Unknown".to_string());
+            report.push_str(&format!(
+                "\n{}\n  first seen: {first_seen} (update {}), {} time(s)\n  sample: {}\n",
+                entry.path(),
+                entry.first_update_id,
+                entry.occurrences,
+                entry.sample
+            ));
+        }
+        report
+    }
+
+    fn record_message(&mut self, message: &Message, update_id: i64, now: i64) {
+        self.record_fields("Message", &message.other, update_id, now);
+        if let Some(reply) = &message.reply_to_message {
+            self.record_message(reply, update_id, now);
+        }
+    }
+
+    fn record_channel_post(&mut self, post: &ChannelPost, update_id: i64, now: i64) {
+        self.record_fields("ChannelPost", &post.other, update_id, now);
+    }
+
+    fn record_fields(
+        &mut self,
+        object: &str,
+        other: &HashMap<String, Value>,
+        update_id: i64,
+        now: i64,
+    ) {
+        for (key, value) in other {
+            if !KNOWN_MESSAGE_FIELDS.contains(&key.as_str()) {
+                self.add(object, key, value, update_id, now);
+            }
+        }
+    }
+
+    fn add(&mut self, object: &str, field: &str, value: &Value, update_id: i64, now: i64) {
+        let key = (object.to_string(), field.to_string());
+        match self.index.get(&key) {
+            Some(&position) => self.entries[position].occurrences += 1,
+            None => {
+                self.index.insert(key, self.entries.len());
+                self.entries.push(DriftEntry {
+                    object: object.to_string(),
+                    field: field.to_string(),
+                    first_update_id: update_id,
+                    first_seen: now,
+                    occurrences: 1,
+                    sample: value.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Parses updates from JSON: a single update, an array of updates, or a
+/// `getUpdates` response.
+pub fn parse_updates(value: Value) -> anyhow::Result<Vec<Update>> {
+    let value = match value {
+        Value::Object(mut object) if object.contains_key("ok") => {
+            object.remove("result").unwrap_or(Value::Array(Vec::new()))
+        }
+        value => value,
+    };
+    Ok(match value {
+        Value::Array(items) => items
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<_, _>>()?,
+        value => vec![serde_json::from_value(value)?],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn update(value: Value) -> Update {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_unknown_fields_are_recorded_once() {
+        let mut drift = SchemaDrift::new();
+        let first = update(json!({
+            "update_id": 1,
+            "message": {
+                "message_id": 1,
+                "chat": {"id": 1, "type": "private"},
+                "date": 1000,
+                "text": "hi",
+                "entities": [],
+                "shiny_new_field": {"a": 1},
+                "reply_to_message": {
+                    "message_id": 0,
+                    "chat": {"id": 1, "type": "private"},
+                    "date": 900,
+                    "nested_new_field": true
+                }
+            },
+            "future_update": {"x": 1}
+        }));
+        let mut second = first.clone();
+        second.update_id = 2;
+
+        drift.record(&first, 5000);
+        drift.record(&second, 6000);
+
+        let paths: Vec<String> = drift.entries().iter().map(DriftEntry::path).collect();
+        assert_eq!(paths.len(), 3);
+        assert!(paths.contains(&"Update.future_update".to_string()));
+        assert!(paths.contains(&"Message.nested_new_field".to_string()));
+
+        let entry = drift
+            .entries()
+            .iter()
+            .find(|e| e.field == "shiny_new_field")
+            .unwrap();
+        assert_eq!(entry.first_update_id, 1);
+        assert_eq!(entry.first_seen, 5000);
+        assert_eq!(entry.occurrences, 2);
+        assert_eq!(entry.sample, json!({"a": 1}));
+        assert!(drift.report().contains("Message.shiny_new_field"));
+    }
+
+    #[test]
+    fn test_known_fields_and_update_kinds_are_not_drift() {
+        let mut drift = SchemaDrift::new();
+        drift.record(
+            &update(json!({
+                "update_id": 1,
+                "callback_query": {"id": "1"},
+                "edited_channel_post": {
+                    "message_id": 1,
+                    "chat": {"id": -100, "type": "channel"},
+                    "date": 1000,
+                    "photo": [],
+                    "edit_date": 1001
+                }
+            })),
+            0,
+        );
+        assert!(drift.is_empty());
+    }
+
+    #[test]
+    fn test_parse_updates_accepts_all_shapes() {
+        let single = json!({"update_id": 1});
+        assert_eq!(parse_updates(single.clone()).unwrap().len(), 1);
+        assert_eq!(
+            parse_updates(json!([single, {"update_id": 2}]))
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            parse_updates(json!({"ok": true, "result": [{"update_id": 3}]})).unwrap()[0].update_id,
+            3
+        );
+    }
+}
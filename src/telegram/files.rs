@@ -0,0 +1,273 @@
+//! File references embedded in messages.
+//!
+//! Media (photos, documents, stickers, voice notes, ...) arrive as nested objects
+//! carrying a `file_id`. This module finds those objects so they can be resolved
+//! with `getFile` and downloaded.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::types::Update;
+
+/// Maximum size of a file that can be downloaded through the cloud Bot API (20 MB).
+pub const MAX_DOWNLOAD_FILE_SIZE: u64 = 20 * 1024 * 1024;
+
+/// A file ready to be downloaded, as returned by the `getFile` API method.
+///
+/// # Fields
+///
+/// * `file_id` - Identifier that can be used to download or reuse the file
+/// * `file_unique_id` - Identifier that is the same for all bots and over time
+/// * `file_size` - Optional file size in bytes
+/// * `file_path` - Optional path to use with the file download URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct File {
+    pub file_id: String,
+    pub file_unique_id: String,
+    pub file_size: Option<u64>,
+    pub file_path: Option<String>,
+}
+
+/// A `file_id` found inside a message payload.
+///
+/// # Fields
+///
+/// * `location` - JSON path of the object inside the message (e.g. `photo[2]`)
+/// * `file_id` - The file identifier
+/// * `file_unique_id` - Optional stable identifier
+/// * `file_size` - Optional size reported in the message
+/// * `mime_type` - Optional MIME type reported in the message
+/// * `file_name` - Optional original file name (documents, audio, video)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileReference {
+    pub location: String,
+    pub file_id: String,
+    pub file_unique_id: Option<String>,
+    pub file_size: Option<u64>,
+    pub mime_type: Option<String>,
+    pub file_name: Option<String>,
+}
+
+impl FileReference {
+    /// Returns true if the size reported in the message exceeds the download
+    /// `limit`; `None` means the file server has no limit.
+    pub fn exceeds_download_limit(&self, limit: Option<u64>) -> bool {
+        self.file_size
+            .zip(limit)
+            .is_some_and(|(size, limit)| size > limit)
+    }
+}
+
+/// Recursively collects every object carrying a `file_id` from a JSON value.
+///
+/// `root` is used as the prefix of [`FileReference::location`].
+///
+/// # Examples
+///
+/// ```
+/// # use telegram_bot_debugger::telegram::files::collect_file_references;
+/// let message = serde_json::json!({
+///     "document": {"file_id": "BQAC", "file_name": "report.pdf", "mime_type": "application/pdf"}
+/// });
+/// let refs = collect_file_references("message", &message);
+/// assert_eq!(refs.len(), 1);
+/// assert_eq!(refs[0].location, "message.document");
+/// ```
+pub fn collect_file_references(root: &str, value: &Value) -> Vec<FileReference> {
+    let mut references = Vec::new();
+    collect_into(root, value, &mut references);
+    references
+}
+
+/// Collects every distinct `file_id` contained in an update.
+///
+/// Locations are relative to the update (e.g. `message.photo[1]`).
+pub fn update_file_references(update: &Update) -> Vec<FileReference> {
+    let Ok(Value::Object(fields)) = serde_json::to_value(update) else {
+        return Vec::new();
+    };
+
+    let mut references = Vec::new();
+    for (key, value) in &fields {
+        collect_into(key, value, &mut references);
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    references.retain(|reference| seen.insert(reference.file_id.clone()));
+    references
+}
+
+fn collect_into(location: &str, value: &Value, references: &mut Vec<FileReference>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(file_id) = map.get("file_id").and_then(Value::as_str) {
+                let text = |key: &str| map.get(key).and_then(Value::as_str).map(str::to_string);
+                references.push(FileReference {
+                    location: location.to_string(),
+                    file_id: file_id.to_string(),
+                    file_unique_id: text("file_unique_id"),
+                    file_size: map.get("file_size").and_then(Value::as_u64),
+                    mime_type: text("mime_type"),
+                    file_name: text("file_name"),
+                });
+            }
+            for (key, child) in map {
+                collect_into(&format!("{location}.{key}"), child, references);
+            }
+        }
+        Value::Array(items) => {
+            for (i, child) in items.iter().enumerate() {
+                collect_into(&format!("{location}[{i}]"), child, references);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Guesses a MIME type from the extension of a Telegram file path.
+///
+/// Photos and stickers do not carry a `mime_type`, but their `file_path` does
+/// have a meaningful extension.
+pub fn guess_mime_type(file_path: &str) -> Option<&'static str> {
+    let extension = file_path.rsplit_once('.')?.1.to_ascii_lowercase();
+    let mime = match extension.as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "tgs" => "application/x-tgsticker",
+        "oga" | "ogg" => "audio/ogg",
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "pdf" => "application/pdf",
+        "txt" => "text/plain",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        _ => return None,
+    };
+    Some(mime)
+}
+
+/// Formats a byte count for display (e.g. `1.5 MB`).
+pub fn format_file_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    let value = bytes as f64;
+    if value >= MB {
+        format!("{:.1} MB", value / MB)
+    } else if value >= KB {
+        format!("{:.1} KB", value / KB)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_collect_photo_sizes() {
+        let message = json!({
+            "photo": [
+                {"file_id": "small", "file_unique_id": "s", "file_size": 1000, "width": 90, "height": 90},
+                {"file_id": "large", "file_unique_id": "l", "file_size": 50000, "width": 1280, "height": 720}
+            ]
+        });
+
+        let refs = collect_file_references("message", &message);
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].location, "message.photo[0]");
+        assert_eq!(refs[1].file_id, "large");
+        assert_eq!(refs[1].file_size, Some(50000));
+    }
+
+    #[test]
+    fn test_collect_nested_thumbnails() {
+        let message = json!({
+            "document": {
+                "file_id": "doc",
+                "mime_type": "video/mp4",
+                "thumbnail": {"file_id": "thumb"}
+            }
+        });
+
+        let refs = collect_file_references("message", &message);
+        let ids: Vec<&str> = refs.iter().map(|r| r.file_id.as_str()).collect();
+        assert!(ids.contains(&"doc"));
+        assert!(ids.contains(&"thumb"));
+        let doc = refs.iter().find(|r| r.file_id == "doc").unwrap();
+        assert_eq!(doc.mime_type.as_deref(), Some("video/mp4"));
+    }
+
+    #[test]
+    fn test_collect_without_files() {
+        let message = json!({"text": "hello", "entities": []});
+        assert!(collect_file_references("message", &message).is_empty());
+    }
+
+    #[test]
+    fn test_update_file_references() {
+        let update: Update = serde_json::from_value(json!({
+            "update_id": 1,
+            "message": {
+                "message_id": 10,
+                "chat": {"id": 100, "type": "private"},
+                "date": 0,
+                "photo": [{"file_id": "p1"}, {"file_id": "p2"}],
+                "reply_to_message": {
+                    "message_id": 9,
+                    "chat": {"id": 100, "type": "private"},
+                    "date": 0,
+                    "photo": [{"file_id": "p1"}]
+                }
+            }
+        }))
+        .unwrap();
+
+        let refs = update_file_references(&update);
+        let ids: Vec<&str> = refs.iter().map(|r| r.file_id.as_str()).collect();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains(&"p1"));
+        assert!(ids.contains(&"p2"));
+        assert!(refs.iter().all(|r| r.location.starts_with("message.")));
+    }
+
+    #[test]
+    fn test_exceeds_download_limit() {
+        let mut reference = FileReference {
+            location: "message.video".to_string(),
+            file_id: "v".to_string(),
+            file_unique_id: None,
+            file_size: Some(MAX_DOWNLOAD_FILE_SIZE),
+            mime_type: None,
+            file_name: None,
+        };
+        assert!(!reference.exceeds_download_limit(Some(MAX_DOWNLOAD_FILE_SIZE)));
+
+        reference.file_size = Some(MAX_DOWNLOAD_FILE_SIZE + 1);
+        assert!(reference.exceeds_download_limit(Some(MAX_DOWNLOAD_FILE_SIZE)));
+
+        assert!(!reference.exceeds_download_limit(None));
+
+        reference.file_size = None;
+        assert!(!reference.exceeds_download_limit(Some(MAX_DOWNLOAD_FILE_SIZE)));
+    }
+
+    #[test]
+    fn test_guess_mime_type() {
+        assert_eq!(guess_mime_type("photos/file_1.jpg"), Some("image/jpeg"));
+        assert_eq!(guess_mime_type("voice/file_2.OGA"), Some("audio/ogg"));
+        assert_eq!(guess_mime_type("documents/file_3"), None);
+    }
+
+    #[test]
+    fn test_format_file_size() {
+        assert_eq!(format_file_size(512), "512 B");
+        assert_eq!(format_file_size(2048), "2.0 KB");
+        assert_eq!(format_file_size(MAX_DOWNLOAD_FILE_SIZE), "20.0 MB");
+    }
+}
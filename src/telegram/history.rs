@@ -0,0 +1,301 @@
+//! Edit history of messages and channel posts.
+//!
+//! Every message is kept with its original version and all later edits
+//! (`edited_message` / `edited_channel_post`), keyed by chat and message ID,
+//! so that revisions can be compared word by word.
+
+use std::collections::{HashMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Number of messages whose history is kept; the oldest are dropped first.
+const MAX_TRACKED_MESSAGES: usize = 5000;
+
+/// Number of revisions kept per message (the original is always kept).
+const MAX_REVISIONS: usize = 50;
+
+/// Above this many token comparisons the diff falls back to a full replacement.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// One version of a message: the original or an edit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageRevision {
+    /// Update that delivered this version
+    pub update_id: i64,
+    /// Date the message was originally sent
+    pub date: i64,
+    /// Date of the edit; `None` for the original version
+    pub edit_date: Option<i64>,
+    /// Text, or the caption of a media message
+    pub text: Option<String>,
+    /// `entities`, or `caption_entities` of a media message
+    pub entities: Vec<Value>,
+}
+
+impl MessageRevision {
+    /// Builds a revision from the fields of a message or channel post.
+    pub fn new(
+        update_id: i64,
+        date: i64,
+        text: Option<&str>,
+        other: &HashMap<String, Value>,
+    ) -> Self {
+        let text = text
+            .or_else(|| other.get("caption").and_then(Value::as_str))
+            .map(str::to_string);
+        let entities = other
+            .get("entities")
+            .or_else(|| other.get("caption_entities"))
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        Self {
+            update_id,
+            date,
+            edit_date: other.get("edit_date").and_then(Value::as_i64),
+            text,
+            entities,
+        }
+    }
+
+    /// Returns `true` if this version came from an edit update.
+    pub fn is_edit(&self) -> bool {
+        self.edit_date.is_some()
+    }
+}
+
+/// Revisions of all tracked messages, keyed by `(chat_id, message_id)`.
+#[derive(Debug, Default)]
+pub struct MessageHistory {
+    revisions: HashMap<(i64, i64), Vec<MessageRevision>>,
+    /// Tracked messages in the order they were first seen
+    order: VecDeque<(i64, i64)>,
+}
+
+impl MessageHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a revision to the history of a message.
+    ///
+    /// A revision delivered twice (same update ID) is ignored. When the
+    /// history is full, the oldest edit after the original is dropped.
+    pub fn record(&mut self, chat_id: i64, message_id: i64, revision: MessageRevision) {
+        let key = (chat_id, message_id);
+        if !self.revisions.contains_key(&key) {
+            if self.order.len() >= MAX_TRACKED_MESSAGES
+                && let Some(oldest) = self.order.pop_front()
+            {
+                self.revisions.remove(&oldest);
+            }
+            self.order.push_back(key);
+        }
+
+        let revisions = self.revisions.entry(key).or_default();
+        if revisions
+            .iter()
+            .any(|known| known.update_id == revision.update_id)
+        {
+            return;
+        }
+        revisions.push(revision);
+        if revisions.len() > MAX_REVISIONS {
+            revisions.remove(1);
+        }
+    }
+
+    /// Returns all known revisions of a message, oldest first.
+    pub fn get(&self, chat_id: i64, message_id: i64) -> Option<&[MessageRevision]> {
+        self.revisions
+            .get(&(chat_id, message_id))
+            .map(Vec::as_slice)
+    }
+
+    /// Returns the number of edits seen for a message.
+    pub fn edit_count(&self, chat_id: i64, message_id: i64) -> usize {
+        self.get(chat_id, message_id)
+            .map(|revisions| revisions.iter().filter(|r| r.is_edit()).count())
+            .unwrap_or(0)
+    }
+}
+
+/// A piece of a word-level diff between two texts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffChunk {
+    Same(String),
+    Added(String),
+    Removed(String),
+}
+
+/// Computes a word-level diff from `old` to `new`.
+///
+/// Whitespace is kept as separate tokens so the chunks concatenate back to
+/// the original texts. Adjacent chunks of the same kind are merged.
+pub fn diff_text(old: &str, new: &str) -> Vec<DiffChunk> {
+    let old_tokens = tokenize(old);
+    let new_tokens = tokenize(new);
+    let (n, m) = (old_tokens.len(), new_tokens.len());
+
+    let mut chunks = Vec::new();
+    if n.saturating_mul(m) > MAX_DIFF_CELLS {
+        push_chunk(&mut chunks, DiffChunk::Removed(old.to_string()));
+        push_chunk(&mut chunks, DiffChunk::Added(new.to_string()));
+        return chunks;
+    }
+
+    // lcs[i][j] = length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old_tokens[i] == new_tokens[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && old_tokens[i] == new_tokens[j] {
+            push_chunk(&mut chunks, DiffChunk::Same(old_tokens[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            push_chunk(&mut chunks, DiffChunk::Removed(old_tokens[i].to_string()));
+            i += 1;
+        } else {
+            push_chunk(&mut chunks, DiffChunk::Added(new_tokens[j].to_string()));
+            j += 1;
+        }
+    }
+    chunks
+}
+
+/// Returns the entities removed from and added to `old` in `new`, as short
+/// descriptions like `bold @0+5`.
+pub fn diff_entities(old: &[Value], new: &[Value]) -> (Vec<String>, Vec<String>) {
+    let removed = old
+        .iter()
+        .filter(|entity| !new.contains(entity))
+        .map(describe_entity)
+        .collect();
+    let added = new
+        .iter()
+        .filter(|entity| !old.contains(entity))
+        .map(describe_entity)
+        .collect();
+    (removed, added)
+}
+
+/// Describes an entity by type, UTF-16 offset and length, plus its URL if any.
+pub fn describe_entity(entity: &Value) -> String {
+    let kind = entity.get("type").and_then(Value::as_str).unwrap_or("?");
+    let offset = entity.get("offset").and_then(Value::as_i64).unwrap_or(0);
+    let length = entity.get("length").and_then(Value::as_i64).unwrap_or(0);
+    match entity.get("url").and_then(Value::as_str) {
+        Some(url) => format!("{kind} @{offset}+{length} {url}"),
+        None => format!("{kind} @{offset}+{length}"),
+    }
+}
+
+/// Splits a text into alternating runs of whitespace and non-whitespace.
+fn tokenize(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut in_space = None;
+    for (index, c) in text.char_indices() {
+        let space = c.is_whitespace();
+        if in_space.is_some_and(|previous| previous != space) {
+            tokens.push(&text[start..index]);
+            start = index;
+        }
+        in_space = Some(space);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+fn push_chunk(chunks: &mut Vec<DiffChunk>, chunk: DiffChunk) {
+    match (chunks.last_mut(), chunk) {
+        (Some(DiffChunk::Same(last)), DiffChunk::Same(text))
+        | (Some(DiffChunk::Added(last)), DiffChunk::Added(text))
+        | (Some(DiffChunk::Removed(last)), DiffChunk::Removed(text)) => last.push_str(&text),
+        (_, chunk) => chunks.push(chunk),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn revision(update_id: i64, edit_date: Option<i64>, text: &str) -> MessageRevision {
+        MessageRevision {
+            update_id,
+            date: 1000,
+            edit_date,
+            text: Some(text.to_string()),
+            entities: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_diff_text_marks_changed_words() {
+        let chunks = diff_text("hello old world", "hello new world!");
+        assert_eq!(
+            chunks,
+            vec![
+                DiffChunk::Same("hello ".to_string()),
+                DiffChunk::Removed("old".to_string()),
+                DiffChunk::Added("new".to_string()),
+                DiffChunk::Same(" ".to_string()),
+                DiffChunk::Removed("world".to_string()),
+                DiffChunk::Added("world!".to_string()),
+            ]
+        );
+
+        assert_eq!(
+            diff_text("same", "same"),
+            vec![DiffChunk::Same("same".to_string())]
+        );
+        assert_eq!(
+            diff_text("", "new"),
+            vec![DiffChunk::Added("new".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_diff_entities() {
+        let bold = json!({"type": "bold", "offset": 0, "length": 5});
+        let link = json!({"type": "text_link", "offset": 6, "length": 4, "url": "https://x.y"});
+        let (removed, added) = diff_entities(std::slice::from_ref(&bold), &[bold.clone(), link]);
+        assert!(removed.is_empty());
+        assert_eq!(added, vec!["text_link @6+4 https://x.y".to_string()]);
+    }
+
+    #[test]
+    fn test_history_keeps_original_and_skips_duplicates() {
+        let mut history = MessageHistory::new();
+        history.record(1, 10, revision(1, None, "original"));
+        history.record(1, 10, revision(2, Some(1100), "edit"));
+        history.record(1, 10, revision(2, Some(1100), "edit"));
+
+        let revisions = history.get(1, 10).unwrap();
+        assert_eq!(revisions.len(), 2);
+        assert!(!revisions[0].is_edit());
+        assert_eq!(history.edit_count(1, 10), 1);
+
+        for update_id in 3..100 {
+            history.record(1, 10, revision(update_id, Some(1100 + update_id), "more"));
+        }
+        let revisions = history.get(1, 10).unwrap();
+        assert_eq!(revisions.len(), MAX_REVISIONS);
+        assert_eq!(revisions[0].text.as_deref(), Some("original"));
+        assert!(history.get(1, 11).is_none());
+    }
+}
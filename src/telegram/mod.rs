@@ -24,9 +24,26 @@
 //! ```
 
 pub mod client;
+pub mod commands;
+pub mod content;
+pub mod conversation;
+pub mod diagnostics;
+pub mod drift;
+pub mod files;
+pub mod history;
+pub mod profile;
 pub mod types;
+pub mod update_kind;
 pub mod updates;
+pub mod wire;
 
 pub use client::TelegramClient;
+pub use content::{EntityKind, MessageContent, MessageEntity, ServiceMessage};
+pub use conversation::{ConversationNode, Conversations, ThreadRow};
+pub use drift::{DriftEntry, SchemaDrift};
+pub use files::{File, FileReference, MAX_DOWNLOAD_FILE_SIZE};
+pub use history::{DiffChunk, MessageHistory, MessageRevision};
 pub use types::*;
+pub use update_kind::UpdateKind;
 pub use updates::UpdateProcessor;
+pub use wire::{WireCapture, WireLog};
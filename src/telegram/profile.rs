@@ -0,0 +1,426 @@
+//! Bot profile settings: name, descriptions, menu button and default admin rights.
+//!
+//! Names and descriptions are stored per language by Telegram; an empty
+//! language code holds the value shown to users without a dedicated one.
+
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+
+use super::types::{ChatAdministratorRights, MenuButton, User, WebAppInfo};
+
+/// A per-language text field of the bot profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProfileField {
+    /// Name shown in chats and search (`setMyName`)
+    Name,
+    /// Text shown in an empty chat with the bot (`setMyDescription`)
+    Description,
+    /// Text shown on the profile page and in shares (`setMyShortDescription`)
+    ShortDescription,
+}
+
+impl ProfileField {
+    pub const ALL: [ProfileField; 3] = [
+        ProfileField::Name,
+        ProfileField::Description,
+        ProfileField::ShortDescription,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ProfileField::Name => "Name",
+            ProfileField::Description => "Description",
+            ProfileField::ShortDescription => "Short description",
+        }
+    }
+
+    /// Maximum length accepted by the Bot API, in characters.
+    pub fn max_length(self) -> usize {
+        match self {
+            ProfileField::Name => 64,
+            ProfileField::Description => 512,
+            ProfileField::ShortDescription => 120,
+        }
+    }
+}
+
+/// One editable row of the Bot Profile screen.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProfileRow {
+    Text {
+        field: ProfileField,
+        language_code: String,
+    },
+    /// Default menu button of private chats
+    MenuButton,
+    /// Default administrator rights for groups or channels
+    AdministratorRights { for_channels: bool },
+}
+
+impl ProfileRow {
+    pub fn label(&self) -> String {
+        match self {
+            ProfileRow::Text {
+                field,
+                language_code,
+            } if language_code.is_empty() => format!("{} [all]", field.label()),
+            ProfileRow::Text {
+                field,
+                language_code,
+            } => format!("{} [{language_code}]", field.label()),
+            ProfileRow::MenuButton => "Menu button (default)".to_string(),
+            ProfileRow::AdministratorRights {
+                for_channels: false,
+            } => "Admin rights (groups)".to_string(),
+            ProfileRow::AdministratorRights { for_channels: true } => {
+                "Admin rights (channels)".to_string()
+            }
+        }
+    }
+
+    /// Returns the input format shown while editing the row.
+    pub fn edit_hint(&self) -> String {
+        match self {
+            ProfileRow::Text { field, .. } => format!(
+                "{} (max {} chars, empty to remove) Enter/Esc",
+                field.label(),
+                field.max_length()
+            ),
+            ProfileRow::MenuButton => {
+                "default | commands | web_app <text> <https url> (Enter/Esc)".to_string()
+            }
+            ProfileRow::AdministratorRights { .. } => {
+                "Granted rights, e.g. delete_messages, pin_messages (Enter/Esc)".to_string()
+            }
+        }
+    }
+}
+
+/// Everything shown on the Bot Profile screen.
+#[derive(Debug, Clone)]
+pub struct BotProfile {
+    /// Result of `getMe`
+    pub bot: Option<User>,
+    /// Language columns; the empty code is always first
+    pub languages: Vec<String>,
+    texts: HashMap<(ProfileField, String), String>,
+    pub menu_button: Option<MenuButton>,
+    pub group_rights: Option<ChatAdministratorRights>,
+    pub channel_rights: Option<ChatAdministratorRights>,
+    /// Why the last fetch of a row failed
+    errors: HashMap<ProfileRow, String>,
+    /// Unix timestamp of the last load
+    pub loaded_at: Option<i64>,
+}
+
+impl BotProfile {
+    pub fn new() -> Self {
+        Self {
+            bot: None,
+            languages: vec![String::new()],
+            texts: HashMap::new(),
+            menu_button: None,
+            group_rights: None,
+            channel_rights: None,
+            errors: HashMap::new(),
+            loaded_at: None,
+        }
+    }
+
+    /// Adds a language if it is not present yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error unless the code is two lowercase ASCII letters.
+    pub fn add_language(&mut self, language_code: &str) -> Result<()> {
+        let code = language_code.trim();
+        if code.len() != 2 || !code.chars().all(|c| c.is_ascii_lowercase()) {
+            bail!("\"{code}\" is not a two-letter ISO 639-1 language code");
+        }
+        if !self.languages.iter().any(|l| l == code) {
+            self.languages.push(code.to_string());
+        }
+        Ok(())
+    }
+
+    pub fn set_text(&mut self, field: ProfileField, language_code: &str, value: String) {
+        self.errors.remove(&ProfileRow::Text {
+            field,
+            language_code: language_code.to_string(),
+        });
+        self.texts.insert((field, language_code.to_string()), value);
+    }
+
+    pub fn set_menu_button(&mut self, button: MenuButton) {
+        self.errors.remove(&ProfileRow::MenuButton);
+        self.menu_button = Some(button);
+    }
+
+    pub fn set_administrator_rights(
+        &mut self,
+        for_channels: bool,
+        rights: ChatAdministratorRights,
+    ) {
+        self.errors
+            .remove(&ProfileRow::AdministratorRights { for_channels });
+        if for_channels {
+            self.channel_rights = Some(rights);
+        } else {
+            self.group_rights = Some(rights);
+        }
+    }
+
+    /// Records that fetching a row failed; its previously fetched value is dropped.
+    pub fn set_error(&mut self, row: ProfileRow, error: String) {
+        match &row {
+            ProfileRow::Text {
+                field,
+                language_code,
+            } => {
+                self.texts.remove(&(*field, language_code.clone()));
+            }
+            ProfileRow::MenuButton => self.menu_button = None,
+            ProfileRow::AdministratorRights {
+                for_channels: false,
+            } => self.group_rights = None,
+            ProfileRow::AdministratorRights { for_channels: true } => self.channel_rights = None,
+        }
+        self.errors.insert(row, error);
+    }
+
+    /// Returns why the last fetch of a row failed.
+    pub fn error(&self, row: &ProfileRow) -> Option<&str> {
+        self.errors.get(row).map(String::as_str)
+    }
+
+    /// Number of rows whose last fetch failed.
+    pub fn failed_rows(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns the value stored for exactly this language, if loaded.
+    pub fn text(&self, field: ProfileField, language_code: &str) -> Option<&str> {
+        self.texts
+            .get(&(field, language_code.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Returns the value users with `language_code` see, falling back to the
+    /// all-languages value when the language-specific one is empty.
+    pub fn effective_text(&self, field: ProfileField, language_code: &str) -> Option<&str> {
+        self.text(field, language_code)
+            .filter(|value| !value.is_empty())
+            .or_else(|| self.text(field, "").filter(|value| !value.is_empty()))
+    }
+
+    /// Returns all editable rows: every field per language, then the global settings.
+    pub fn rows(&self) -> Vec<ProfileRow> {
+        let mut rows = Vec::new();
+        for language in &self.languages {
+            for field in ProfileField::ALL {
+                rows.push(ProfileRow::Text {
+                    field,
+                    language_code: language.clone(),
+                });
+            }
+        }
+        rows.push(ProfileRow::MenuButton);
+        rows.push(ProfileRow::AdministratorRights {
+            for_channels: false,
+        });
+        rows.push(ProfileRow::AdministratorRights { for_channels: true });
+        rows
+    }
+
+    /// Returns the current value of a row in its edit format.
+    pub fn value(&self, row: &ProfileRow) -> String {
+        match row {
+            ProfileRow::Text {
+                field,
+                language_code,
+            } => self
+                .text(*field, language_code)
+                .unwrap_or_default()
+                .to_string(),
+            ProfileRow::MenuButton => match &self.menu_button {
+                Some(MenuButton::WebApp { text, web_app }) => {
+                    format!("web_app {text} {}", web_app.url)
+                }
+                Some(button) => button.describe(),
+                None => String::new(),
+            },
+            ProfileRow::AdministratorRights { for_channels } => {
+                let rights = if *for_channels {
+                    &self.channel_rights
+                } else {
+                    &self.group_rights
+                };
+                rights
+                    .as_ref()
+                    .map(|r| r.granted().join(", "))
+                    .unwrap_or_default()
+            }
+        }
+    }
+}
+
+impl Default for BotProfile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Validates a name or description against the Bot API length limit.
+///
+/// # Errors
+///
+/// Returns an error if the text is longer than [`ProfileField::max_length`].
+pub fn validate_profile_text(field: ProfileField, text: &str) -> Result<()> {
+    let length = text.chars().count();
+    if length > field.max_length() {
+        bail!(
+            "{} too long ({length} of max {} characters)",
+            field.label(),
+            field.max_length()
+        );
+    }
+    Ok(())
+}
+
+/// Parses the menu button edit format.
+///
+/// Accepts `default`, `commands` or `web_app <text> <url>` where the URL is
+/// the last word and must use HTTPS.
+///
+/// # Errors
+///
+/// Returns an error for unknown button types or a missing/invalid Web App URL.
+///
+/// # Examples
+///
+/// ```
+/// # use telegram_bot_debugger::telegram::profile::parse_menu_button;
+/// # use telegram_bot_debugger::telegram::MenuButton;
+/// assert_eq!(parse_menu_button("commands").unwrap(), MenuButton::Commands);
+/// let button = parse_menu_button("web_app Open shop https://example.com/shop").unwrap();
+/// assert_eq!(button.describe(), "web_app \"Open shop\" https://example.com/shop");
+/// ```
+pub fn parse_menu_button(input: &str) -> Result<MenuButton> {
+    let input = input.trim();
+    let (kind, rest) = input.split_once(' ').unwrap_or((input, ""));
+    match kind {
+        "" | "default" => Ok(MenuButton::Default),
+        "commands" => Ok(MenuButton::Commands),
+        "web_app" => {
+            let Some((text, url)) = rest.trim().rsplit_once(' ') else {
+                bail!("Expected \"web_app <text> <url>\"");
+            };
+            if !url.starts_with("https://") {
+                bail!("Web App URL must start with https://");
+            }
+            Ok(MenuButton::WebApp {
+                text: text.trim().to_string(),
+                web_app: WebAppInfo {
+                    url: url.to_string(),
+                },
+            })
+        }
+        other => bail!("Unknown menu button type \"{other}\""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_text_falls_back_to_all_languages() {
+        let mut profile = BotProfile::new();
+        profile.add_language("de").unwrap();
+        profile.set_text(ProfileField::Name, "", "Debug Bot".to_string());
+        profile.set_text(ProfileField::Name, "de", String::new());
+        profile.set_text(ProfileField::Description, "de", "Hallo".to_string());
+
+        assert_eq!(
+            profile.effective_text(ProfileField::Name, "de"),
+            Some("Debug Bot")
+        );
+        assert_eq!(
+            profile.effective_text(ProfileField::Description, "de"),
+            Some("Hallo")
+        );
+        assert_eq!(profile.effective_text(ProfileField::Description, ""), None);
+    }
+
+    #[test]
+    fn test_rows_cover_every_language_and_global_setting() {
+        let mut profile = BotProfile::new();
+        profile.add_language("fr").unwrap();
+        profile.add_language("fr").unwrap();
+        assert!(profile.add_language("french").is_err());
+
+        let rows = profile.rows();
+        assert_eq!(rows.len(), 2 * ProfileField::ALL.len() + 3);
+        assert_eq!(rows[3].label(), "Name [fr]");
+        assert_eq!(rows.last().unwrap().label(), "Admin rights (channels)");
+    }
+
+    #[test]
+    fn test_value_uses_edit_format() {
+        let mut profile = BotProfile::new();
+        profile.menu_button = Some(parse_menu_button("web_app Open https://example.com").unwrap());
+        profile.group_rights =
+            Some(ChatAdministratorRights::parse_granted("pin_messages").unwrap());
+
+        let menu_value = profile.value(&ProfileRow::MenuButton);
+        assert_eq!(
+            parse_menu_button(&menu_value).unwrap(),
+            profile.menu_button.clone().unwrap()
+        );
+        assert_eq!(
+            profile.value(&ProfileRow::AdministratorRights {
+                for_channels: false
+            }),
+            "can_pin_messages"
+        );
+    }
+
+    #[test]
+    fn test_row_errors_replace_and_are_cleared_by_loads() {
+        let mut profile = BotProfile::new();
+        let row = ProfileRow::Text {
+            field: ProfileField::Name,
+            language_code: String::new(),
+        };
+        profile.set_text(ProfileField::Name, "", "Debug Bot".to_string());
+        profile.set_error(row.clone(), "Too Many Requests: retry after 5".to_string());
+        profile.set_error(ProfileRow::MenuButton, "Bad Gateway".to_string());
+        assert_eq!(profile.text(ProfileField::Name, ""), None);
+        assert_eq!(
+            profile.error(&row),
+            Some("Too Many Requests: retry after 5")
+        );
+        assert_eq!(profile.failed_rows(), 2);
+
+        profile.set_text(ProfileField::Name, "", "Debug Bot".to_string());
+        profile.set_menu_button(MenuButton::Commands);
+        assert_eq!(profile.error(&row), None);
+        assert_eq!(profile.failed_rows(), 0);
+    }
+
+    #[test]
+    fn test_parse_menu_button_errors() {
+        assert_eq!(parse_menu_button("").unwrap(), MenuButton::Default);
+        assert!(parse_menu_button("web_app Open http://example.com").is_err());
+        assert!(parse_menu_button("web_app https://example.com").is_err());
+        assert!(parse_menu_button("keyboard").is_err());
+    }
+
+    #[test]
+    fn test_validate_profile_text() {
+        assert!(validate_profile_text(ProfileField::Name, &"a".repeat(64)).is_ok());
+        assert!(validate_profile_text(ProfileField::Name, &"a".repeat(65)).is_err());
+        assert!(validate_profile_text(ProfileField::ShortDescription, "").is_ok());
+    }
+}
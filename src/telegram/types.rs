@@ -4,6 +4,10 @@
 //! including users, chats, messages, and updates.
 
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::path::PathBuf;
+
+use super::update_kind::UpdateKind;
 
 /// Represents a Telegram user or bot.
 ///
@@ -14,13 +18,46 @@ use serde::{Deserialize, Serialize};
 /// * `first_name` - User's or bot's first name
 /// * `last_name` - Optional last name
 /// * `username` - Optional username (without @)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// * `can_join_groups` - Optional, true if the bot can be invited to groups (`getMe` only)
+/// * `can_read_all_group_messages` - Optional, true if privacy mode is disabled (`getMe` only)
+/// * `supports_inline_queries` - Optional, true if the bot supports inline queries (`getMe` only)
+/// * `language_code` - Optional IETF language tag of the user's client
+/// * `is_premium` - Optional, true if the user has Telegram Premium
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct User {
     pub id: i64,
     pub is_bot: bool,
     pub first_name: String,
     pub last_name: Option<String>,
     pub username: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_join_groups: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_read_all_group_messages: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supports_inline_queries: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_premium: Option<bool>,
+}
+
+impl User {
+    /// Returns the first name followed by the last name, if any.
+    pub fn full_name(&self) -> String {
+        match &self.last_name {
+            Some(last_name) => format!("{} {last_name}", self.first_name),
+            None => self.first_name.clone(),
+        }
+    }
+
+    /// Returns "@username", or the full name for users without a username.
+    pub fn display_name(&self) -> String {
+        match &self.username {
+            Some(username) => format!("@{username}"),
+            None => self.full_name(),
+        }
+    }
 }
 
 /// Represents a Telegram chat (private, group, supergroup, or channel).
@@ -113,6 +150,66 @@ pub struct Message {
     pub other: std::collections::HashMap<String, serde_json::Value>,
 }
 
+impl Message {
+    /// Returns the album identifier if this message belongs to a media group.
+    ///
+    /// Telegram delivers every item of an album as a separate message sharing the
+    /// same `media_group_id`.
+    pub fn media_group_id(&self) -> Option<&str> {
+        self.other.get("media_group_id").and_then(Value::as_str)
+    }
+
+    /// Returns the caption of a media message.
+    pub fn caption(&self) -> Option<&str> {
+        self.other.get("caption").and_then(Value::as_str)
+    }
+
+    /// Returns the part of the replied-to message quoted in this reply (`quote.text`).
+    pub fn quote_text(&self) -> Option<&str> {
+        self.other.get("quote")?.get("text")?.as_str()
+    }
+
+    /// Returns the supergroup ID this group was upgraded to (`migrate_to_chat_id`).
+    ///
+    /// This service message is the last one in the old group.
+    pub fn migrate_to_chat_id(&self) -> Option<i64> {
+        self.other.get("migrate_to_chat_id").and_then(Value::as_i64)
+    }
+
+    /// Returns the group ID this supergroup was upgraded from (`migrate_from_chat_id`).
+    pub fn migrate_from_chat_id(&self) -> Option<i64> {
+        self.other
+            .get("migrate_from_chat_id")
+            .and_then(Value::as_i64)
+    }
+
+    /// Returns the `forum_topic_created` service message, if this message created a topic.
+    pub fn forum_topic_created(&self) -> Option<ForumTopicCreated> {
+        self.other
+            .get("forum_topic_created")
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Returns the `forum_topic_edited` service message, if this message edited a topic.
+    pub fn forum_topic_edited(&self) -> Option<ForumTopicEdited> {
+        self.other
+            .get("forum_topic_edited")
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Returns `Some(true)` for `forum_topic_closed` and `Some(false)` for
+    /// `forum_topic_reopened` service messages.
+    pub fn forum_topic_closed(&self) -> Option<bool> {
+        if self.other.contains_key("forum_topic_closed") {
+            Some(true)
+        } else if self.other.contains_key("forum_topic_reopened") {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
 /// Represents a channel post.
 ///
 /// Similar to [`Message`] but specifically for channel posts without a sender.
@@ -128,6 +225,18 @@ pub struct ChannelPost {
     pub other: std::collections::HashMap<String, serde_json::Value>,
 }
 
+impl ChannelPost {
+    /// Returns the album identifier if this post belongs to a media group.
+    pub fn media_group_id(&self) -> Option<&str> {
+        self.other.get("media_group_id").and_then(Value::as_str)
+    }
+
+    /// Returns the caption of a media post.
+    pub fn caption(&self) -> Option<&str> {
+        self.other.get("caption").and_then(Value::as_str)
+    }
+}
+
 /// Represents an incoming update from Telegram.
 ///
 /// Only one of the optional fields will be present in each update.
@@ -157,6 +266,9 @@ pub struct Update {
 pub struct GetUpdatesResponse {
     pub ok: bool,
     pub result: Vec<Update>,
+    /// JSON text of every update in `result` exactly as Telegram sent it
+    #[serde(skip)]
+    pub raw_result: Vec<String>,
 }
 
 /// Response from the `sendMessage` API method.
@@ -165,9 +277,314 @@ pub struct SendMessageResponse {
     pub ok: bool,
     pub result: Option<Message>,
     pub description: Option<String>,
+    pub parameters: Option<ResponseParameters>,
+}
+
+/// Generic response envelope returned by Bot API methods.
+///
+/// # Fields
+///
+/// * `ok` - True if the request was successful
+/// * `result` - Optional method-specific result
+/// * `description` - Optional human-readable error description
+/// * `error_code` - Optional error code (mirrors the HTTP status)
+/// * `parameters` - Optional hints on how the failed request can be retried
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiResponse<T> {
+    pub ok: bool,
+    pub result: Option<T>,
+    pub description: Option<String>,
+    pub error_code: Option<i32>,
+    pub parameters: Option<ResponseParameters>,
+}
+
+impl<T> ApiResponse<T> {
+    /// Converts the envelope into the method result, turning API failures into errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns the API description (and error code) if `ok` is false or the result is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use telegram_bot_debugger::telegram::ApiResponse;
+    /// let response: ApiResponse<i64> = serde_json::from_str(
+    ///     r#"{"ok": false, "error_code": 400, "description": "Bad Request: chat not found"}"#,
+    /// ).unwrap();
+    /// let err = response.into_result().unwrap_err();
+    /// assert_eq!(err.to_string(), "Bad Request: chat not found (400)");
+    /// ```
+    pub fn into_result(self) -> anyhow::Result<T> {
+        match (self.ok, self.result) {
+            (true, Some(result)) => Ok(result),
+            _ => {
+                let description = self
+                    .description
+                    .unwrap_or_else(|| "Unknown error".to_string());
+                match self.error_code {
+                    Some(code) => anyhow::bail!("{description} ({code})"),
+                    None => anyhow::bail!("{description}"),
+                }
+            }
+        }
+    }
+}
+
+/// Additional information attached to some failed requests.
+///
+/// # Fields
+///
+/// * `migrate_to_chat_id` - The group has been migrated to a supergroup with this ID
+/// * `retry_after` - Seconds to wait before the request can be repeated (flood control)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseParameters {
+    pub migrate_to_chat_id: Option<i64>,
+    pub retry_after: Option<i64>,
+}
+
+/// Identifier of a message, as returned by `copyMessage`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MessageId {
+    pub message_id: i64,
+}
+
+/// Maximum number of messages accepted by a single `deleteMessages` call.
+pub const DELETE_MESSAGES_MAX_IDS: usize = 100;
+
+/// Minimum number of items accepted by `sendMediaGroup`.
+pub const MEDIA_GROUP_MIN_ITEMS: usize = 2;
+/// Maximum number of items accepted by `sendMediaGroup`.
+pub const MEDIA_GROUP_MAX_ITEMS: usize = 10;
+/// Maximum caption length for a single media item.
+pub const MAX_CAPTION_LENGTH: usize = 1024;
+
+/// Kind of media that can be part of an album.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputMediaKind {
+    Photo,
+    Video,
+    Audio,
+    Document,
+}
+
+impl InputMediaKind {
+    /// Returns the `type` value expected by the Bot API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InputMediaKind::Photo => "photo",
+            InputMediaKind::Video => "video",
+            InputMediaKind::Audio => "audio",
+            InputMediaKind::Document => "document",
+        }
+    }
+
+    /// Cycles to the next media kind (used by the album composer).
+    pub fn next(self) -> Self {
+        match self {
+            InputMediaKind::Photo => InputMediaKind::Video,
+            InputMediaKind::Video => InputMediaKind::Audio,
+            InputMediaKind::Audio => InputMediaKind::Document,
+            InputMediaKind::Document => InputMediaKind::Photo,
+        }
+    }
+}
+
+/// Where the content of an [`InputMedia`] item comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputMediaSource {
+    /// A `file_id` already stored on Telegram servers, or an HTTP URL
+    FileId(String),
+    /// A local file that is uploaded with the request
+    Path(PathBuf),
+}
+
+impl InputMediaSource {
+    /// Interprets user input as a local path if such a file exists, otherwise as a `file_id`/URL.
+    pub fn from_input(input: &str) -> Self {
+        let input = input.trim();
+        let path = PathBuf::from(input);
+        if path.is_file() {
+            InputMediaSource::Path(path)
+        } else {
+            InputMediaSource::FileId(input.to_string())
+        }
+    }
+
+    /// Returns a short description for display purposes.
+    pub fn describe(&self) -> String {
+        match self {
+            InputMediaSource::FileId(id) => format!("file_id {id}"),
+            InputMediaSource::Path(path) => format!("file {}", path.display()),
+        }
+    }
+}
+
+/// A single item of an album sent via `sendMediaGroup`.
+///
+/// # Fields
+///
+/// * `kind` - Type of the media (photo, video, audio or document)
+/// * `source` - File ID, URL or local path of the media
+/// * `caption` - Optional caption (0-1024 characters)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputMedia {
+    pub kind: InputMediaKind,
+    pub source: InputMediaSource,
+    pub caption: Option<String>,
+}
+
+impl InputMedia {
+    /// Builds the JSON object for the `media` array of `sendMediaGroup`.
+    ///
+    /// `media_ref` is either the file ID/URL or an `attach://<name>` reference to an
+    /// uploaded multipart field.
+    pub fn to_api_value(&self, media_ref: &str) -> Value {
+        let mut value = json!({
+            "type": self.kind.as_str(),
+            "media": media_ref,
+        });
+        if let Some(caption) = &self.caption {
+            value["caption"] = json!(caption);
+        }
+        value
+    }
+
+    /// Checks an album against the rules enforced by Telegram before sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The album has fewer than 2 or more than 10 items
+    /// - Audio or documents are mixed with other media kinds
+    /// - An item has an empty file ID or a caption longer than 1024 characters
+    pub fn validate_group(items: &[InputMedia]) -> anyhow::Result<()> {
+        if items.len() < MEDIA_GROUP_MIN_ITEMS || items.len() > MEDIA_GROUP_MAX_ITEMS {
+            anyhow::bail!(
+                "An album needs {MEDIA_GROUP_MIN_ITEMS}-{MEDIA_GROUP_MAX_ITEMS} items (got {})",
+                items.len()
+            );
+        }
+
+        for exclusive in [InputMediaKind::Audio, InputMediaKind::Document] {
+            let count = items.iter().filter(|item| item.kind == exclusive).count();
+            if count > 0 && count != items.len() {
+                anyhow::bail!(
+                    "Albums with {} items can only contain {} items",
+                    exclusive.as_str(),
+                    exclusive.as_str()
+                );
+            }
+        }
+
+        for (i, item) in items.iter().enumerate() {
+            if let InputMediaSource::FileId(id) = &item.source
+                && id.trim().is_empty()
+            {
+                anyhow::bail!("Item {} has an empty file ID", i + 1);
+            }
+            if let Some(caption) = &item.caption
+                && caption.chars().count() > MAX_CAPTION_LENGTH
+            {
+                anyhow::bail!(
+                    "Item {} caption too long (max {MAX_CAPTION_LENGTH} characters)",
+                    i + 1
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Update {
+    /// Returns the album identifier of the message or channel post in this update.
+    pub fn media_group_id(&self) -> Option<&str> {
+        if let Some(message) = &self.message {
+            message.media_group_id()
+        } else if let Some(channel_post) = &self.channel_post {
+            channel_post.media_group_id()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the ID of the message, channel post or edited message in this update.
+    pub fn message_id(&self) -> Option<i64> {
+        self.message
+            .as_ref()
+            .map(|m| m.message_id)
+            .or_else(|| self.channel_post.as_ref().map(|p| p.message_id))
+            .or_else(|| self.edited_message.as_ref().map(|m| m.message_id))
+    }
+
+    /// Returns the `(chat_id, message_id)` of the message, channel post or edit in this update.
+    pub fn message_key(&self) -> Option<(i64, i64)> {
+        if let Some(message) = self.message.as_ref().or(self.edited_message.as_ref()) {
+            Some((message.chat.id, message.message_id))
+        } else if let Some(post) = &self.channel_post {
+            Some((post.chat.id, post.message_id))
+        } else {
+            self.edited_channel_post()
+                .map(|post| (post.chat.id, post.message_id))
+        }
+    }
+
+    /// Returns the text of the message, channel post or edited message in this update.
+    pub fn message_text(&self) -> Option<&str> {
+        self.message
+            .as_ref()
+            .and_then(|m| m.text.as_deref())
+            .or_else(|| self.channel_post.as_ref().and_then(|p| p.text.as_deref()))
+            .or_else(|| self.edited_message.as_ref().and_then(|m| m.text.as_deref()))
+    }
+
+    /// Returns the caption of the message-like payload, if it is a media message.
+    pub fn message_caption(&self) -> Option<&str> {
+        self.message
+            .as_ref()
+            .and_then(Message::caption)
+            .or_else(|| self.channel_post.as_ref().and_then(ChannelPost::caption))
+            .or_else(|| self.edited_message.as_ref().and_then(Message::caption))
+    }
+
+    /// Returns the fields of the message-like payload that have no dedicated struct field.
+    pub fn message_other(&self) -> Option<&std::collections::HashMap<String, Value>> {
+        self.message
+            .as_ref()
+            .map(|m| &m.other)
+            .or_else(|| self.channel_post.as_ref().map(|p| &p.other))
+            .or_else(|| self.edited_message.as_ref().map(|m| &m.other))
+    }
+
+    /// Returns the bot's own membership change (`my_chat_member`), if this update carries one.
+    pub fn my_chat_member(&self) -> Option<ChatMemberUpdated> {
+        match self.kind() {
+            UpdateKind::MyChatMember(member_update) => Some(member_update),
+            _ => None,
+        }
+    }
+
+    /// Returns a member change of another user (`chat_member`), if this update carries one.
+    ///
+    /// Only delivered when `chat_member` is listed in `allowed_updates` and the
+    /// bot is an administrator.
+    pub fn chat_member(&self) -> Option<ChatMemberUpdated> {
+        match self.kind() {
+            UpdateKind::ChatMember(member_update) => Some(member_update),
+            _ => None,
+        }
+    }
+
+    /// Returns the new version of an edited channel post (`edited_channel_post`), if any.
+    pub fn edited_channel_post(&self) -> Option<Message> {
+        match self.kind() {
+            UpdateKind::EditedChannelPost(post) => Some(post),
+            _ => None,
+        }
+    }
+
     /// Returns a human-readable string describing the type of this update.
     ///
     /// Detects all known Telegram Bot API update types, including:
@@ -177,8 +594,8 @@ impl Update {
     /// - User/chat membership updates (my_chat_member, chat_member, chat_join_request)
     /// - Other types (chat_boost, message_reaction, etc.)
     ///
-    /// For unknown or future update types, returns "other_{key}" where {key} is the
-    /// field name from the API response.
+    /// This is the [`UpdateKind::name`] of [`Update::kind`]; unknown or future
+    /// update types return the field name from the API response.
     ///
     /// # Examples
     ///
@@ -212,55 +629,408 @@ impl Update {
     /// assert_eq!(update.get_update_type(), "message");
     /// ```
     pub fn get_update_type(&self) -> String {
-        // Check explicit fields first
-        if self.message.is_some() {
-            return "message".to_string();
-        }
-        if self.edited_message.is_some() {
-            return "edited_message".to_string();
-        }
-        if self.channel_post.is_some() {
-            return "channel_post".to_string();
+        self.kind().name().to_string()
+    }
+}
+
+/// Full information about a chat, as returned by the `getChat` API method.
+///
+/// # Fields
+///
+/// * `id` - Unique identifier for this chat
+/// * `chat_type` - Type of chat: "private", "group", "supergroup", or "channel"
+/// * `title` - Optional title for groups, supergroups, and channels
+/// * `username` - Optional username
+/// * `description` - Optional description for groups, supergroups, and channels
+/// * `is_forum` - True if the supergroup has topics enabled
+/// * `permissions` - Optional default permissions of members in groups and supergroups
+/// * `slow_mode_delay` - Optional minimum delay between messages of one user, in seconds
+/// * `linked_chat_id` - Optional discussion group of a channel, or channel of a discussion group
+/// * `pinned_message` - Optional most recent pinned message
+/// * `has_protected_content` - True if messages can't be forwarded or saved
+///
+/// Note: Additional fields from Telegram API are preserved using flatten.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatFullInfo {
+    pub id: i64,
+    #[serde(rename = "type")]
+    pub chat_type: String,
+    pub title: Option<String>,
+    pub username: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub is_forum: bool,
+    pub permissions: Option<ChatPermissions>,
+    pub slow_mode_delay: Option<i64>,
+    pub linked_chat_id: Option<i64>,
+    pub pinned_message: Option<Box<Message>>,
+    #[serde(default)]
+    pub has_protected_content: bool,
+
+    #[serde(flatten)]
+    pub other: std::collections::HashMap<String, Value>,
+}
+
+/// Default actions members are allowed to take in a chat.
+///
+/// Only the most commonly inspected permissions are typed; the remaining
+/// `can_send_*` media permissions are preserved in `other`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChatPermissions {
+    pub can_send_messages: Option<bool>,
+    pub can_send_polls: Option<bool>,
+    pub can_send_other_messages: Option<bool>,
+    pub can_add_web_page_previews: Option<bool>,
+    pub can_change_info: Option<bool>,
+    pub can_invite_users: Option<bool>,
+    pub can_pin_messages: Option<bool>,
+    pub can_manage_topics: Option<bool>,
+
+    #[serde(flatten)]
+    pub other: std::collections::HashMap<String, Value>,
+}
+
+/// Information about one member of a chat (`getChatMember`, `getChatAdministrators`).
+///
+/// # Fields
+///
+/// * `status` - "creator", "administrator", "member", "restricted", "left" or "kicked"
+/// * `user` - The member
+/// * `custom_title` - Optional custom title of an administrator
+/// * `is_anonymous` - Optional, true if the administrator's presence is hidden
+/// * `can_*` - Administrator rights; absent for non-administrators
+///
+/// Note: Additional fields from Telegram API are preserved using flatten.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMember {
+    pub status: String,
+    pub user: User,
+    pub custom_title: Option<String>,
+    pub is_anonymous: Option<bool>,
+    pub can_manage_chat: Option<bool>,
+    pub can_delete_messages: Option<bool>,
+    pub can_restrict_members: Option<bool>,
+    pub can_promote_members: Option<bool>,
+    pub can_change_info: Option<bool>,
+    pub can_invite_users: Option<bool>,
+    pub can_pin_messages: Option<bool>,
+    pub can_manage_topics: Option<bool>,
+    pub can_post_messages: Option<bool>,
+    pub can_edit_messages: Option<bool>,
+
+    #[serde(flatten)]
+    pub other: std::collections::HashMap<String, Value>,
+}
+
+/// A change of a chat member's status (`my_chat_member` and `chat_member` updates).
+///
+/// Note: Additional fields from Telegram API are preserved using flatten.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMemberUpdated {
+    pub chat: Chat,
+    /// User who performed the change
+    pub from: User,
+    pub date: i64,
+    pub old_chat_member: ChatMember,
+    pub new_chat_member: ChatMember,
+
+    #[serde(flatten)]
+    pub other: std::collections::HashMap<String, Value>,
+}
+
+/// One change of the bot's own status in a chat.
+///
+/// # Fields
+///
+/// * `date` - Unix timestamp of the change
+/// * `old_status` - Status before the change, e.g. "left"
+/// * `new_status` - Status after the change, e.g. "administrator"
+/// * `changed_by` - Display name of the user who changed it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BotStatusChange {
+    pub date: i64,
+    pub old_status: String,
+    pub new_status: String,
+    pub changed_by: String,
+}
+
+impl ChatMember {
+    /// Returns true if the member is the creator or an administrator of the chat.
+    pub fn is_admin(&self) -> bool {
+        matches!(self.status.as_str(), "creator" | "administrator")
+    }
+
+    /// Returns true if the member is still part of the chat.
+    pub fn is_present(&self) -> bool {
+        !matches!(self.status.as_str(), "left" | "kicked")
+    }
+
+    /// Returns the administrator rights of this member as `(name, granted)` pairs.
+    ///
+    /// The creator implicitly holds every right. Rights that don't apply to the
+    /// chat (e.g. `can_post_messages` outside channels) are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use telegram_bot_debugger::telegram::ChatMember;
+    /// let member: ChatMember = serde_json::from_value(serde_json::json!({
+    ///     "status": "administrator",
+    ///     "user": {"id": 1, "is_bot": true, "first_name": "Bot"},
+    ///     "can_delete_messages": false,
+    ///     "can_pin_messages": true
+    /// })).unwrap();
+    /// let rights = member.rights();
+    /// assert!(rights.contains(&("can_delete_messages", false)));
+    /// assert!(rights.contains(&("can_pin_messages", true)));
+    /// ```
+    pub fn rights(&self) -> Vec<(&'static str, bool)> {
+        let creator = self.status == "creator";
+        [
+            ("can_manage_chat", self.can_manage_chat),
+            ("can_delete_messages", self.can_delete_messages),
+            ("can_restrict_members", self.can_restrict_members),
+            ("can_promote_members", self.can_promote_members),
+            ("can_change_info", self.can_change_info),
+            ("can_invite_users", self.can_invite_users),
+            ("can_pin_messages", self.can_pin_messages),
+            ("can_manage_topics", self.can_manage_topics),
+            ("can_post_messages", self.can_post_messages),
+            ("can_edit_messages", self.can_edit_messages),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| match (creator, value) {
+            (true, _) => Some((name, true)),
+            (false, Some(granted)) => Some((name, granted)),
+            (false, None) => None,
+        })
+        .collect()
+    }
+}
+
+/// A bot command shown in the Telegram command menu.
+///
+/// # Fields
+///
+/// * `command` - Command text without the leading slash (1-32 chars: a-z, 0-9, _)
+/// * `description` - Description shown in the menu (1-256 chars)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BotCommand {
+    pub command: String,
+    pub description: String,
+}
+
+/// The audience a list of bot commands applies to.
+///
+/// Serialized with a `type` tag as expected by the Bot API, e.g.
+/// `{"type": "chat_administrators", "chat_id": -100123}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BotCommandScope {
+    Default,
+    AllPrivateChats,
+    AllGroupChats,
+    AllChatAdministrators,
+    Chat { chat_id: i64 },
+    ChatAdministrators { chat_id: i64 },
+    ChatMember { chat_id: i64, user_id: i64 },
+}
+
+impl BotCommandScope {
+    /// Returns a short label for display (e.g. `chat_administrators(-100123)`).
+    pub fn label(&self) -> String {
+        match self {
+            BotCommandScope::Default => "default".to_string(),
+            BotCommandScope::AllPrivateChats => "all_private_chats".to_string(),
+            BotCommandScope::AllGroupChats => "all_group_chats".to_string(),
+            BotCommandScope::AllChatAdministrators => "all_chat_administrators".to_string(),
+            BotCommandScope::Chat { chat_id } => format!("chat({chat_id})"),
+            BotCommandScope::ChatAdministrators { chat_id } => {
+                format!("chat_administrators({chat_id})")
+            }
+            BotCommandScope::ChatMember { chat_id, user_id } => {
+                format!("chat_member({chat_id}, {user_id})")
+            }
         }
+    }
 
-        // Check for other known update types in the `other` HashMap
-        // These are flattened fields from the API that we don't explicitly handle
-        let known_types = [
-            "edited_channel_post",
-            "business_connection",
-            "business_message",
-            "edited_business_message",
-            "deleted_business_messages",
-            "message_reaction",
-            "message_reaction_count",
-            "inline_query",
-            "chosen_inline_result",
-            "callback_query",
-            "shipping_query",
-            "pre_checkout_query",
-            "purchased_paid_media",
-            "poll",
-            "poll_answer",
-            "my_chat_member",
-            "chat_member",
-            "chat_join_request",
-            "chat_boost",
-            "removed_chat_boost",
-        ];
+    /// Returns the scopes Telegram checks, in order, to find the commands for this scope.
+    ///
+    /// The first scope with commands wins; `default` is always last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use telegram_bot_debugger::telegram::BotCommandScope;
+    /// let chain = BotCommandScope::AllChatAdministrators.fallback_chain();
+    /// assert_eq!(chain, vec![
+    ///     BotCommandScope::AllChatAdministrators,
+    ///     BotCommandScope::AllGroupChats,
+    ///     BotCommandScope::Default,
+    /// ]);
+    /// ```
+    pub fn fallback_chain(&self) -> Vec<BotCommandScope> {
+        use BotCommandScope::*;
 
-        for type_name in &known_types {
-            if self.other.contains_key(*type_name) {
-                return type_name.to_string();
+        // Positive chat IDs are private chats, negative ones groups and channels
+        let chat_kind = |chat_id: i64| {
+            if chat_id > 0 {
+                AllPrivateChats
+            } else {
+                AllGroupChats
             }
+        };
+
+        match self {
+            Default => vec![Default],
+            AllPrivateChats => vec![AllPrivateChats, Default],
+            AllGroupChats => vec![AllGroupChats, Default],
+            AllChatAdministrators => vec![AllChatAdministrators, AllGroupChats, Default],
+            Chat { chat_id } => vec![self.clone(), chat_kind(*chat_id), Default],
+            ChatAdministrators { chat_id } => vec![
+                self.clone(),
+                Chat { chat_id: *chat_id },
+                AllChatAdministrators,
+                AllGroupChats,
+                Default,
+            ],
+            ChatMember { chat_id, .. } => vec![
+                self.clone(),
+                Chat { chat_id: *chat_id },
+                chat_kind(*chat_id),
+                Default,
+            ],
+        }
+    }
+}
+
+/// The bot's name for a language (`getMyName`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BotName {
+    pub name: String,
+}
+
+/// The bot's description shown in empty chats (`getMyDescription`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BotDescription {
+    pub description: String,
+}
+
+/// The bot's short description shown on its profile page (`getMyShortDescription`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BotShortDescription {
+    pub short_description: String,
+}
+
+/// A Web App launched from a menu button.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebAppInfo {
+    pub url: String,
+}
+
+/// The button next to the message input field (`getChatMenuButton`).
+///
+/// Serialized with a `type` tag, e.g. `{"type": "commands"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MenuButton {
+    /// Opens the bot's command list
+    Commands,
+    /// Launches a Web App
+    WebApp { text: String, web_app: WebAppInfo },
+    /// No specific button; Telegram decides
+    Default,
+}
+
+impl MenuButton {
+    /// Returns a one-line description for display.
+    pub fn describe(&self) -> String {
+        match self {
+            MenuButton::Commands => "commands".to_string(),
+            MenuButton::WebApp { text, web_app } => format!("web_app \"{text}\" {}", web_app.url),
+            MenuButton::Default => "default".to_string(),
         }
+    }
+}
 
-        // If we have any other field, return it as "other_{field_name}"
-        if let Some((key, _)) = self.other.iter().next() {
-            return format!("other_{key}");
+/// Administrator rights, as used by `getMyDefaultAdministratorRights`.
+///
+/// Rights are kept as a sorted name → granted map so rights added to the
+/// Bot API later survive a load/edit/save round trip.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatAdministratorRights {
+    #[serde(flatten)]
+    pub rights: std::collections::BTreeMap<String, bool>,
+}
+
+impl ChatAdministratorRights {
+    /// Rights known to this tool, in Bot API order.
+    pub const KNOWN_RIGHTS: &'static [&'static str] = &[
+        "is_anonymous",
+        "can_manage_chat",
+        "can_delete_messages",
+        "can_manage_video_chats",
+        "can_restrict_members",
+        "can_promote_members",
+        "can_change_info",
+        "can_invite_users",
+        "can_post_stories",
+        "can_edit_stories",
+        "can_delete_stories",
+        "can_post_messages",
+        "can_edit_messages",
+        "can_pin_messages",
+        "can_manage_topics",
+    ];
+
+    /// Returns the names of all granted rights.
+    pub fn granted(&self) -> Vec<&str> {
+        self.rights
+            .iter()
+            .filter(|(_, granted)| **granted)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Builds rights from a comma- or space-separated list of granted right names.
+    ///
+    /// Every known right not listed is explicitly denied. The `can_` prefix may be omitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first unknown right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use telegram_bot_debugger::telegram::ChatAdministratorRights;
+    /// let rights = ChatAdministratorRights::parse_granted("delete_messages, can_pin_messages").unwrap();
+    /// assert_eq!(rights.granted(), vec!["can_delete_messages", "can_pin_messages"]);
+    /// assert_eq!(rights.rights.get("can_manage_chat"), Some(&false));
+    /// ```
+    pub fn parse_granted(input: &str) -> anyhow::Result<Self> {
+        let mut rights: std::collections::BTreeMap<String, bool> = Self::KNOWN_RIGHTS
+            .iter()
+            .map(|name| (name.to_string(), false))
+            .collect();
+
+        for name in input
+            .split([',', ' '])
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+        {
+            let name = if name == "is_anonymous" || name.starts_with("can_") {
+                name.to_string()
+            } else {
+                format!("can_{name}")
+            };
+            match rights.get_mut(&name) {
+                Some(granted) => *granted = true,
+                None => anyhow::bail!("Unknown administrator right \"{name}\""),
+            }
         }
 
-        // Fallback for completely empty updates
-        "unknown".to_string()
+        Ok(Self { rights })
     }
 }
 
@@ -352,12 +1122,86 @@ pub struct DeleteWebhookResponse {
 /// * `last_seen` - Unix timestamp of the last message received from this chat
 /// * `message_count` - Total number of messages seen in this chat
 /// * `topics` - List of topics/threads for forum groups
+/// * `bot_status_history` - Changes of the bot's own status from `my_chat_member`, oldest first
+/// * `migrated_from_chat_id` - ID of the group this supergroup was upgraded from; its
+///   messages are counted in this chat
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscoveredChat {
     pub chat: Chat,
     pub last_seen: i64,
     pub message_count: usize,
     pub topics: Vec<TopicInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bot_status_history: Vec<BotStatusChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub migrated_from_chat_id: Option<i64>,
+}
+
+impl DiscoveredChat {
+    /// Returns the bot's current status, if a `my_chat_member` update was seen.
+    pub fn bot_status(&self) -> Option<&str> {
+        self.bot_status_history
+            .last()
+            .map(|change| change.new_status.as_str())
+    }
+
+    /// Returns true if the bot left or was kicked from the chat.
+    pub fn bot_removed(&self) -> bool {
+        matches!(self.bot_status(), Some("left" | "kicked"))
+    }
+
+    /// Returns when the bot left or was kicked, if it is no longer in the chat.
+    pub fn bot_removed_at(&self) -> Option<i64> {
+        self.bot_status_history
+            .last()
+            .filter(|_| self.bot_removed())
+            .map(|change| change.date)
+    }
+}
+
+/// A user seen in updates, with the names they used over time.
+///
+/// # Fields
+///
+/// * `user` - Latest known profile; `language_code` and `is_premium` keep the last reported value
+/// * `name_history` - Distinct name/username combinations, oldest first
+/// * `first_seen` / `last_seen` - Unix timestamps of the first and last sighting
+/// * `chat_ids` - Chats the user sent updates in, in order of first appearance
+/// * `update_count` - Number of updates the user sent (messages, callbacks, inline queries, member changes)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnownUser {
+    pub user: User,
+    pub name_history: Vec<UserName>,
+    pub first_seen: i64,
+    pub last_seen: i64,
+    pub chat_ids: Vec<i64>,
+    pub update_count: usize,
+}
+
+/// A name and username a user had at some point.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserName {
+    /// Unix timestamp of the first sighting with this name
+    pub since: i64,
+    pub full_name: String,
+    pub username: Option<String>,
+}
+
+impl KnownUser {
+    /// Returns true if the ID, a current or former name or username contains `query`
+    /// (case-insensitive).
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().trim_start_matches('@').to_lowercase();
+        query.is_empty()
+            || self.user.id.to_string().contains(&query)
+            || self.name_history.iter().any(|name| {
+                name.full_name.to_lowercase().contains(&query)
+                    || name
+                        .username
+                        .as_ref()
+                        .is_some_and(|u| u.to_lowercase().contains(&query))
+            })
+    }
 }
 
 /// Information about a topic (thread) in a forum group.
@@ -365,15 +1209,92 @@ pub struct DiscoveredChat {
 /// # Fields
 ///
 /// * `thread_id` - Unique identifier for this topic
-/// * `name` - Optional topic name (may not be available from regular messages)
+/// * `name` - Optional topic name, known from forum service messages or topic management
 /// * `message_count` - Number of messages seen in this topic
 /// * `last_seen` - Unix timestamp of the last message in this topic
+/// * `icon_color` - Optional RGB color of the topic icon
+/// * `icon_custom_emoji_id` - Optional custom emoji shown as the topic icon
+/// * `is_closed` - True if the topic was closed
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TopicInfo {
     pub thread_id: i64,
     pub name: Option<String>,
     pub message_count: usize,
     pub last_seen: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_color: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+    #[serde(default)]
+    pub is_closed: bool,
+}
+
+impl TopicInfo {
+    /// Returns the topic name, or "Topic {thread_id}" while it is unknown.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("Topic {}", self.thread_id),
+        }
+    }
+}
+
+/// Icon colors accepted by `createForumTopic`.
+pub const FORUM_TOPIC_ICON_COLORS: [i64; 6] =
+    [0x6FB9F0, 0xFFD67E, 0xCB86DB, 0x8EEE98, 0xFF93B2, 0xFB6F5F];
+
+/// A forum topic as returned by `createForumTopic`.
+///
+/// # Fields
+///
+/// * `message_thread_id` - Identifier of the topic, used as `message_thread_id` when sending
+/// * `name` - Name of the topic
+/// * `icon_color` - RGB color of the topic icon
+/// * `icon_custom_emoji_id` - Optional custom emoji shown as the topic icon
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForumTopic {
+    pub message_thread_id: i64,
+    pub name: String,
+    pub icon_color: i64,
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// Service message about a new forum topic (`forum_topic_created`).
+///
+/// Every message in a topic that does not reply to another message replies to
+/// this one, so it is also found in `reply_to_message`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForumTopicCreated {
+    pub name: String,
+    pub icon_color: i64,
+    #[serde(default)]
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// Service message about an edited forum topic (`forum_topic_edited`).
+///
+/// Absent fields were not changed; an empty `icon_custom_emoji_id` means the
+/// icon was removed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForumTopicEdited {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// A sticker; only the fields needed to pick forum topic icons are typed.
+///
+/// Note: Additional fields from Telegram API are preserved using flatten.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sticker {
+    pub file_id: String,
+    pub file_unique_id: String,
+    pub emoji: Option<String>,
+    pub custom_emoji_id: Option<String>,
+
+    #[serde(flatten)]
+    pub other: std::collections::HashMap<String, Value>,
 }
 
 #[cfg(test)]
@@ -483,6 +1404,11 @@ mod tests {
             first_name: "Test".to_string(),
             last_name: Some("User".to_string()),
             username: Some("testuser".to_string()),
+            can_join_groups: None,
+            can_read_all_group_messages: None,
+            supports_inline_queries: None,
+            language_code: None,
+            is_premium: None,
         };
 
         let json = serde_json::to_string(&user).unwrap();
@@ -525,6 +1451,11 @@ mod tests {
                 first_name: "Alice".to_string(),
                 last_name: None,
                 username: Some("alice".to_string()),
+                can_join_groups: None,
+                can_read_all_group_messages: None,
+                supports_inline_queries: None,
+                language_code: None,
+                is_premium: None,
             }),
             chat: Chat {
                 id: 100,
@@ -603,7 +1534,12 @@ mod tests {
                 name: Some("General".to_string()),
                 message_count: 10,
                 last_seen: 1000,
+                icon_color: None,
+                icon_custom_emoji_id: None,
+                is_closed: false,
             }],
+            bot_status_history: Vec::new(),
+            migrated_from_chat_id: None,
         };
 
         let json = serde_json::to_string(&discovered_chat).unwrap();
@@ -732,6 +1668,9 @@ mod tests {
         assert!(update.message.is_none());
         assert!(update.channel_post.is_some());
         assert!(update.edited_message.is_none());
+        assert_eq!(update.message_id(), Some(100));
+        assert_eq!(update.message_text(), Some("Channel post"));
+        assert!(update.message_other().is_some());
     }
 
     #[test]
@@ -788,6 +1727,9 @@ mod tests {
             name: None,
             message_count: 5,
             last_seen: 1000,
+            icon_color: None,
+            icon_custom_emoji_id: None,
+            is_closed: false,
         };
 
         assert!(topic.name.is_none());
@@ -801,6 +1743,9 @@ mod tests {
             name: Some("General Discussion".to_string()),
             message_count: 100,
             last_seen: 2000,
+            icon_color: None,
+            icon_custom_emoji_id: None,
+            is_closed: false,
         };
 
         assert_eq!(topic.name, Some("General Discussion".to_string()));
@@ -821,6 +1766,8 @@ mod tests {
             last_seen: 1000,
             message_count: 10,
             topics: Vec::new(),
+            bot_status_history: Vec::new(),
+            migrated_from_chat_id: None,
         };
 
         assert_eq!(chat.topics.len(), 0);
@@ -899,5 +1846,202 @@ mod tests {
         assert!(!response.ok);
         assert!(response.result.is_none());
         assert!(response.description.is_some());
+        assert!(response.parameters.is_none());
+    }
+
+    #[test]
+    fn test_send_message_response_migrated_group() {
+        let json = r#"{
+            "ok": false,
+            "error_code": 400,
+            "description": "Bad Request: group chat was upgraded to a supergroup chat",
+            "parameters": {"migrate_to_chat_id": -1001234567890}
+        }"#;
+
+        let response: SendMessageResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            response.parameters.and_then(|p| p.migrate_to_chat_id),
+            Some(-1001234567890)
+        );
+    }
+
+    // Media group tests
+    fn media(kind: InputMediaKind, file_id: &str) -> InputMedia {
+        InputMedia {
+            kind,
+            source: InputMediaSource::FileId(file_id.to_string()),
+            caption: None,
+        }
+    }
+
+    #[test]
+    fn test_message_media_group_id() {
+        let json = r#"{
+            "message_id": 1,
+            "chat": {"id": 1, "type": "private"},
+            "date": 1000,
+            "media_group_id": "13579",
+            "photo": []
+        }"#;
+
+        let message: Message = serde_json::from_str(json).unwrap();
+        assert_eq!(message.media_group_id(), Some("13579"));
+    }
+
+    #[test]
+    fn test_validate_group_accepts_mixed_photo_and_video() {
+        let items = vec![
+            media(InputMediaKind::Photo, "a"),
+            media(InputMediaKind::Video, "b"),
+        ];
+        assert!(InputMedia::validate_group(&items).is_ok());
+    }
+
+    #[test]
+    fn test_validate_group_rejects_too_few_and_too_many_items() {
+        let one = vec![media(InputMediaKind::Photo, "a")];
+        assert!(InputMedia::validate_group(&one).is_err());
+
+        let eleven: Vec<InputMedia> = (0..11)
+            .map(|i| media(InputMediaKind::Photo, &format!("id{i}")))
+            .collect();
+        assert!(InputMedia::validate_group(&eleven).is_err());
+    }
+
+    #[test]
+    fn test_validate_group_rejects_documents_mixed_with_photos() {
+        let items = vec![
+            media(InputMediaKind::Document, "a"),
+            media(InputMediaKind::Photo, "b"),
+        ];
+        let err = InputMedia::validate_group(&items).unwrap_err();
+        assert!(err.to_string().contains("document"));
+    }
+
+    #[test]
+    fn test_validate_group_rejects_empty_file_id() {
+        let items = vec![
+            media(InputMediaKind::Audio, "a"),
+            media(InputMediaKind::Audio, " "),
+        ];
+        assert!(InputMedia::validate_group(&items).is_err());
+    }
+
+    #[test]
+    fn test_input_media_to_api_value() {
+        let mut item = media(InputMediaKind::Video, "abc");
+        item.caption = Some("Clip".to_string());
+
+        let value = item.to_api_value("attach://file0");
+        assert_eq!(value["type"], "video");
+        assert_eq!(value["media"], "attach://file0");
+        assert_eq!(value["caption"], "Clip");
+    }
+
+    #[test]
+    fn test_api_response_with_parameters() {
+        let json = r#"{
+            "ok": false,
+            "error_code": 400,
+            "description": "Bad Request: group chat was upgraded to a supergroup chat",
+            "parameters": {"migrate_to_chat_id": -1001234567890}
+        }"#;
+
+        let response: ApiResponse<Message> = serde_json::from_str(json).unwrap();
+        assert!(!response.ok);
+        assert_eq!(response.error_code, Some(400));
+        assert_eq!(
+            response.parameters.unwrap().migrate_to_chat_id,
+            Some(-1001234567890)
+        );
+    }
+
+    #[test]
+    fn test_api_response_into_result() {
+        let ok: ApiResponse<i64> = serde_json::from_str(r#"{"ok": true, "result": 3}"#).unwrap();
+        assert_eq!(ok.into_result().unwrap(), 3);
+
+        let failed: ApiResponse<i64> =
+            serde_json::from_str(r#"{"ok": false, "description": "Forbidden"}"#).unwrap();
+        assert_eq!(failed.into_result().unwrap_err().to_string(), "Forbidden");
+    }
+
+    #[test]
+    fn test_chat_member_rights() {
+        let creator: ChatMember = serde_json::from_value(json!({
+            "status": "creator",
+            "user": {"id": 1, "is_bot": false, "first_name": "Owner"}
+        }))
+        .unwrap();
+        assert!(creator.is_admin());
+        assert!(creator.rights().iter().all(|(_, granted)| *granted));
+
+        let left: ChatMember = serde_json::from_value(json!({
+            "status": "left",
+            "user": {"id": 2, "is_bot": true, "first_name": "Bot"}
+        }))
+        .unwrap();
+        assert!(!left.is_present());
+        assert!(!left.is_admin());
+    }
+
+    #[test]
+    fn test_get_me_capability_fields() {
+        let me: User = serde_json::from_value(json!({
+            "id": 1, "is_bot": true, "first_name": "Bot",
+            "can_join_groups": true,
+            "can_read_all_group_messages": false,
+            "supports_inline_queries": true
+        }))
+        .unwrap();
+        assert_eq!(me.can_join_groups, Some(true));
+        assert_eq!(me.can_read_all_group_messages, Some(false));
+        assert_eq!(me.supports_inline_queries, Some(true));
+
+        // Regular users don't carry these fields, and they are not written back
+        let user: User =
+            serde_json::from_value(json!({"id": 2, "is_bot": false, "first_name": "A"})).unwrap();
+        let value = serde_json::to_value(&user).unwrap();
+        assert!(value.get("can_join_groups").is_none());
+    }
+
+    #[test]
+    fn test_menu_button_serialization() {
+        let button: MenuButton = serde_json::from_value(json!({
+            "type": "web_app",
+            "text": "Open",
+            "web_app": {"url": "https://example.com"}
+        }))
+        .unwrap();
+        assert_eq!(button.describe(), "web_app \"Open\" https://example.com");
+
+        let value = serde_json::to_value(MenuButton::Commands).unwrap();
+        assert_eq!(value, json!({"type": "commands"}));
+    }
+
+    #[test]
+    fn test_administrator_rights_roundtrip() {
+        let rights: ChatAdministratorRights = serde_json::from_value(json!({
+            "is_anonymous": false,
+            "can_manage_chat": true,
+            "can_some_future_right": true
+        }))
+        .unwrap();
+        assert_eq!(
+            rights.granted(),
+            vec!["can_manage_chat", "can_some_future_right"]
+        );
+        assert_eq!(
+            serde_json::to_value(&rights).unwrap()["can_some_future_right"],
+            json!(true)
+        );
+
+        assert!(ChatAdministratorRights::parse_granted("fly").is_err());
+        let none = ChatAdministratorRights::parse_granted("").unwrap();
+        assert!(none.granted().is_empty());
+        assert_eq!(
+            none.rights.len(),
+            ChatAdministratorRights::KNOWN_RIGHTS.len()
+        );
     }
 }
@@ -0,0 +1,552 @@
+//! Typed view of every Bot API update kind.
+//!
+//! [`Update`] keeps the raw payload of every kind except messages in its
+//! `other` map, so nothing is lost. [`Update::kind`] parses that payload into
+//! an [`UpdateKind`] that can be pattern-matched; kinds this version doesn't
+//! know (or payloads that don't parse) come back as [`UpdateKind::Unknown`]
+//! with the JSON untouched.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::types::{ChannelPost, Chat, ChatMemberUpdated, Message, Update, User};
+
+/// Update kinds carried in `Update::other`, in the order they are looked up.
+pub const OTHER_UPDATE_KINDS: [&str; 20] = [
+    "edited_channel_post",
+    "business_connection",
+    "business_message",
+    "edited_business_message",
+    "deleted_business_messages",
+    "message_reaction",
+    "message_reaction_count",
+    "inline_query",
+    "chosen_inline_result",
+    "callback_query",
+    "shipping_query",
+    "pre_checkout_query",
+    "purchased_paid_media",
+    "poll",
+    "poll_answer",
+    "my_chat_member",
+    "chat_member",
+    "chat_join_request",
+    "chat_boost",
+    "removed_chat_boost",
+];
+
+/// The payload of an update, by kind.
+#[derive(Debug, Clone)]
+pub enum UpdateKind {
+    Message(Message),
+    EditedMessage(Message),
+    ChannelPost(ChannelPost),
+    EditedChannelPost(Message),
+    BusinessConnection(BusinessConnection),
+    BusinessMessage(Message),
+    EditedBusinessMessage(Message),
+    DeletedBusinessMessages(BusinessMessagesDeleted),
+    MessageReaction(MessageReactionUpdated),
+    MessageReactionCount(MessageReactionCountUpdated),
+    InlineQuery(InlineQuery),
+    ChosenInlineResult(ChosenInlineResult),
+    CallbackQuery(CallbackQuery),
+    ShippingQuery(ShippingQuery),
+    PreCheckoutQuery(PreCheckoutQuery),
+    PurchasedPaidMedia(PaidMediaPurchased),
+    Poll(Poll),
+    PollAnswer(PollAnswer),
+    MyChatMember(ChatMemberUpdated),
+    ChatMember(ChatMemberUpdated),
+    ChatJoinRequest(ChatJoinRequest),
+    ChatBoost(ChatBoostUpdated),
+    RemovedChatBoost(ChatBoostRemoved),
+    /// A kind this version doesn't model, or a payload that didn't parse
+    Unknown {
+        key: String,
+        payload: Value,
+    },
+    /// An update without any payload
+    Empty,
+}
+
+impl UpdateKind {
+    /// Parses the payload stored under `key` in `Update::other`.
+    ///
+    /// Falls back to [`UpdateKind::Unknown`] so the payload is never dropped.
+    pub fn from_other(key: &str, payload: &Value) -> Self {
+        fn parse<T: serde::de::DeserializeOwned>(payload: &Value) -> Option<T> {
+            serde_json::from_value(payload.clone()).ok()
+        }
+
+        let kind = match key {
+            "edited_channel_post" => parse(payload).map(Self::EditedChannelPost),
+            "business_connection" => parse(payload).map(Self::BusinessConnection),
+            "business_message" => parse(payload).map(Self::BusinessMessage),
+            "edited_business_message" => parse(payload).map(Self::EditedBusinessMessage),
+            "deleted_business_messages" => parse(payload).map(Self::DeletedBusinessMessages),
+            "message_reaction" => parse(payload).map(Self::MessageReaction),
+            "message_reaction_count" => parse(payload).map(Self::MessageReactionCount),
+            "inline_query" => parse(payload).map(Self::InlineQuery),
+            "chosen_inline_result" => parse(payload).map(Self::ChosenInlineResult),
+            "callback_query" => parse(payload).map(Self::CallbackQuery),
+            "shipping_query" => parse(payload).map(Self::ShippingQuery),
+            "pre_checkout_query" => parse(payload).map(Self::PreCheckoutQuery),
+            "purchased_paid_media" => parse(payload).map(Self::PurchasedPaidMedia),
+            "poll" => parse(payload).map(Self::Poll),
+            "poll_answer" => parse(payload).map(Self::PollAnswer),
+            "my_chat_member" => parse(payload).map(Self::MyChatMember),
+            "chat_member" => parse(payload).map(Self::ChatMember),
+            "chat_join_request" => parse(payload).map(Self::ChatJoinRequest),
+            "chat_boost" => parse(payload).map(Self::ChatBoost),
+            "removed_chat_boost" => parse(payload).map(Self::RemovedChatBoost),
+            _ => None,
+        };
+        kind.unwrap_or_else(|| Self::Unknown {
+            key: key.to_string(),
+            payload: payload.clone(),
+        })
+    }
+
+    /// Returns the field name of this kind in the Bot API `Update` object.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Message(_) => "message",
+            Self::EditedMessage(_) => "edited_message",
+            Self::ChannelPost(_) => "channel_post",
+            Self::EditedChannelPost(_) => "edited_channel_post",
+            Self::BusinessConnection(_) => "business_connection",
+            Self::BusinessMessage(_) => "business_message",
+            Self::EditedBusinessMessage(_) => "edited_business_message",
+            Self::DeletedBusinessMessages(_) => "deleted_business_messages",
+            Self::MessageReaction(_) => "message_reaction",
+            Self::MessageReactionCount(_) => "message_reaction_count",
+            Self::InlineQuery(_) => "inline_query",
+            Self::ChosenInlineResult(_) => "chosen_inline_result",
+            Self::CallbackQuery(_) => "callback_query",
+            Self::ShippingQuery(_) => "shipping_query",
+            Self::PreCheckoutQuery(_) => "pre_checkout_query",
+            Self::PurchasedPaidMedia(_) => "purchased_paid_media",
+            Self::Poll(_) => "poll",
+            Self::PollAnswer(_) => "poll_answer",
+            Self::MyChatMember(_) => "my_chat_member",
+            Self::ChatMember(_) => "chat_member",
+            Self::ChatJoinRequest(_) => "chat_join_request",
+            Self::ChatBoost(_) => "chat_boost",
+            Self::RemovedChatBoost(_) => "removed_chat_boost",
+            Self::Unknown { key, .. } => key,
+            Self::Empty => "unknown",
+        }
+    }
+
+    /// Returns whether this is a kind this version models whose payload didn't parse.
+    pub fn is_unparsed(&self) -> bool {
+        matches!(self, Self::Unknown { key, .. } if OTHER_UPDATE_KINDS.contains(&key.as_str()))
+    }
+
+    /// Returns the user who caused the update, if the kind has one.
+    pub fn sender(&self) -> Option<&User> {
+        match self {
+            Self::Message(message)
+            | Self::EditedMessage(message)
+            | Self::EditedChannelPost(message)
+            | Self::BusinessMessage(message)
+            | Self::EditedBusinessMessage(message) => message.from.as_ref(),
+            Self::BusinessConnection(connection) => Some(&connection.user),
+            Self::MessageReaction(reaction) => reaction.user.as_ref(),
+            Self::InlineQuery(query) => Some(&query.from),
+            Self::ChosenInlineResult(result) => Some(&result.from),
+            Self::CallbackQuery(query) => Some(&query.from),
+            Self::ShippingQuery(query) => Some(&query.from),
+            Self::PreCheckoutQuery(query) => Some(&query.from),
+            Self::PurchasedPaidMedia(purchase) => Some(&purchase.from),
+            Self::PollAnswer(answer) => answer.user.as_ref(),
+            Self::MyChatMember(member) | Self::ChatMember(member) => Some(&member.from),
+            Self::ChatJoinRequest(request) => Some(&request.from),
+            _ => None,
+        }
+    }
+
+    /// Returns the chat the update happened in, if the kind has one.
+    pub fn chat(&self) -> Option<&Chat> {
+        match self {
+            Self::Message(message)
+            | Self::EditedMessage(message)
+            | Self::EditedChannelPost(message)
+            | Self::BusinessMessage(message)
+            | Self::EditedBusinessMessage(message) => Some(&message.chat),
+            Self::ChannelPost(post) => Some(&post.chat),
+            Self::DeletedBusinessMessages(deleted) => Some(&deleted.chat),
+            Self::MessageReaction(reaction) => Some(&reaction.chat),
+            Self::MessageReactionCount(count) => Some(&count.chat),
+            Self::CallbackQuery(query) => query.message.as_ref().map(|m| &m.chat),
+            Self::MyChatMember(member) | Self::ChatMember(member) => Some(&member.chat),
+            Self::ChatJoinRequest(request) => Some(&request.chat),
+            Self::ChatBoost(boost) => Some(&boost.chat),
+            Self::RemovedChatBoost(boost) => Some(&boost.chat),
+            _ => None,
+        }
+    }
+}
+
+impl Update {
+    /// Returns the typed payload of this update.
+    ///
+    /// Payloads are parsed on every call; screens that need one field
+    /// repeatedly should keep the result.
+    pub fn kind(&self) -> UpdateKind {
+        if let Some(message) = &self.message {
+            return UpdateKind::Message(message.clone());
+        }
+        if let Some(message) = &self.edited_message {
+            return UpdateKind::EditedMessage(message.clone());
+        }
+        if let Some(post) = &self.channel_post {
+            return UpdateKind::ChannelPost(post.clone());
+        }
+        for key in OTHER_UPDATE_KINDS {
+            if let Some(payload) = self.other.get(key) {
+                return UpdateKind::from_other(key, payload);
+            }
+        }
+        match self.other.iter().next() {
+            Some((key, payload)) => UpdateKind::from_other(key, payload),
+            None => UpdateKind::Empty,
+        }
+    }
+}
+
+/// A connection of the bot to a business account (`business_connection`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessConnection {
+    pub id: String,
+    pub user: User,
+    pub user_chat_id: i64,
+    pub date: i64,
+    pub is_enabled: bool,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// Messages deleted from a connected business account (`deleted_business_messages`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessMessagesDeleted {
+    pub business_connection_id: String,
+    pub chat: Chat,
+    pub message_ids: Vec<i64>,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A reaction type: an emoji, a custom emoji or a paid reaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReactionType {
+    #[serde(rename = "type")]
+    pub reaction_type: String,
+    pub emoji: Option<String>,
+    pub custom_emoji_id: Option<String>,
+}
+
+impl ReactionType {
+    /// Returns the emoji, the custom emoji ID or the reaction type.
+    pub fn label(&self) -> &str {
+        self.emoji
+            .as_deref()
+            .or(self.custom_emoji_id.as_deref())
+            .unwrap_or(&self.reaction_type)
+    }
+}
+
+/// A change of one user's reactions to a message (`message_reaction`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReactionUpdated {
+    pub chat: Chat,
+    pub message_id: i64,
+    /// Absent for anonymous reactions
+    pub user: Option<User>,
+    pub actor_chat: Option<Chat>,
+    pub date: i64,
+    pub old_reaction: Vec<ReactionType>,
+    pub new_reaction: Vec<ReactionType>,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// Count of one reaction on a message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionCount {
+    #[serde(rename = "type")]
+    pub reaction_type: ReactionType,
+    pub total_count: i64,
+}
+
+/// Anonymous reaction counts of a message (`message_reaction_count`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReactionCountUpdated {
+    pub chat: Chat,
+    pub message_id: i64,
+    pub date: i64,
+    pub reactions: Vec<ReactionCount>,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// An inline query (`inline_query`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InlineQuery {
+    pub id: String,
+    pub from: User,
+    pub query: String,
+    pub offset: String,
+    pub chat_type: Option<String>,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// An inline result chosen by a user (`chosen_inline_result`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChosenInlineResult {
+    pub result_id: String,
+    pub from: User,
+    pub query: String,
+    pub inline_message_id: Option<String>,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A press of an inline keyboard button (`callback_query`).
+///
+/// `message` is absent for buttons of inline messages; for messages the bot
+/// can no longer access it only carries the chat, ID and a zero date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallbackQuery {
+    pub id: String,
+    pub from: User,
+    pub message: Option<Message>,
+    pub inline_message_id: Option<String>,
+    pub chat_instance: String,
+    pub data: Option<String>,
+    pub game_short_name: Option<String>,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A shipping address request of an invoice (`shipping_query`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShippingQuery {
+    pub id: String,
+    pub from: User,
+    pub invoice_payload: String,
+    pub shipping_address: Value,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A checkout confirmation request (`pre_checkout_query`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreCheckoutQuery {
+    pub id: String,
+    pub from: User,
+    pub currency: String,
+    /// Price in the smallest units of the currency
+    pub total_amount: i64,
+    pub invoice_payload: String,
+    pub shipping_option_id: Option<String>,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A purchase of paid media (`purchased_paid_media`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaidMediaPurchased {
+    pub from: User,
+    pub paid_media_payload: String,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// One answer option of a poll.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollOption {
+    pub text: String,
+    pub voter_count: i64,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A new state of a poll (`poll`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Poll {
+    pub id: String,
+    pub question: String,
+    pub options: Vec<PollOption>,
+    pub total_voter_count: i64,
+    pub is_closed: bool,
+    pub is_anonymous: bool,
+    #[serde(rename = "type")]
+    pub poll_type: String,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A vote in a non-anonymous poll (`poll_answer`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollAnswer {
+    pub poll_id: String,
+    /// Chat that voted, for votes on behalf of a chat
+    pub voter_chat: Option<Chat>,
+    pub user: Option<User>,
+    /// Chosen options; empty if the vote was retracted
+    pub option_ids: Vec<i64>,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A request to join a chat (`chat_join_request`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatJoinRequest {
+    pub chat: Chat,
+    pub from: User,
+    pub user_chat_id: i64,
+    pub date: i64,
+    pub bio: Option<String>,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A boost of a chat.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatBoost {
+    pub boost_id: String,
+    pub add_date: i64,
+    pub expiration_date: i64,
+    pub source: Value,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A chat was boosted or a boost changed (`chat_boost`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatBoostUpdated {
+    pub chat: Chat,
+    pub boost: ChatBoost,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A boost was removed from a chat (`removed_chat_boost`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatBoostRemoved {
+    pub chat: Chat,
+    pub boost_id: String,
+    pub remove_date: i64,
+    pub source: Value,
+
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn update(value: Value) -> Update {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_typed_payloads() {
+        let callback = update(json!({
+            "update_id": 1,
+            "callback_query": {
+                "id": "42",
+                "from": {"id": 7, "is_bot": false, "first_name": "Ann"},
+                "message": {
+                    "message_id": 5,
+                    "chat": {"id": -100, "type": "supergroup", "title": "G"},
+                    "date": 1000
+                },
+                "chat_instance": "ci",
+                "data": "buy:1",
+                "future_field": true
+            }
+        }));
+        let kind = callback.kind();
+        assert_eq!(kind.name(), "callback_query");
+        assert_eq!(kind.sender().map(|u| u.id), Some(7));
+        assert_eq!(kind.chat().map(|c| c.id), Some(-100));
+        let UpdateKind::CallbackQuery(query) = kind else {
+            panic!("expected a callback query");
+        };
+        assert_eq!(query.data.as_deref(), Some("buy:1"));
+        assert_eq!(query.other.get("future_field"), Some(&json!(true)));
+
+        let reaction = update(json!({
+            "update_id": 2,
+            "message_reaction": {
+                "chat": {"id": 1, "type": "private"},
+                "message_id": 3,
+                "user": {"id": 7, "is_bot": false, "first_name": "Ann"},
+                "date": 1000,
+                "old_reaction": [],
+                "new_reaction": [{"type": "emoji", "emoji": "👍"}]
+            }
+        }));
+        let UpdateKind::MessageReaction(reaction) = reaction.kind() else {
+            panic!("expected a reaction");
+        };
+        assert_eq!(reaction.new_reaction[0].label(), "👍");
+    }
+
+    #[test]
+    fn test_unknown_and_malformed_kinds_are_kept() {
+        let payload = json!({"some": ["future", "payload"], "n": 1.5});
+        let future = update(json!({"update_id": 3, "future_kind": payload.clone()}));
+        match future.kind() {
+            UpdateKind::Unknown { key, payload: kept } => {
+                assert_eq!(key, "future_kind");
+                assert_eq!(kept, payload);
+            }
+            other => panic!("expected an unknown kind, got {}", other.name()),
+        }
+        assert_eq!(future.get_update_type(), "future_kind");
+        assert!(!future.kind().is_unparsed());
+
+        // A known kind whose payload doesn't match the model is kept raw
+        let malformed = json!({"id": "1"});
+        let poll = update(json!({"update_id": 4, "poll": malformed.clone()}));
+        assert!(matches!(
+            poll.kind(),
+            UpdateKind::Unknown { ref key, ref payload } if key == "poll" && *payload == malformed
+        ));
+        assert!(poll.kind().is_unparsed());
+        assert_eq!(poll.get_update_type(), "poll");
+
+        // The update itself serializes back with the payload untouched
+        let json = serde_json::to_value(&future).unwrap();
+        assert_eq!(json["future_kind"], payload);
+
+        let empty = update(json!({"update_id": 5}));
+        assert!(matches!(empty.kind(), UpdateKind::Empty));
+        assert_eq!(empty.get_update_type(), "unknown");
+    }
+}
@@ -1,6 +1,19 @@
 use std::collections::HashMap;
 
-use super::types::{DiscoveredChat, TopicInfo, Update};
+use super::conversation::{ConversationNode, Conversations, ThreadRow};
+use super::drift::SchemaDrift;
+use super::history::{MessageHistory, MessageRevision};
+use super::types::{
+    BotStatusChange, Chat, DiscoveredChat, ForumTopic, KnownUser, Message, TopicInfo, Update, User,
+    UserName,
+};
+use super::update_kind::UpdateKind;
+
+/// Number of bot status changes kept per chat.
+const MAX_BOT_STATUS_HISTORY: usize = 50;
+
+/// Number of distinct names kept per user.
+const MAX_NAME_HISTORY: usize = 20;
 
 /// Processes Telegram updates and maintains discovered chat information.
 ///
@@ -8,6 +21,11 @@ use super::types::{DiscoveredChat, TopicInfo, Update};
 /// - Track all chats the bot has interacted with
 /// - Count messages per chat
 /// - Discover and track topics in forum groups
+/// - Track the bot's own membership status per chat
+/// - Merge groups upgraded to supergroups into one chat
+/// - Keep a registry of the users seen and the chats they appeared in
+/// - Keep every revision of edited messages and channel posts
+/// - Thread messages and the bot's own replies into conversations
 /// - Maintain the last seen timestamp for each chat
 ///
 /// # Examples
@@ -23,6 +41,16 @@ use super::types::{DiscoveredChat, TopicInfo, Update};
 /// ```
 pub struct UpdateProcessor {
     discovered_chats: HashMap<i64, DiscoveredChat>,
+    /// Registry of every user seen in updates, by user ID
+    known_users: HashMap<i64, KnownUser>,
+    /// Group IDs upgraded to supergroups, mapped to the new ID
+    migrations: HashMap<i64, i64>,
+    /// Original version and edits of every message seen
+    message_history: MessageHistory,
+    /// Reply trees of received and sent messages
+    conversations: Conversations,
+    /// Fields unknown to the targeted Bot API version
+    schema_drift: SchemaDrift,
     last_update_id: i64,
 }
 
@@ -31,6 +59,11 @@ impl UpdateProcessor {
     pub fn new() -> Self {
         Self {
             discovered_chats: HashMap::new(),
+            known_users: HashMap::new(),
+            migrations: HashMap::new(),
+            message_history: MessageHistory::new(),
+            conversations: Conversations::new(),
+            schema_drift: SchemaDrift::new(),
             last_update_id: 0,
         }
     }
@@ -62,69 +95,405 @@ impl UpdateProcessor {
     /// # }
     /// ```
     pub fn process_updates(&mut self, updates: Vec<Update>) {
+        // Callback and inline queries carry no date of their own
+        let now = chrono::Utc::now().timestamp();
         for update in updates {
             if update.update_id > self.last_update_id {
                 self.last_update_id = update.update_id;
             }
 
+            self.record_update_users(&update, now);
+            self.record_revisions(&update);
+            self.schema_drift.record(&update, now);
+
             // Process message
             if let Some(message) = &update.message {
-                let chat_id = message.chat.id;
-                let entry =
-                    self.discovered_chats
-                        .entry(chat_id)
-                        .or_insert_with(|| DiscoveredChat {
-                            chat: message.chat.clone(),
-                            last_seen: message.date,
-                            message_count: 0,
-                            topics: Vec::new(),
-                        });
-
+                self.conversations.record(message, false);
+                let entry = self.discover_chat(&message.chat, message.date);
                 entry.message_count += 1;
-                entry.last_seen = message.date.max(entry.last_seen);
 
                 // Handle topics for forum groups
                 if let Some(thread_id) = message.message_thread_id {
-                    if let Some(topic) = entry.topics.iter_mut().find(|t| t.thread_id == thread_id)
-                    {
-                        topic.message_count += 1;
-                        topic.last_seen = message.date.max(topic.last_seen);
-                    } else {
-                        entry.topics.push(TopicInfo {
-                            thread_id,
-                            name: None, // We can't extract topic name from regular messages
-                            message_count: 1,
-                            last_seen: message.date,
-                        });
-                    }
+                    let topic = find_or_insert_topic(&mut entry.topics, thread_id);
+                    topic.message_count += 1;
+                    topic.last_seen = message.date.max(topic.last_seen);
+                    apply_topic_service_message(topic, message);
+                }
+
+                // The last message of an upgraded group and the first of the new
+                // supergroup both announce the migration
+                if let Some(new_chat_id) = message.migrate_to_chat_id() {
+                    self.link_migration(message.chat.id, new_chat_id);
+                }
+                if let Some(old_chat_id) = message.migrate_from_chat_id() {
+                    self.link_migration(old_chat_id, message.chat.id);
                 }
             }
 
             // Process channel posts
             if let Some(channel_post) = &update.channel_post {
-                let chat_id = channel_post.chat.id;
-                let entry =
-                    self.discovered_chats
-                        .entry(chat_id)
-                        .or_insert_with(|| DiscoveredChat {
-                            chat: channel_post.chat.clone(),
-                            last_seen: channel_post.date,
-                            message_count: 0,
-                            topics: Vec::new(),
-                        });
-
+                let entry = self.discover_chat(&channel_post.chat, channel_post.date);
                 entry.message_count += 1;
-                entry.last_seen = channel_post.date.max(entry.last_seen);
             }
 
-            // Process edited messages
-            if let Some(edited_message) = &update.edited_message {
-                let chat_id = edited_message.chat.id;
+            // Process the bot's own membership changes; this also discovers chats
+            // the bot was added to before anyone wrote there
+            if let Some(member_update) = update.my_chat_member() {
+                let entry = self.discover_chat(&member_update.chat, member_update.date);
+                entry.bot_status_history.push(BotStatusChange {
+                    date: member_update.date,
+                    old_status: member_update.old_chat_member.status,
+                    new_status: member_update.new_chat_member.status,
+                    changed_by: member_update.from.display_name(),
+                });
+                if entry.bot_status_history.len() > MAX_BOT_STATUS_HISTORY {
+                    entry.bot_status_history.remove(0);
+                }
+            }
+
+            // Membership changes of other users only discover the chat
+            if let Some(member_update) = update.chat_member() {
+                self.discover_chat(&member_update.chat, member_update.date);
+            }
+
+            // Process edited messages and channel posts
+            let edited_channel_post = update.edited_channel_post();
+            for edited in [update.edited_message.as_ref(), edited_channel_post.as_ref()]
+                .into_iter()
+                .flatten()
+            {
+                let chat_id = self.resolve_chat_id(edited.chat.id);
                 if let Some(entry) = self.discovered_chats.get_mut(&chat_id) {
-                    entry.last_seen = edited_message.date.max(entry.last_seen);
+                    entry.last_seen = edited.date.max(entry.last_seen);
+                }
+            }
+        }
+    }
+
+    /// Adds the message, channel post or edit in an update to the edit history.
+    fn record_revisions(&mut self, update: &Update) {
+        let id = update.update_id;
+        if let Some(message) = &update.message {
+            let revision =
+                MessageRevision::new(id, message.date, message.text.as_deref(), &message.other);
+            self.message_history
+                .record(message.chat.id, message.message_id, revision);
+        }
+        if let Some(post) = &update.channel_post {
+            let revision = MessageRevision::new(id, post.date, post.text.as_deref(), &post.other);
+            self.message_history
+                .record(post.chat.id, post.message_id, revision);
+        }
+        let edited_channel_post = update.edited_channel_post();
+        for edited in [update.edited_message.as_ref(), edited_channel_post.as_ref()]
+            .into_iter()
+            .flatten()
+        {
+            let revision =
+                MessageRevision::new(id, edited.date, edited.text.as_deref(), &edited.other);
+            self.message_history
+                .record(edited.chat.id, edited.message_id, revision);
+        }
+    }
+
+    /// Returns the original version and all edits of a message, oldest first.
+    pub fn get_message_history(&self, chat_id: i64, message_id: i64) -> Option<&[MessageRevision]> {
+        self.message_history.get(chat_id, message_id)
+    }
+
+    /// Returns the fields seen in updates that the targeted Bot API version doesn't have.
+    pub fn get_schema_drift(&self) -> &SchemaDrift {
+        &self.schema_drift
+    }
+
+    /// Returns the number of edits seen for a message.
+    pub fn edit_count(&self, chat_id: i64, message_id: i64) -> usize {
+        self.message_history.edit_count(chat_id, message_id)
+    }
+
+    /// Adds a message sent or edited by the bot (from an API response) to its
+    /// conversation and edit history.
+    ///
+    /// `update_id` identifies the revision in the history; outgoing messages use
+    /// negative IDs since they never arrive as updates.
+    pub fn record_outgoing(&mut self, update_id: i64, message: &Message) {
+        self.conversations.record(message, true);
+        let revision = MessageRevision::new(
+            update_id,
+            message.date,
+            message.text.as_deref(),
+            &message.other,
+        );
+        self.message_history
+            .record(message.chat.id, message.message_id, revision);
+    }
+
+    /// Returns a message of the conversation model.
+    pub fn get_conversation_node(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+    ) -> Option<&ConversationNode> {
+        self.conversations.get(chat_id, message_id)
+    }
+
+    /// Returns the reply thread a message belongs to, depth-first from its root.
+    pub fn get_conversation_thread(&self, chat_id: i64, message_id: i64) -> Vec<ThreadRow<'_>> {
+        self.conversations.thread(chat_id, message_id)
+    }
+
+    /// Feeds the user registry with every sender and member found in an update.
+    fn record_update_users(&mut self, update: &Update, now: i64) {
+        for message in [&update.message, &update.edited_message]
+            .into_iter()
+            .flatten()
+        {
+            if let Some(from) = &message.from {
+                self.record_user(from, Some(message.chat.id), message.date);
+            }
+            if let Some(reply) = &message.reply_to_message
+                && let Some(from) = &reply.from
+            {
+                self.refresh_user(from, reply.date);
+            }
+        }
+
+        for member_update in [update.my_chat_member(), update.chat_member()]
+            .into_iter()
+            .flatten()
+        {
+            let chat_id = Some(member_update.chat.id);
+            self.record_user(&member_update.from, chat_id, member_update.date);
+            if !member_update.new_chat_member.user.is_bot {
+                self.refresh_user(&member_update.new_chat_member.user, member_update.date);
+            }
+        }
+
+        // Senders of the other kinds; most carry no date of their own
+        if update.message.is_some() || update.edited_message.is_some() {
+            return;
+        }
+        let kind = update.kind();
+        let date = match &kind {
+            UpdateKind::MyChatMember(_) | UpdateKind::ChatMember(_) => return,
+            UpdateKind::MessageReaction(reaction) => reaction.date,
+            UpdateKind::ChatJoinRequest(request) => request.date,
+            UpdateKind::BusinessConnection(connection) => connection.date,
+            _ => now,
+        };
+        if let Some(sender) = kind.sender() {
+            self.record_user(sender, kind.chat().map(|chat| chat.id), date);
+        }
+    }
+
+    /// Records an update sent by a user, optionally in a chat.
+    ///
+    /// Counts the update and adds the chat; names and profile are refreshed as
+    /// in [`Self::refresh_user`].
+    pub fn record_user(&mut self, user: &User, chat_id: Option<i64>, date: i64) {
+        let chat_id = chat_id.map(|id| self.resolve_chat_id(id));
+        let known = self.refresh_user(user, date);
+        known.update_count += 1;
+
+        if let Some(chat_id) = chat_id
+            && !known.chat_ids.contains(&chat_id)
+        {
+            known.chat_ids.push(chat_id);
+        }
+    }
+
+    /// Refreshes a user that appears inside an update without sending it,
+    /// e.g. the author of a replied-to message.
+    ///
+    /// A new name or username is added to the user's history; language and
+    /// premium status keep their last reported value.
+    fn refresh_user(&mut self, user: &User, date: i64) -> &mut KnownUser {
+        let known = self
+            .known_users
+            .entry(user.id)
+            .or_insert_with(|| KnownUser {
+                user: user.clone(),
+                name_history: Vec::new(),
+                first_seen: date,
+                last_seen: date,
+                chat_ids: Vec::new(),
+                update_count: 0,
+            });
+
+        known.first_seen = known.first_seen.min(date);
+        known.last_seen = known.last_seen.max(date);
+
+        let name = UserName {
+            since: date,
+            full_name: user.full_name(),
+            username: user.username.clone(),
+        };
+        let is_new_name = !known
+            .name_history
+            .iter()
+            .any(|n| n.full_name == name.full_name && n.username == name.username);
+        if is_new_name {
+            known.name_history.push(name);
+            known.name_history.sort_by_key(|n| n.since);
+            if known.name_history.len() > MAX_NAME_HISTORY {
+                known.name_history.remove(0);
+            }
+        }
+
+        // Older sightings (e.g. replied-to messages) do not overwrite the profile
+        if date >= known.last_seen {
+            let language_code = user
+                .language_code
+                .clone()
+                .or_else(|| known.user.language_code.take());
+            let is_premium = user.is_premium.or(known.user.is_premium);
+            known.user = User {
+                language_code,
+                is_premium,
+                ..user.clone()
+            };
+        }
+        known
+    }
+
+    /// Returns all known users sorted by last sighting (most recent first).
+    pub fn get_known_users(&self) -> Vec<&KnownUser> {
+        let mut users: Vec<&KnownUser> = self.known_users.values().collect();
+        users.sort_by_key(|user| (std::cmp::Reverse(user.last_seen), user.user.id));
+        users
+    }
+
+    /// Returns the discovered chat, adding it on first sight, and bumps its last activity.
+    ///
+    /// Chats of upgraded groups resolve to their supergroup.
+    fn discover_chat(&mut self, chat: &Chat, date: i64) -> &mut DiscoveredChat {
+        let chat_id = self.resolve_chat_id(chat.id);
+        let entry = self.discovered_chats.entry(chat_id).or_insert_with(|| {
+            if chat_id == chat.id {
+                DiscoveredChat {
+                    chat: chat.clone(),
+                    last_seen: date,
+                    message_count: 0,
+                    topics: Vec::new(),
+                    bot_status_history: Vec::new(),
+                    migrated_from_chat_id: None,
+                }
+            } else {
+                migrated_chat(chat, chat_id, date)
+            }
+        });
+        entry.last_seen = date.max(entry.last_seen);
+        entry
+    }
+
+    /// Links a group to the supergroup it was upgraded to.
+    ///
+    /// The group's chat is merged into the supergroup's: counts are summed,
+    /// topics and bot status history combined, and both IDs kept. Messages that
+    /// still arrive for the old ID are counted in the supergroup.
+    pub fn link_migration(&mut self, old_chat_id: i64, new_chat_id: i64) {
+        if old_chat_id == new_chat_id || self.resolve_chat_id(new_chat_id) == old_chat_id {
+            return;
+        }
+        self.migrations.insert(old_chat_id, new_chat_id);
+        for known in self.known_users.values_mut() {
+            if let Some(index) = known.chat_ids.iter().position(|&id| id == old_chat_id) {
+                if known.chat_ids.contains(&new_chat_id) {
+                    known.chat_ids.remove(index);
+                } else {
+                    known.chat_ids[index] = new_chat_id;
                 }
             }
         }
+
+        let Some(old) = self.discovered_chats.remove(&old_chat_id) else {
+            if let Some(new) = self.discovered_chats.get_mut(&new_chat_id) {
+                new.migrated_from_chat_id = Some(old_chat_id);
+            }
+            return;
+        };
+        let new = self
+            .discovered_chats
+            .entry(new_chat_id)
+            .or_insert_with(|| migrated_chat(&old.chat, new_chat_id, old.last_seen));
+        new.migrated_from_chat_id = Some(old_chat_id);
+        new.message_count += old.message_count;
+        new.last_seen = new.last_seen.max(old.last_seen);
+        for topic in old.topics {
+            if !new.topics.iter().any(|t| t.thread_id == topic.thread_id) {
+                new.topics.push(topic);
+            }
+        }
+        new.bot_status_history.extend(old.bot_status_history);
+        new.bot_status_history.sort_by_key(|change| change.date);
+    }
+
+    /// Returns the ID a chat is known under, following group-to-supergroup migrations.
+    pub fn resolve_chat_id(&self, chat_id: i64) -> i64 {
+        let mut resolved = chat_id;
+        // A group is upgraded at most once; the bound only guards against cycles
+        for _ in 0..4 {
+            match self.migrations.get(&resolved) {
+                Some(&new_chat_id) => resolved = new_chat_id,
+                None => break,
+            }
+        }
+        resolved
+    }
+
+    /// Records a topic returned by `createForumTopic` in a discovered chat.
+    ///
+    /// Updates name and icon if the topic is already known.
+    /// Returns false if the chat has not been discovered.
+    pub fn upsert_topic(&mut self, chat_id: i64, forum_topic: &ForumTopic) -> bool {
+        let Some(chat) = self.discovered_chats.get_mut(&chat_id) else {
+            return false;
+        };
+        let topic = find_or_insert_topic(&mut chat.topics, forum_topic.message_thread_id);
+        topic.name = Some(forum_topic.name.clone());
+        topic.icon_color = Some(forum_topic.icon_color);
+        topic.icon_custom_emoji_id = forum_topic.icon_custom_emoji_id.clone();
+        true
+    }
+
+    /// Applies an `editForumTopic` change; `None` values are left untouched.
+    pub fn rename_topic(
+        &mut self,
+        chat_id: i64,
+        thread_id: i64,
+        name: Option<&str>,
+        icon_custom_emoji_id: Option<&str>,
+    ) {
+        if let Some(topic) = self.topic_mut(chat_id, thread_id) {
+            if let Some(name) = name {
+                topic.name = Some(name.to_string());
+            }
+            if let Some(icon) = icon_custom_emoji_id {
+                topic.icon_custom_emoji_id = Some(icon.to_string()).filter(|i| !i.is_empty());
+            }
+        }
+    }
+
+    /// Marks a topic as closed or reopened.
+    pub fn set_topic_closed(&mut self, chat_id: i64, thread_id: i64, is_closed: bool) {
+        if let Some(topic) = self.topic_mut(chat_id, thread_id) {
+            topic.is_closed = is_closed;
+        }
+    }
+
+    /// Forgets a deleted topic.
+    pub fn remove_topic(&mut self, chat_id: i64, thread_id: i64) {
+        if let Some(chat) = self.discovered_chats.get_mut(&chat_id) {
+            chat.topics.retain(|t| t.thread_id != thread_id);
+        }
+    }
+
+    fn topic_mut(&mut self, chat_id: i64, thread_id: i64) -> Option<&mut TopicInfo> {
+        self.discovered_chats
+            .get_mut(&chat_id)?
+            .topics
+            .iter_mut()
+            .find(|t| t.thread_id == thread_id)
     }
 
     /// Returns all discovered chats sorted by last activity (most recent first).
@@ -135,7 +504,7 @@ impl UpdateProcessor {
     /// in descending order (newest first).
     pub fn get_discovered_chats(&self) -> Vec<&DiscoveredChat> {
         let mut chats: Vec<&DiscoveredChat> = self.discovered_chats.values().collect();
-        chats.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+        chats.sort_by_key(|chat| std::cmp::Reverse(chat.last_seen));
         chats
     }
 }
@@ -146,6 +515,76 @@ impl Default for UpdateProcessor {
     }
 }
 
+/// Creates the supergroup a group was upgraded to, before any of its own messages were seen.
+fn migrated_chat(old_chat: &Chat, new_chat_id: i64, date: i64) -> DiscoveredChat {
+    DiscoveredChat {
+        chat: Chat {
+            id: new_chat_id,
+            chat_type: "supergroup".to_string(),
+            ..old_chat.clone()
+        },
+        last_seen: date,
+        message_count: 0,
+        topics: Vec::new(),
+        bot_status_history: Vec::new(),
+        migrated_from_chat_id: Some(old_chat.id),
+    }
+}
+
+/// Returns the topic with `thread_id`, adding an empty one if it is not known yet.
+fn find_or_insert_topic(topics: &mut Vec<TopicInfo>, thread_id: i64) -> &mut TopicInfo {
+    match topics.iter().position(|t| t.thread_id == thread_id) {
+        Some(index) => &mut topics[index],
+        None => {
+            topics.push(TopicInfo {
+                thread_id,
+                name: None,
+                message_count: 0,
+                last_seen: 0,
+                icon_color: None,
+                icon_custom_emoji_id: None,
+                is_closed: false,
+            });
+            topics.last_mut().expect("topic was just pushed")
+        }
+    }
+}
+
+/// Updates name, icon and closed state of a topic from forum service messages.
+///
+/// Regular topic messages reply to the `forum_topic_created` message, which
+/// names topics created before the bot started listening. That name is only
+/// used while none is known, as it does not reflect later edits.
+fn apply_topic_service_message(topic: &mut TopicInfo, message: &Message) {
+    if let Some(created) = message.forum_topic_created() {
+        topic.name = Some(created.name);
+        topic.icon_color = Some(created.icon_color);
+        topic.icon_custom_emoji_id = created.icon_custom_emoji_id;
+    } else if let Some(reply) = &message.reply_to_message
+        && reply.message_id == topic.thread_id
+        && let Some(created) = reply.forum_topic_created()
+    {
+        topic.icon_color = Some(created.icon_color);
+        if topic.name.is_none() {
+            topic.name = Some(created.name);
+            topic.icon_custom_emoji_id = created.icon_custom_emoji_id;
+        }
+    }
+
+    if let Some(edited) = message.forum_topic_edited() {
+        if let Some(name) = edited.name {
+            topic.name = Some(name);
+        }
+        if let Some(icon) = edited.icon_custom_emoji_id {
+            topic.icon_custom_emoji_id = Some(icon).filter(|i| !i.is_empty());
+        }
+    }
+
+    if let Some(is_closed) = message.forum_topic_closed() {
+        topic.is_closed = is_closed;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,6 +600,11 @@ mod tests {
                 first_name: "Test".to_string(),
                 last_name: None,
                 username: Some("testuser".to_string()),
+                can_join_groups: None,
+                can_read_all_group_messages: None,
+                supports_inline_queries: None,
+                language_code: None,
+                is_premium: None,
             }),
             chat: Chat {
                 id: chat_id,
@@ -607,6 +1051,87 @@ mod tests {
         assert!(!chats.iter().any(|c| c.chat.id == 100));
     }
 
+    #[test]
+    fn test_edit_history_of_messages_and_channel_posts() {
+        let mut processor = UpdateProcessor::new();
+
+        let mut edited = create_test_message(100, 1, 1000);
+        edited.text = Some("Edited message".to_string());
+        edited
+            .other
+            .insert("edit_date".to_string(), serde_json::json!(1100));
+        processor.process_updates(vec![
+            Update {
+                update_id: 1,
+                message: Some(create_test_message(100, 1, 1000)),
+                channel_post: None,
+                edited_message: None,
+                other: std::collections::HashMap::new(),
+            },
+            Update {
+                update_id: 2,
+                message: None,
+                channel_post: None,
+                edited_message: Some(edited),
+                other: std::collections::HashMap::new(),
+            },
+            Update {
+                update_id: 3,
+                message: None,
+                channel_post: None,
+                edited_message: None,
+                other: std::collections::HashMap::from([(
+                    "edited_channel_post".to_string(),
+                    serde_json::json!({
+                        "message_id": 7,
+                        "chat": {"id": -1001, "type": "channel", "title": "News"},
+                        "date": 900,
+                        "edit_date": 1200,
+                        "caption": "New caption",
+                        "caption_entities": [{"type": "bold", "offset": 0, "length": 3}]
+                    }),
+                )]),
+            },
+        ]);
+
+        let revisions = processor.get_message_history(100, 1).unwrap();
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[0].text.as_deref(), Some("Test message"));
+        assert_eq!(revisions[0].edit_date, None);
+        assert_eq!(revisions[1].text.as_deref(), Some("Edited message"));
+        assert_eq!(revisions[1].edit_date, Some(1100));
+        assert_eq!(processor.edit_count(100, 1), 1);
+
+        // An edit whose original was never seen is still kept
+        let post = processor.get_message_history(-1001, 7).unwrap();
+        assert_eq!(post.len(), 1);
+        assert_eq!(post[0].text.as_deref(), Some("New caption"));
+        assert_eq!(post[0].entities.len(), 1);
+    }
+
+    #[test]
+    fn test_bot_replies_join_conversation() {
+        let mut processor = UpdateProcessor::new();
+        let question = create_test_message(100, 1, 1000);
+        processor.process_updates(vec![Update {
+            update_id: 1,
+            message: Some(question.clone()),
+            channel_post: None,
+            edited_message: None,
+            other: std::collections::HashMap::new(),
+        }]);
+
+        let mut answer = create_test_message(100, 2, 1001);
+        answer.reply_to_message = Some(Box::new(question));
+        processor.record_outgoing(-1, &answer);
+
+        let thread = processor.get_conversation_thread(100, 1);
+        assert_eq!(thread.len(), 2);
+        assert_eq!(thread[1].depth, 1);
+        assert!(thread[1].node.outgoing);
+        assert!(!thread[0].node.outgoing);
+    }
+
     // Last seen timestamp tests
     #[test]
     fn test_last_seen_updates_to_most_recent() {
@@ -790,4 +1315,319 @@ mod tests {
         // Should discover both chats
         assert_eq!(processor.get_discovered_chats().len(), 2);
     }
+
+    #[test]
+    fn test_topic_management_updates_discovered_topics() {
+        let mut processor = UpdateProcessor::new();
+        let mut message = create_test_message(-100, 1, 1000);
+        message.chat.chat_type = "supergroup".to_string();
+        processor.process_updates(vec![Update {
+            update_id: 1,
+            message: Some(message),
+            channel_post: None,
+            edited_message: None,
+            other: std::collections::HashMap::new(),
+        }]);
+
+        let created = ForumTopic {
+            message_thread_id: 7,
+            name: "Test topic".to_string(),
+            icon_color: 0x6FB9F0,
+            icon_custom_emoji_id: None,
+        };
+        assert!(processor.upsert_topic(-100, &created));
+        assert!(!processor.upsert_topic(-200, &created));
+
+        processor.rename_topic(-100, 7, Some("Renamed"), Some("5312"));
+        processor.set_topic_closed(-100, 7, true);
+        let topic = &processor.get_discovered_chats()[0].topics[0];
+        assert_eq!(topic.name.as_deref(), Some("Renamed"));
+        assert_eq!(topic.icon_custom_emoji_id.as_deref(), Some("5312"));
+        assert!(topic.is_closed);
+
+        processor.remove_topic(-100, 7);
+        assert!(processor.get_discovered_chats()[0].topics.is_empty());
+    }
+
+    #[test]
+    fn test_forum_service_messages_track_topic_state() {
+        let mut processor = UpdateProcessor::new();
+        let topic_message = |message_id: i64, field: &str, value: serde_json::Value| {
+            let mut message = create_test_message(-100, message_id, 1000 + message_id);
+            message.chat.chat_type = "supergroup".to_string();
+            message.message_thread_id = Some(10);
+            message.other.insert(field.to_string(), value);
+            message
+        };
+
+        // A topic created before the bot started listening is named via reply_to_message
+        let mut reply = topic_message(11, "is_topic_message", serde_json::json!(true));
+        reply.reply_to_message = Some(Box::new(topic_message(
+            10,
+            "forum_topic_created",
+            serde_json::json!({"name": "Bugs", "icon_color": 0x6FB9F0}),
+        )));
+        let messages = vec![
+            reply,
+            topic_message(
+                12,
+                "forum_topic_edited",
+                serde_json::json!({"name": "Known bugs", "icon_custom_emoji_id": "5312"}),
+            ),
+            topic_message(13, "forum_topic_closed", serde_json::json!({})),
+        ];
+        processor.process_updates(
+            messages
+                .into_iter()
+                .enumerate()
+                .map(|(i, message)| Update {
+                    update_id: i as i64 + 1,
+                    message: Some(message),
+                    channel_post: None,
+                    edited_message: None,
+                    other: std::collections::HashMap::new(),
+                })
+                .collect(),
+        );
+
+        let topic = &processor.get_discovered_chats()[0].topics[0];
+        assert_eq!(topic.thread_id, 10);
+        assert_eq!(topic.name.as_deref(), Some("Known bugs"));
+        assert_eq!(topic.icon_color, Some(0x6FB9F0));
+        assert_eq!(topic.icon_custom_emoji_id.as_deref(), Some("5312"));
+        assert!(topic.is_closed);
+        assert_eq!(topic.message_count, 3);
+
+        // Later replies to the creation message keep the edited name
+        let mut reply = topic_message(14, "forum_topic_reopened", serde_json::json!({}));
+        reply.reply_to_message = Some(Box::new(topic_message(
+            10,
+            "forum_topic_created",
+            serde_json::json!({"name": "Bugs", "icon_color": 0x6FB9F0}),
+        )));
+        processor.process_updates(vec![Update {
+            update_id: 4,
+            message: Some(reply),
+            channel_post: None,
+            edited_message: None,
+            other: std::collections::HashMap::new(),
+        }]);
+        let topic = &processor.get_discovered_chats()[0].topics[0];
+        assert_eq!(topic.name.as_deref(), Some("Known bugs"));
+        assert!(!topic.is_closed);
+    }
+
+    #[test]
+    fn test_my_chat_member_tracks_bot_status() {
+        let mut processor = UpdateProcessor::new();
+        let member_update = |update_id: i64, date: i64, old: &str, new: &str| {
+            let mut other = std::collections::HashMap::new();
+            other.insert(
+                "my_chat_member".to_string(),
+                serde_json::json!({
+                    "chat": {"id": -100, "type": "group", "title": "Quiet group"},
+                    "from": {"id": 1, "is_bot": false, "first_name": "Admin", "username": "admin"},
+                    "date": date,
+                    "old_chat_member": {"status": old, "user": {"id": 9, "is_bot": true, "first_name": "Bot"}},
+                    "new_chat_member": {"status": new, "user": {"id": 9, "is_bot": true, "first_name": "Bot"}}
+                }),
+            );
+            Update {
+                update_id,
+                message: None,
+                channel_post: None,
+                edited_message: None,
+                other,
+            }
+        };
+
+        // Being added discovers the chat even without any message
+        processor.process_updates(vec![member_update(1, 1000, "left", "member")]);
+        let chat = &processor.get_discovered_chats()[0];
+        assert_eq!(chat.chat.id, -100);
+        assert_eq!(chat.message_count, 0);
+        assert_eq!(chat.bot_status(), Some("member"));
+        assert_eq!(chat.bot_removed_at(), None);
+
+        processor.process_updates(vec![
+            member_update(2, 2000, "member", "administrator"),
+            member_update(3, 3000, "administrator", "kicked"),
+        ]);
+        let chat = &processor.get_discovered_chats()[0];
+        assert_eq!(chat.bot_status_history.len(), 3);
+        assert_eq!(chat.bot_status_history[2].changed_by, "@admin");
+        assert!(chat.bot_removed());
+        assert_eq!(chat.bot_removed_at(), Some(3000));
+        assert_eq!(chat.last_seen, 3000);
+    }
+
+    #[test]
+    fn test_migration_merges_group_into_supergroup() {
+        let mut processor = UpdateProcessor::new();
+        let group_message = |update_id: i64, message_id: i64, date: i64| {
+            let mut message = create_test_message(-100, message_id, date);
+            message.chat.chat_type = "group".to_string();
+            message.chat.title = Some("Team".to_string());
+            Update {
+                update_id,
+                message: Some(message),
+                channel_post: None,
+                edited_message: None,
+                other: std::collections::HashMap::new(),
+            }
+        };
+
+        let mut migrate_to = group_message(3, 3, 1002);
+        migrate_to
+            .message
+            .as_mut()
+            .unwrap()
+            .other
+            .insert("migrate_to_chat_id".to_string(), serde_json::json!(-1001));
+        processor.process_updates(vec![
+            group_message(1, 1, 1000),
+            group_message(2, 2, 1001),
+            migrate_to,
+        ]);
+
+        let chats = processor.get_discovered_chats();
+        assert_eq!(chats.len(), 1);
+        assert_eq!(chats[0].chat.id, -1001);
+        assert_eq!(chats[0].chat.chat_type, "supergroup");
+        assert_eq!(chats[0].chat.title.as_deref(), Some("Team"));
+        assert_eq!(chats[0].migrated_from_chat_id, Some(-100));
+        assert_eq!(chats[0].message_count, 3);
+
+        // Late messages for the old ID and the supergroup's own migrate_from
+        // message end up in the same chat
+        let mut migrate_from = create_test_message(-1001, 1, 1003);
+        migrate_from.chat.chat_type = "supergroup".to_string();
+        migrate_from
+            .other
+            .insert("migrate_from_chat_id".to_string(), serde_json::json!(-100));
+        processor.process_updates(vec![
+            group_message(4, 4, 1004),
+            Update {
+                update_id: 5,
+                message: Some(migrate_from),
+                channel_post: None,
+                edited_message: None,
+                other: std::collections::HashMap::new(),
+            },
+        ]);
+
+        let chats = processor.get_discovered_chats();
+        assert_eq!(chats.len(), 1);
+        assert_eq!(chats[0].message_count, 5);
+        assert_eq!(chats[0].last_seen, 1004);
+        assert_eq!(processor.resolve_chat_id(-100), -1001);
+        assert_eq!(processor.resolve_chat_id(-1001), -1001);
+    }
+
+    #[test]
+    fn test_migration_reported_before_supergroup_is_seen() {
+        let mut processor = UpdateProcessor::new();
+        processor.link_migration(-100, -1001);
+        assert!(processor.get_discovered_chats().is_empty());
+
+        let mut message = create_test_message(-100, 1, 1000);
+        message.chat.chat_type = "group".to_string();
+        processor.process_updates(vec![Update {
+            update_id: 1,
+            message: Some(message),
+            channel_post: None,
+            edited_message: None,
+            other: std::collections::HashMap::new(),
+        }]);
+
+        let chat = &processor.get_discovered_chats()[0];
+        assert_eq!(chat.chat.id, -1001);
+        assert_eq!(chat.chat.chat_type, "supergroup");
+        assert_eq!(chat.migrated_from_chat_id, Some(-100));
+
+        // A cycle is ignored instead of swapping the IDs back
+        processor.link_migration(-1001, -100);
+        assert_eq!(processor.resolve_chat_id(-100), -1001);
+        assert_eq!(processor.get_discovered_chats()[0].message_count, 1);
+    }
+
+    #[test]
+    fn test_user_registry_tracks_names_and_chats() {
+        let mut processor = UpdateProcessor::new();
+
+        let mut first = create_test_message(100, 1, 1000);
+        first.from.as_mut().unwrap().language_code = Some("de".to_string());
+        let mut renamed = create_test_message(-200, 2, 2000);
+        renamed.chat.chat_type = "group".to_string();
+        let from = renamed.from.as_mut().unwrap();
+        from.username = Some("renamed".to_string());
+        from.is_premium = Some(true);
+        // Replying to someone registers them as well, without counting an update
+        let mut replied = create_test_message(-200, 1, 500);
+        replied.from.as_mut().unwrap().id = 456;
+        renamed.reply_to_message = Some(Box::new(replied));
+
+        let mut other = std::collections::HashMap::new();
+        other.insert(
+            "callback_query".to_string(),
+            serde_json::json!({
+                "id": "1",
+                "from": {"id": 789, "is_bot": false, "first_name": "Clicker"},
+                "message": {"message_id": 2, "chat": {"id": -200, "type": "group"}, "date": 2000},
+                "chat_instance": "-42",
+                "data": "ok"
+            }),
+        );
+        processor.process_updates(vec![
+            Update {
+                update_id: 1,
+                message: Some(first),
+                channel_post: None,
+                edited_message: None,
+                other: std::collections::HashMap::new(),
+            },
+            Update {
+                update_id: 2,
+                message: Some(renamed),
+                channel_post: None,
+                edited_message: None,
+                other: std::collections::HashMap::new(),
+            },
+            Update {
+                update_id: 3,
+                message: None,
+                channel_post: None,
+                edited_message: None,
+                other,
+            },
+        ]);
+
+        let users = processor.get_known_users();
+        assert_eq!(users.len(), 3);
+        let user = users.iter().find(|u| u.user.id == 123).unwrap();
+        assert_eq!(user.user.username.as_deref(), Some("renamed"));
+        assert_eq!(user.user.language_code.as_deref(), Some("de"));
+        assert_eq!(user.user.is_premium, Some(true));
+        assert_eq!(user.name_history.len(), 2);
+        assert_eq!(user.name_history[0].username.as_deref(), Some("testuser"));
+        assert_eq!((user.first_seen, user.last_seen), (1000, 2000));
+        assert_eq!(user.chat_ids, vec![100, -200]);
+        assert_eq!(user.update_count, 2);
+        assert!(user.matches("@TESTUSER"));
+        assert!(user.matches("123"));
+        assert!(!user.matches("nobody"));
+
+        let replied = users.iter().find(|u| u.user.id == 456).unwrap();
+        assert_eq!(replied.last_seen, 500);
+        assert_eq!(replied.update_count, 0);
+        assert!(replied.chat_ids.is_empty());
+        let clicker = users.iter().find(|u| u.user.id == 789).unwrap();
+        assert_eq!(clicker.chat_ids, vec![-200]);
+        assert_eq!(clicker.update_count, 1);
+
+        processor.link_migration(-200, -1200);
+        let users = processor.get_known_users();
+        let user = users.iter().find(|u| u.user.id == 123).unwrap();
+        assert_eq!(user.chat_ids, vec![100, -1200]);
+    }
 }
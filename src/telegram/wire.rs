@@ -0,0 +1,212 @@
+//! Verbatim capture of Bot API responses.
+//!
+//! Parsed types re-serialize with their own field order and without the
+//! fields they don't model in a fixed place, so what the Raw JSON screen shows
+//! after parsing is not exactly what Telegram sent. [`WireLog`] keeps the
+//! response bodies as received, and [`split_update_results`] cuts the text of
+//! every single update out of a `getUpdates` body.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::value::RawValue;
+
+/// Number of API responses kept; the oldest are dropped first.
+const MAX_WIRE_CAPTURES: usize = 200;
+
+/// One API response as received.
+#[derive(Debug, Clone)]
+pub struct WireCapture {
+    /// Bot API method, e.g. `sendMessage`
+    pub method: String,
+    /// Unix time the response arrived
+    pub timestamp: i64,
+    /// HTTP status code
+    pub status: u16,
+    /// Response body, byte for byte
+    pub body: Arc<str>,
+}
+
+/// Recent API responses, shared by all clones of a client.
+#[derive(Debug, Clone, Default)]
+pub struct WireLog {
+    captures: Arc<Mutex<VecDeque<WireCapture>>>,
+}
+
+impl WireLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a response, dropping the oldest one when the log is full.
+    pub fn record(&self, capture: WireCapture) {
+        let mut captures = self.lock();
+        captures.push_back(capture);
+        if captures.len() > MAX_WIRE_CAPTURES {
+            captures.pop_front();
+        }
+    }
+
+    /// Returns all kept responses, oldest first.
+    pub fn captures(&self) -> Vec<WireCapture> {
+        self.lock().iter().cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<WireCapture>> {
+        // A panic while holding the lock can't leave the deque inconsistent
+        self.captures
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Returns the verbatim JSON text of every element of `result` in a
+/// `getUpdates` response body, in order.
+pub fn split_update_results(body: &str) -> Result<Vec<String>> {
+    #[derive(Deserialize)]
+    struct Results<'a> {
+        #[serde(borrow, default)]
+        result: Vec<&'a RawValue>,
+    }
+
+    let results: Results = serde_json::from_str(body)?;
+    Ok(results
+        .result
+        .into_iter()
+        .map(|raw| raw.get().to_string())
+        .collect())
+}
+
+/// Indents compact JSON for display without touching its content.
+///
+/// Only whitespace between tokens changes: key order, number formatting and
+/// string escapes stay exactly as received.
+pub fn indent_json(raw: &str) -> String {
+    let mut output = String::with_capacity(raw.len() * 2);
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = raw.trim().chars().peekable();
+
+    let newline = |output: &mut String, depth: usize| {
+        output.push('\n');
+        output.push_str(&"  ".repeat(depth));
+    };
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => {
+                in_string = true;
+                output.push(c);
+            }
+            '{' | '[' => {
+                output.push(c);
+                while chars.peek().is_some_and(|next| next.is_whitespace()) {
+                    chars.next();
+                }
+                // Keep empty objects and arrays on one line
+                match chars.peek() {
+                    Some('}') | Some(']') => {
+                        if let Some(close) = chars.next() {
+                            output.push(close);
+                        }
+                    }
+                    _ => {
+                        depth += 1;
+                        newline(&mut output, depth);
+                    }
+                }
+            }
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut output, depth);
+                output.push(c);
+            }
+            ',' => {
+                output.push(c);
+                newline(&mut output, depth);
+            }
+            ':' => output.push_str(": "),
+            c if c.is_whitespace() => {}
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_update_results_keeps_text() {
+        let body = r#"{"ok":true,"result":[{"update_id":1,"z":1.50,"a":"é"},{"update_id":2}]}"#;
+        let updates = split_update_results(body).unwrap();
+        assert_eq!(
+            updates,
+            vec![
+                r#"{"update_id":1,"z":1.50,"a":"é"}"#.to_string(),
+                r#"{"update_id":2}"#.to_string(),
+            ]
+        );
+        assert!(
+            split_update_results(r#"{"ok":false,"error_code":409}"#)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_indent_json_only_changes_whitespace() {
+        let raw = r#"{"b":[1,{"c":"x,{y}:\"z\""}],"a":{},"n":1.0e3}"#;
+        let indented = indent_json(raw);
+        assert_eq!(
+            indented,
+            "{\n  \"b\": [\n    1,\n    {\n      \"c\": \"x,{y}:\\\"z\\\"\"\n    }\n  ],\n  \"a\": {},\n  \"n\": 1.0e3\n}"
+        );
+        let compact: String = indented
+            .lines()
+            .map(str::trim_start)
+            .collect::<String>()
+            .replace(": ", ":");
+        assert_eq!(compact, raw);
+    }
+
+    #[test]
+    fn test_wire_log_is_bounded_and_shared() {
+        let log = WireLog::new();
+        let shared = log.clone();
+        for i in 0..MAX_WIRE_CAPTURES + 5 {
+            shared.record(WireCapture {
+                method: format!("m{i}"),
+                timestamp: 0,
+                status: 200,
+                body: Arc::from("{}"),
+            });
+        }
+        let captures = log.captures();
+        assert_eq!(captures.len(), MAX_WIRE_CAPTURES);
+        assert_eq!(captures[0].method, "m5");
+    }
+}
@@ -19,6 +19,11 @@ pub fn create_test_user(id: i64, username: Option<String>) -> User {
         first_name: "Test".to_string(),
         last_name: Some("User".to_string()),
         username,
+        can_join_groups: None,
+        can_read_all_group_messages: None,
+        supports_inline_queries: None,
+        language_code: None,
+        is_premium: None,
     }
 }
 
@@ -109,6 +114,8 @@ pub fn create_test_discovered_chat(
         last_seen: 1000,
         message_count,
         topics: Vec::new(),
+        bot_status_history: Vec::new(),
+        migrated_from_chat_id: None,
     }
 }
 
@@ -127,6 +134,8 @@ pub fn create_test_discovered_chat_with_topics(
         last_seen: 1000,
         message_count: topics.iter().map(|t| t.message_count).sum(),
         topics,
+        bot_status_history: Vec::new(),
+        migrated_from_chat_id: None,
     }
 }
 
@@ -143,6 +152,20 @@ pub fn create_test_topic(thread_id: i64, name: Option<String>, message_count: us
         name,
         message_count,
         last_seen: 1000,
+        icon_color: None,
+        icon_custom_emoji_id: None,
+        is_closed: false,
+    }
+}
+
+/// Creates a test Update carrying `message`, with the message ID as update ID.
+pub fn create_test_update(message: Message) -> Update {
+    Update {
+        update_id: message.message_id,
+        message: Some(message),
+        channel_post: None,
+        edited_message: None,
+        other: std::collections::HashMap::new(),
     }
 }
 
@@ -0,0 +1,58 @@
+//! Styled rendering of message text with its entities.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::telegram::content::{EntityKind, MessageEntity, split_by_entities};
+
+/// Renders a text as lines, styling every part by the entities covering it.
+///
+/// Nested entities combine (a bold link is bold and blue). Entities whose
+/// range doesn't fit the text are ignored here; the entities panel of the
+/// Messages screen reports them.
+pub fn styled_lines(text: &str, entities: &[MessageEntity], base: Style) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::default()];
+    for run in split_by_entities(text, entities) {
+        let style = run.entities.iter().fold(base, |style, index| {
+            style.patch(entity_style(&entities[*index].kind))
+        });
+        for (i, part) in run.text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(Line::default());
+            }
+            if !part.is_empty()
+                && let Some(line) = lines.last_mut()
+            {
+                line.spans.push(Span::styled(part.to_string(), style));
+            }
+        }
+    }
+    lines
+}
+
+/// Returns the style an entity adds to the text it covers.
+pub fn entity_style(kind: &EntityKind) -> Style {
+    let style = Style::default();
+    match kind {
+        EntityKind::Bold => style.add_modifier(Modifier::BOLD),
+        EntityKind::Italic => style.add_modifier(Modifier::ITALIC),
+        EntityKind::Underline => style.add_modifier(Modifier::UNDERLINED),
+        EntityKind::Strikethrough => style.add_modifier(Modifier::CROSSED_OUT),
+        // Spoilers stay readable in a debugger, just marked
+        EntityKind::Spoiler => style.bg(Color::DarkGray),
+        EntityKind::Code | EntityKind::Pre => style.fg(Color::Yellow),
+        EntityKind::Url | EntityKind::TextLink | EntityKind::Email | EntityKind::PhoneNumber => {
+            style.fg(Color::Blue).add_modifier(Modifier::UNDERLINED)
+        }
+        EntityKind::Mention | EntityKind::TextMention => style.fg(Color::Cyan),
+        EntityKind::Hashtag | EntityKind::Cashtag => style.fg(Color::Magenta),
+        EntityKind::BotCommand => style.fg(Color::Green),
+        EntityKind::CustomEmoji => style.fg(Color::LightYellow),
+        EntityKind::Blockquote | EntityKind::ExpandableBlockquote => {
+            style.fg(Color::Gray).add_modifier(Modifier::ITALIC)
+        }
+        EntityKind::Other(_) => style,
+    }
+}
@@ -6,7 +6,7 @@ use ratatui::{
     widgets::{Block, Paragraph},
 };
 
-use crate::app::{App, Screen};
+use crate::app::{App, ChatTab, Screen};
 
 pub fn render_frame(frame: &mut Frame, app: &App) {
     let [title_area, content_area, status_area] = Layout::vertical([
@@ -24,7 +24,14 @@ pub fn render_frame(frame: &mut Frame, app: &App) {
         Screen::TokenInput => super::screens::token_input::render(frame, content_area, app),
         Screen::Home => super::screens::home::render(frame, content_area, app),
         Screen::Discovery => super::screens::discovery::render(frame, content_area, app),
-        Screen::Messages => super::screens::messages::render(frame, content_area, app),
+        Screen::Messages => {
+            let area = super::screens::chat_details::render_tabs(frame, content_area, app);
+            match app.ui.chat_tab {
+                ChatTab::Messages => super::screens::messages::render(frame, area, app),
+                ChatTab::Details => super::screens::chat_details::render(frame, area, app),
+                ChatTab::Topics => super::screens::topics::render(frame, area, app),
+            }
+        }
         Screen::TestMessage => super::screens::test_message::render(frame, content_area, app),
         Screen::Monitor => super::screens::monitor::render(frame, content_area, app),
         Screen::Analytics => super::screens::analytics::render(frame, content_area, app),
@@ -32,6 +39,11 @@ pub fn render_frame(frame: &mut Frame, app: &App) {
         Screen::WebhookManagement => {
             super::screens::webhook_management::render(frame, content_area, app)
         }
+        Screen::MediaGroup => super::screens::media_group::render(frame, content_area, app),
+        Screen::Commands => super::screens::commands::render(frame, content_area, app),
+        Screen::BotProfile => super::screens::bot_profile::render(frame, content_area, app),
+        Screen::Users => super::screens::users::render(frame, content_area, app),
+        Screen::SchemaDrift => super::screens::schema_drift::render(frame, content_area, app),
         Screen::Help => render_help_screen(frame, content_area),
     }
 
@@ -44,12 +56,21 @@ fn render_title_bar(frame: &mut Frame, area: Rect, app: &App) {
         Screen::TokenInput => "Telegram Bot Debugger - Token Setup",
         Screen::Home => "Telegram Bot Debugger - Dashboard",
         Screen::Discovery => "Telegram Bot Debugger - Discovery (Chats & Topics)",
-        Screen::Messages => "Telegram Bot Debugger - Messages",
+        Screen::Messages => match app.ui.chat_tab {
+            ChatTab::Messages => "Telegram Bot Debugger - Messages",
+            ChatTab::Details => "Telegram Bot Debugger - Chat Details",
+            ChatTab::Topics => "Telegram Bot Debugger - Topics",
+        },
         Screen::TestMessage => "Telegram Bot Debugger - Send Test Message",
         Screen::Monitor => "Telegram Bot Debugger - Live Monitor",
         Screen::Analytics => "Telegram Bot Debugger - Analytics",
         Screen::RawJson => "Telegram Bot Debugger - Raw JSON Debug",
         Screen::WebhookManagement => "Telegram Bot Debugger - Webhook Management",
+        Screen::MediaGroup => "Telegram Bot Debugger - Album Composer",
+        Screen::Commands => "Telegram Bot Debugger - Bot Commands",
+        Screen::BotProfile => "Telegram Bot Debugger - Bot Profile",
+        Screen::Users => "Telegram Bot Debugger - Users",
+        Screen::SchemaDrift => "Telegram Bot Debugger - Schema Drift",
         Screen::Help => "Telegram Bot Debugger - Help",
     };
 
@@ -76,7 +97,9 @@ fn render_status_bar(frame: &mut Frame, area: Rect, app: &App) {
     status_text.push(":Help ".into());
     status_text.push("m".yellow().bold());
     status_text.push(":Message ".into());
-    status_text.push("1-5".yellow().bold());
+    status_text.push("g".yellow().bold());
+    status_text.push(":Album ".into());
+    status_text.push("1-9".yellow().bold());
     status_text.push(":Screens ".into());
     status_text.push("F5".yellow().bold());
     status_text.push(":Monitor".into());
@@ -126,6 +149,8 @@ fn render_help_screen(frame: &mut Frame, area: Rect) {
         Line::from(" 3 - Analytics (statistics)"),
         Line::from(" 4 - Raw JSON Debug (API responses)"),
         Line::from(" 5 - Webhook Management (configure webhooks)"),
+        Line::from(" 6 - Bot Commands (scopes × languages, diff, import/export)"),
+        Line::from(" 7 - Bot Profile (name, descriptions, menu button, admin rights)"),
         Line::from(""),
         Line::from(vec![
             Span::styled(
@@ -136,6 +161,14 @@ fn render_help_screen(frame: &mut Frame, area: Rect) {
             ),
             Span::raw(" - Toggle monitoring (works in background)"),
         ]),
+        Line::from(vec![
+            Span::styled("d", Style::default().fg(Color::Yellow)),
+            Span::raw(" - On the dashboard: run privacy-mode and permission diagnostics"),
+        ]),
+        Line::from(vec![
+            Span::styled("p", Style::default().fg(Color::Yellow)),
+            Span::raw(" - On the dashboard: load getMe and the bot profile"),
+        ]),
         Line::from(""),
         Line::from(Span::styled(
             "Webhook Management Screen:",
@@ -157,6 +190,54 @@ fn render_help_screen(frame: &mut Frame, area: Rect) {
             Span::raw(" - Set webhook from entered URL"),
         ]),
         Line::from(""),
+        Line::from(Span::styled(
+            "Analytics Screen:",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Tab", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Switch between Overview, Update Types, Commands and Engagement"),
+        ]),
+        Line::from(vec![
+            Span::styled("w", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Throughput and trend window: last 15m, 1h, 24h or 7d"),
+        ]),
+        Line::from(vec![
+            Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Commands tab: select a command"),
+        ]),
+        Line::from(vec![
+            Span::styled("r", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Commands tab: load registered commands (getMyCommands)"),
+        ]),
+        Line::from(vec![
+            Span::styled("e", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Engagement tab: export users and cohorts as CSV"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Raw JSON Screen:",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("v", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Toggle between the JSON as received and re-serialized"),
+        ]),
+        Line::from(vec![
+            Span::styled("a", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Switch between updates and the responses of all API calls"),
+        ]),
+        Line::from(vec![
+            Span::styled("e", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Export the selected JSON exactly as received"),
+        ]),
+        Line::from(""),
         Line::from(Span::styled(
             "Discovery Screen:",
             Style::default()
@@ -188,6 +269,16 @@ fn render_help_screen(frame: &mut Frame, area: Rect) {
             Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
             Span::raw(" - Navigate messages"),
         ]),
+        Line::from(vec![
+            Span::styled("Tab", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Switch to chat details (getChat, admins, bot rights; r to refresh)"),
+        ]),
+        Line::from(vec![
+            Span::styled("Tab Tab", Style::default().fg(Color::Yellow)),
+            Span::raw(
+                " - Topics of a supergroup: n new, e edit, s send, c close, o reopen, d delete",
+            ),
+        ]),
         Line::from(vec![
             Span::styled("m", Style::default().fg(Color::Yellow)),
             Span::raw(" - Send test message to this chat"),
@@ -196,6 +287,34 @@ fn render_help_screen(frame: &mut Frame, area: Rect) {
             Span::styled("e", Style::default().fg(Color::Yellow)),
             Span::raw(" - Export message as JSON"),
         ]),
+        Line::from(vec![
+            Span::styled("f", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Inspect and download files (getFile, max 20 MB)"),
+        ]),
+        Line::from(vec![
+            Span::styled("t / k", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Edit text / inline keyboard of the selected message"),
+        ]),
+        Line::from(vec![
+            Span::styled("w / c", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Forward / copy the selected message"),
+        ]),
+        Line::from(vec![
+            Span::styled("d", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Delete the selected message or album (confirm with y)"),
+        ]),
+        Line::from(vec![
+            Span::styled("v", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Edit history with word and entity diffs"),
+        ]),
+        Line::from(vec![
+            Span::styled("r", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Reply thread of the selected message"),
+        ]),
+        Line::from(vec![
+            Span::styled("n", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Entities with their UTF-16 and UTF-8 ranges"),
+        ]),
         Line::from(""),
         Line::from(Span::styled(
             "Test Message:",
@@ -208,11 +327,103 @@ fn render_help_screen(frame: &mut Frame, area: Rect) {
             Span::styled("Tab", Style::default().fg(Color::Yellow)),
             Span::raw(" - Switch between Selected Chat / Manual Chat ID"),
         ]),
+        Line::from(vec![
+            Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Select the forum topic of the selected chat"),
+        ]),
         Line::from(vec![
             Span::styled("Enter", Style::default().fg(Color::Yellow)),
             Span::raw(" - Send message"),
         ]),
         Line::from(""),
+        Line::from(Span::styled(
+            "Album Composer (g):",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Add item / send album when input is empty"),
+        ]),
+        Line::from(vec![
+            Span::styled("Tab", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Cycle media type"),
+        ]),
+        Line::from(vec![
+            Span::styled("Del", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Remove last item"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Bot Commands (6):",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("r", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Load all lists (getMyCommands per scope and language)"),
+        ]),
+        Line::from(vec![
+            Span::styled("Enter / l", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Edit the selected cell as a draft / add a language"),
+        ]),
+        Line::from(vec![
+            Span::styled("a / u", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Apply drafts after reviewing the diff (confirm with y) / discard drafts"),
+        ]),
+        Line::from(vec![
+            Span::styled("x / i", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Export all lists to JSON / import a JSON file as drafts"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Bot Profile (7):",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("r", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Load name, descriptions, menu button and admin rights"),
+        ]),
+        Line::from(vec![
+            Span::styled("Enter / l", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Edit the selected value / add a language"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Users (8):",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Search by ID, name or username (Enter keeps, Esc clears)"),
+        ]),
+        Line::from(vec![
+            Span::styled("e", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Export the listed users as JSON"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Schema Drift (9):",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("e", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Export the unknown fields with their sample payloads as JSON"),
+        ]),
+        Line::from(""),
     ];
 
     let paragraph = Paragraph::new(help_text).block(Block::bordered().title("Help"));
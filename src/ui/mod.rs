@@ -4,8 +4,9 @@
 //! It includes:
 //! - Main layout and frame rendering
 //! - Individual screens for each application mode
-//! - UI components and widgets
+//! - UI components and widgets, such as entity-styled message text
 
+pub mod entities;
 pub mod layout;
 pub mod screens;
 
@@ -3,10 +3,13 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, List, ListItem, Paragraph},
+    widgets::{Bar, BarChart, BarGroup, Block, List, ListItem, Paragraph, Sparkline, Tabs},
 };
 
-use crate::app::App;
+use crate::analytics::{ActivityHeatmap, RETENTION_DAYS, Statistics, Throughput, ThroughputWindow};
+use crate::app::{AnalyticsTab, App};
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let Some(stats) = &app.ui.statistics else {
@@ -16,14 +19,52 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         return;
     };
 
+    let [tabs_area, content_area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+    let selected = match app.ui.analytics_tab {
+        AnalyticsTab::Overview => 0,
+        AnalyticsTab::UpdateTypes => 1,
+        AnalyticsTab::Commands => 2,
+        AnalyticsTab::Engagement => 3,
+    };
+    let tabs = Tabs::new([" Overview ", " Update Types ", " Commands ", " Engagement "])
+        .select(selected)
+        .style(Style::default().fg(Color::DarkGray))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+        )
+        .divider("Tab ⇄");
+    frame.render_widget(tabs, tabs_area);
+
+    match app.ui.analytics_tab {
+        AnalyticsTab::Overview => render_overview(frame, content_area, stats),
+        AnalyticsTab::UpdateTypes => render_update_types(frame, content_area, stats),
+        AnalyticsTab::Commands => {
+            render_commands(frame, content_area, stats, app.ui.selected_command_index)
+        }
+        AnalyticsTab::Engagement => render_engagement(frame, content_area, stats),
+    }
+}
+
+fn render_overview(frame: &mut Frame, area: Rect, stats: &Statistics) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(8), Constraint::Min(0)])
+        .constraints([
+            Constraint::Length(8),
+            Constraint::Length(7),
+            Constraint::Min(0),
+            Constraint::Length(12),
+        ])
         .split(area);
+    let top_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .split(chunks[0]);
 
     // Overview
     let overview_text = vec![
-        Line::from(""),
         Line::from(Span::styled(
             "📊 Statistics Overview",
             Style::default()
@@ -34,22 +75,24 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         Line::from(format!("Total Chats: {}", stats.total_chats)),
         Line::from(format!("Total Messages: {}", stats.total_messages)),
         Line::from(format!("Total Topics: {}", stats.total_topics)),
-        Line::from(""),
+        Line::from(format!("Total Updates: {}", stats.total_updates)),
     ];
 
     let overview = Paragraph::new(overview_text).block(Block::bordered().title("Analytics"));
 
-    frame.render_widget(overview, chunks[0]);
+    frame.render_widget(overview, top_chunks[0]);
+
+    render_throughput(frame, top_chunks[1], chunks[1], &stats.throughput);
 
     // Split bottom section into 3 columns
     let bottom_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
             Constraint::Percentage(40),
-            Constraint::Percentage(30),
-            Constraint::Percentage(30),
+            Constraint::Percentage(25),
+            Constraint::Percentage(35),
         ])
-        .split(chunks[1]);
+        .split(chunks[2]);
 
     // Top chats
     let mut top_chats_items = vec![ListItem::new(Line::from(Span::styled(
@@ -98,33 +141,596 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
 
     frame.render_widget(type_list, bottom_chunks[1]);
 
-    // Hourly distribution
-    let mut hourly_items = vec![ListItem::new(Line::from(Span::styled(
-        "Activity by Hour:",
+    // Day-of-week distribution
+    let mut daily_items = vec![ListItem::new(Line::from(Span::styled(
+        "Activity by Day:",
         Style::default()
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD),
     )))];
 
-    // Show only hours with activity
-    let active_hours: Vec<_> = stats
-        .hourly_distribution
+    let daily = stats.activity.daily();
+    let busiest_day = daily.iter().copied().max().unwrap_or(0);
+    if busiest_day == 0 {
+        daily_items.push(ListItem::new("No data yet"));
+    } else {
+        for (day, count) in WEEKDAYS.iter().zip(daily) {
+            let bar = "█".repeat((count * 15).div_ceil(busiest_day));
+            daily_items.push(ListItem::new(Line::from(vec![
+                Span::raw(format!("{day} ")),
+                Span::styled(format!("{bar:<15}"), Style::default().fg(Color::Green)),
+                Span::raw(format!(" {count}")),
+            ])));
+        }
+    }
+
+    let daily_list = List::new(daily_items).block(Block::bordered().title("Daily"));
+
+    frame.render_widget(daily_list, bottom_chunks[2]);
+
+    // Day-of-week × hour-of-day heatmap
+    let title = match stats.activity_since.and_then(|since| {
+        chrono::DateTime::from_timestamp(since, 0).map(|dt| dt.format("%Y-%m-%d").to_string())
+    }) {
+        Some(since) => format!(
+            "Activity Heatmap ({}) - {} messages since {since}",
+            stats.timezone,
+            daily.iter().sum::<usize>()
+        ),
+        None => format!("Activity Heatmap ({})", stats.timezone),
+    };
+    let heatmap = if busiest_day == 0 {
+        Paragraph::new(vec![
+            Line::from(""),
+            Line::from("No messages received yet. Press 'F5' to start monitoring for updates."),
+            Line::from("Messages are placed by the date Telegram gives them."),
+        ])
+    } else {
+        Paragraph::new(heatmap_lines(&stats.activity))
+    };
+    frame.render_widget(heatmap.block(Block::bordered().title(title)), chunks[3]);
+}
+
+/// Renders the update rate of the selected window as a sparkline, and one
+/// text sparkline per busy update type and chat below it.
+fn render_throughput(
+    frame: &mut Frame,
+    total_area: Rect,
+    series_area: Rect,
+    throughput: &Throughput,
+) {
+    let window = throughput.window;
+    let unit = match window {
+        ThroughputWindow::FifteenMinutes | ThroughputWindow::Hour => "min",
+        ThroughputWindow::Day | ThroughputWindow::Week => "h",
+    };
+    let total: u64 = throughput.total.iter().sum();
+    let peak = throughput.total.iter().copied().max().unwrap_or(0);
+    let data = resample(
+        &throughput.total,
+        total_area.width.saturating_sub(2) as usize,
+    );
+    frame.render_widget(
+        Sparkline::default()
+            .block(Block::bordered().title(format!(
+                "Throughput, last {} - {total} updates, peak {peak}/{unit} - w to change window",
+                window.label()
+            )))
+            .data(&data)
+            .style(Style::default().fg(Color::Green)),
+        total_area,
+    );
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(series_area);
+    for (area, title, series) in [
+        (columns[0], "By Update Type", &throughput.by_kind),
+        (columns[1], "By Chat", &throughput.by_chat),
+    ] {
+        // Label, sparkline and total share the width inside the borders
+        let width = area.width.saturating_sub(2 + 16 + 7) as usize;
+        let lines: Vec<Line> = if series.is_empty() {
+            vec![Line::from(Span::styled(
+                "No updates in this window",
+                Style::default().fg(Color::DarkGray),
+            ))]
+        } else {
+            series
+                .iter()
+                .map(|(label, values)| {
+                    let label: String = label.chars().take(15).collect();
+                    Line::from(vec![
+                        Span::raw(format!("{label:<16}")),
+                        Span::styled(
+                            text_sparkline(&resample(values, width)),
+                            Style::default().fg(Color::Cyan),
+                        ),
+                        Span::raw(format!(" {}", values.iter().sum::<u64>())),
+                    ])
+                })
+                .collect()
+        };
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(title)),
+            area,
+        );
+    }
+}
+
+/// Sums neighbouring buckets so a series fits `width` columns, newest last.
+fn resample(values: &[u64], width: usize) -> Vec<u64> {
+    if width == 0 || values.len() <= width {
+        return values.to_vec();
+    }
+    let group = values.len().div_ceil(width);
+    // Group from the newest end so only the oldest column can be a partial sum
+    let mut groups: Vec<u64> = values
+        .rchunks(group)
+        .map(|chunk| chunk.iter().sum())
+        .collect();
+    groups.reverse();
+    groups
+}
+
+fn text_sparkline(values: &[u64]) -> String {
+    const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = values.iter().copied().max().unwrap_or(0);
+    values
+        .iter()
+        .map(|&value| {
+            if value == 0 || max == 0 {
+                ' '
+            } else {
+                LEVELS[((value * 8).div_ceil(max) as usize).clamp(1, 8) - 1]
+            }
+        })
+        .collect()
+}
+
+/// Renders a bar per update type, a table of counts and trends, and the
+/// update types of the busiest chats.
+fn render_update_types(frame: &mut Frame, area: Rect, stats: &Statistics) {
+    let window = stats.throughput.window.label();
+    if stats.update_types.is_empty() {
+        let empty = Paragraph::new(vec![
+            Line::from(""),
+            Line::from("No updates received yet. Press 'F5' to start monitoring for updates."),
+        ])
+        .block(Block::bordered().title("Updates by Type"));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let [chart_area, tables_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(14)]).areas(area);
+
+    let bars: Vec<Bar> = stats
+        .update_types
         .iter()
-        .filter(|(_, count)| *count > 0)
-        .take(10)
+        .map(|stat| {
+            Bar::default()
+                .label(Line::from(stat.update_type.clone()))
+                .value(stat.total as u64)
+                .text_value(format!("{} ({} in {window})", stat.total, stat.current))
+                .style(Style::default().fg(Color::Cyan))
+                .value_style(
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                )
+        })
         .collect();
+    frame.render_widget(
+        BarChart::default()
+            .block(Block::bordered().title(format!(
+                "Updates by Type - {} since monitoring started",
+                stats.total_updates
+            )))
+            .direction(Direction::Horizontal)
+            .bar_width(1)
+            .bar_gap(0)
+            .data(BarGroup::default().bars(&bars)),
+        chart_area,
+    );
+
+    let [types_area, chats_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(tables_area);
+
+    let mut type_items = vec![ListItem::new(Line::from(Span::styled(
+        format!(
+            "{:<26}{:>8}{:>9}{:>9}{:>8}",
+            "Type",
+            "Total",
+            format!("Last {window}"),
+            "Before",
+            "Trend"
+        ),
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )))];
+    for stat in &stats.update_types {
+        let trend = stat.trend();
+        let trend_color = if stat.current > stat.previous {
+            Color::Green
+        } else if stat.current < stat.previous {
+            Color::Red
+        } else {
+            Color::DarkGray
+        };
+        type_items.push(ListItem::new(Line::from(vec![
+            Span::raw(format!(
+                "{:<26}{:>8}{:>9}{:>9}",
+                stat.update_type, stat.total, stat.current, stat.previous
+            )),
+            Span::styled(format!("{trend:>8}"), Style::default().fg(trend_color)),
+        ])));
+    }
+    frame.render_widget(
+        List::new(type_items).block(Block::bordered().title(format!(
+            "Trends - last {window} vs the {window} before | w to change window"
+        ))),
+        types_area,
+    );
+
+    let mut chat_items = Vec::new();
+    for chat in &stats.chat_update_types {
+        chat_items.push(ListItem::new(Line::from(vec![
+            Span::styled(
+                chat.chat_name.clone(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(" - {} updates", chat.total)),
+        ])));
+        let types: Vec<String> = chat
+            .by_type
+            .iter()
+            .map(|(update_type, count)| format!("{update_type} {count}"))
+            .collect();
+        chat_items.push(ListItem::new(Line::from(Span::styled(
+            format!("  {}", types.join(" · ")),
+            Style::default().fg(Color::DarkGray),
+        ))));
+    }
+    if chat_items.is_empty() {
+        chat_items.push(ListItem::new("No updates tied to a chat yet"));
+    }
+    frame.render_widget(
+        List::new(chat_items).block(Block::bordered().title("By Chat")),
+        chats_area,
+    );
+}
 
-    if active_hours.is_empty() {
-        hourly_items.push(ListItem::new("No data yet"));
+/// Renders a row per command with its last 24 hours and registration status,
+/// and where the selected command was used.
+fn render_commands(frame: &mut Frame, area: Rect, stats: &Statistics, selected: usize) {
+    if stats.commands.is_empty() {
+        let mut lines = vec![
+            Line::from(""),
+            Line::from("No bot commands received yet. Press 'F5' to start monitoring for updates."),
+        ];
+        if !stats.unused_commands.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!(
+                "Registered: /{}",
+                stats.unused_commands.join(" /")
+            )));
+        }
+        if let Some(other_bots) = other_bot_commands(stats) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(other_bots));
+        }
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title("Commands")),
+            area,
+        );
+        return;
+    }
+
+    let [list_area, details_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(12)]).areas(area);
+
+    let mut items = vec![ListItem::new(Line::from(Span::styled(
+        format!(
+            "  {:<24}{:>7}{:>7}{:>7}  {:<26}{}",
+            "Command", "Uses", "Users", "Chats", "Last 24h", "Status"
+        ),
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )))];
+    for (i, command) in stats.commands.iter().enumerate() {
+        let is_selected = i == selected;
+        let name_style = if is_selected {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        let (status, status_color) = match command.registered {
+            Some(true) => ("✓ registered", Color::Green),
+            Some(false) => ("⚠ not registered", Color::Red),
+            None => ("? press r to check", Color::DarkGray),
+        };
+        let name: String = format!("/{}", command.command).chars().take(23).collect();
+        items.push(ListItem::new(Line::from(vec![
+            Span::raw(if is_selected { "→ " } else { "  " }),
+            Span::styled(format!("{name:<24}"), name_style),
+            Span::raw(format!(
+                "{:>7}{:>7}{:>7}  ",
+                command.count,
+                command.unique_users,
+                command.chats.len()
+            )),
+            Span::styled(
+                format!("{:<26}", text_sparkline(&command.last_24h)),
+                Style::default().fg(Color::Green),
+            ),
+            Span::styled(status, Style::default().fg(status_color)),
+        ])));
+    }
+    if !stats.unused_commands.is_empty() {
+        items.push(ListItem::new(""));
+        items.push(ListItem::new(Line::from(Span::styled(
+            format!(
+                "Registered but unused: /{}",
+                stats.unused_commands.join(" /")
+            ),
+            Style::default().fg(Color::DarkGray),
+        ))));
+    }
+    if let Some(other_bots) = other_bot_commands(stats) {
+        if stats.unused_commands.is_empty() {
+            items.push(ListItem::new(""));
+        }
+        items.push(ListItem::new(Line::from(Span::styled(
+            other_bots,
+            Style::default().fg(Color::DarkGray),
+        ))));
+    }
+    let unregistered = stats
+        .commands
+        .iter()
+        .filter(|command| command.registered == Some(false))
+        .count();
+    let title = if stats.commands_loaded {
+        format!(
+            "Commands ({}, {unregistered} not registered) - ↑/↓ select | r reload registered",
+            stats.commands.len()
+        )
     } else {
-        for (hour, count) in active_hours {
-            hourly_items.push(ListItem::new(Line::from(format!(
-                "{hour:02}:00 - {count} msgs"
-            ))));
+        format!(
+            "Commands ({}) - ↑/↓ select | r load registered commands",
+            stats.commands.len()
+        )
+    };
+    frame.render_widget(
+        List::new(items).block(Block::bordered().title(title)),
+        list_area,
+    );
+
+    let Some(command) = stats.commands.get(selected) else {
+        return;
+    };
+    let last_used = chrono::DateTime::from_timestamp(command.last_used, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_default();
+    let [chats_area, payloads_area, bots_area] = Layout::horizontal([
+        Constraint::Percentage(40),
+        Constraint::Percentage(35),
+        Constraint::Percentage(25),
+    ])
+    .areas(details_area);
+    for (area, title, counts, empty) in [
+        (
+            chats_area,
+            format!("/{} by Chat - last used {last_used}", command.command),
+            &command.chats,
+            "",
+        ),
+        (
+            payloads_area,
+            "Deep-link Payloads".to_string(),
+            &command.payloads,
+            "No /start payloads",
+        ),
+        (
+            bots_area,
+            "Addressed To".to_string(),
+            &command.addressed_to,
+            "No /command@bot forms",
+        ),
+    ] {
+        let items: Vec<ListItem> = if counts.is_empty() {
+            vec![ListItem::new(Span::styled(
+                empty,
+                Style::default().fg(Color::DarkGray),
+            ))]
+        } else {
+            counts
+                .iter()
+                .map(|(label, count)| ListItem::new(format!("{label} - {count}")))
+                .collect()
+        };
+        frame.render_widget(List::new(items).block(Block::bordered().title(title)), area);
+    }
+}
+
+/// Lists the commands addressed to other bots, which aren't counted above.
+fn other_bot_commands(stats: &Statistics) -> Option<String> {
+    if stats.other_bot_commands.is_empty() {
+        return None;
+    }
+    let commands: Vec<String> = stats
+        .other_bot_commands
+        .iter()
+        .map(|(command, count)| format!("{command} ×{count}"))
+        .collect();
+    Some(format!(
+        "Addressed to other bots (not counted): {}",
+        commands.join(" · ")
+    ))
+}
+
+/// Number of most recent cohorts shown; the CSV export has all of them.
+const COHORTS_SHOWN: usize = 14;
+
+/// Renders the most active users and a retention table of the latest cohorts.
+fn render_engagement(frame: &mut Frame, area: Rect, stats: &Statistics) {
+    if stats.user_engagement.is_empty() {
+        let empty = Paragraph::new(vec![
+            Line::from(""),
+            Line::from("No user activity yet. Press 'F5' to start monitoring for updates."),
+        ])
+        .block(Block::bordered().title("Engagement"));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let cohorts = &stats.cohorts[stats.cohorts.len().saturating_sub(COHORTS_SHOWN)..];
+    let [users_area, cohorts_area] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length(cohorts.len() as u16 + 3),
+    ])
+    .areas(area);
+
+    let format_time = |timestamp: i64| {
+        chrono::DateTime::from_timestamp(timestamp, 0)
+            .map(|dt| dt.format("%m-%d %H:%M").to_string())
+            .unwrap_or_default()
+    };
+    let mut items = vec![ListItem::new(Line::from(Span::styled(
+        format!(
+            "{:<28}{:>7}{:>7}{:>7}{:>7}{:>6}  {:<13}{}",
+            "User", "Msgs", "Calls", "Inline", "Other", "Days", "First seen", "Last seen"
+        ),
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )))];
+    for user in &stats.user_engagement {
+        let name = match &user.username {
+            Some(username) => format!("{} @{username}", user.name),
+            None => format!("{} ({})", user.name, user.user_id),
+        };
+        let name: String = name.chars().take(27).collect();
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(format!("{name:<28}"), Style::default().fg(Color::Cyan)),
+            Span::raw(format!(
+                "{:>7}{:>7}{:>7}{:>7}{:>6}  ",
+                user.messages,
+                user.callbacks,
+                user.inline_queries,
+                user.other,
+                user.active_days.len()
+            )),
+            Span::styled(
+                format!(
+                    "{:<13}{}",
+                    format_time(user.first_seen),
+                    format_time(user.last_seen)
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ])));
+    }
+    frame.render_widget(
+        List::new(items).block(Block::bordered().title(format!(
+            "Users ({}) - most updates first | e export CSV",
+            stats.user_engagement.len()
+        ))),
+        users_area,
+    );
+
+    let mut header = format!("{:<12}{:>7}", "Cohort", "Users");
+    for offset in 0..=RETENTION_DAYS {
+        header.push_str(&format!("{:>8}", format!("Day {offset}")));
+    }
+    let mut lines = vec![Line::from(Span::styled(
+        header,
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    for cohort in cohorts {
+        let mut spans = vec![Span::raw(format!(
+            "{:<12}{:>7}",
+            cohort.date(),
+            cohort.size
+        ))];
+        for offset in 0..=RETENTION_DAYS {
+            spans.push(match cohort.retention(offset) {
+                Some(percent) => {
+                    let color = if percent >= 50.0 {
+                        Color::Green
+                    } else if percent >= 20.0 {
+                        Color::Yellow
+                    } else if percent > 0.0 {
+                        Color::Red
+                    } else {
+                        Color::DarkGray
+                    };
+                    Span::styled(format!("{:>7.0}%", percent), Style::default().fg(color))
+                }
+                None => Span::styled(format!("{:>8}", "·"), Style::default().fg(Color::DarkGray)),
+            });
         }
+        lines.push(Line::from(spans));
     }
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::bordered()
+                .title("Retention - users first seen on a UTC day still active N days later"),
+        ),
+        cohorts_area,
+    );
+}
 
-    let hourly_list = List::new(hourly_items).block(Block::bordered().title("Hourly"));
+/// Renders one row per day and a row of hourly totals, each cell shaded by
+/// its count relative to the busiest cell of the row group.
+fn heatmap_lines(activity: &ActivityHeatmap) -> Vec<Line<'static>> {
+    let mut header = String::from("     ");
+    for hour in (0..24).step_by(3) {
+        header.push_str(&format!("{hour:<6}"));
+    }
+    let mut lines = vec![Line::from(Span::styled(
+        header,
+        Style::default().fg(Color::DarkGray),
+    ))];
+
+    let max = activity.max();
+    for (day, counts) in WEEKDAYS.iter().zip(&activity.cells) {
+        let mut spans = vec![Span::raw(format!("{day}  "))];
+        spans.extend(counts.iter().map(|count| heat_cell(*count, max)));
+        spans.push(Span::raw(format!(" {}", counts.iter().sum::<usize>())));
+        lines.push(Line::from(spans));
+    }
 
-    frame.render_widget(hourly_list, bottom_chunks[2]);
+    let hourly = activity.hourly();
+    let busiest_hour = hourly.iter().copied().max().unwrap_or(0);
+    let mut spans = vec![Span::styled("All  ", Style::default().fg(Color::Yellow))];
+    spans.extend(hourly.iter().map(|count| heat_cell(*count, busiest_hour)));
+    lines.push(Line::from(""));
+    lines.push(Line::from(spans));
+    lines
+}
+
+fn heat_cell(count: usize, max: usize) -> Span<'static> {
+    if count == 0 || max == 0 {
+        return Span::styled("··", Style::default().fg(Color::DarkGray));
+    }
+    let (shade, color) = match (count * 4).div_ceil(max) {
+        1 => ("░░", Color::Green),
+        2 => ("▒▒", Color::Green),
+        3 => ("▓▓", Color::Yellow),
+        _ => ("██", Color::Red),
+    };
+    Span::styled(shade, Style::default().fg(color))
 }
@@ -0,0 +1,187 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::{App, ProfileInput};
+use crate::telegram::User;
+use crate::telegram::profile::ProfileRow;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    // Prompt of a pending edit
+    let area = if let Some(mode) = app.ui.profile_input_mode {
+        let [content_area, prompt_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(area);
+        let title = match (mode, app.selected_profile_row()) {
+            (ProfileInput::Edit, Some(row)) => row.edit_hint(),
+            _ => "Two-letter language code, e.g. de (Enter/Esc)".to_string(),
+        };
+        let prompt = Paragraph::new(app.ui.profile_input.as_str()).block(
+            Block::bordered()
+                .title(title)
+                .border_style(Style::default().fg(Color::Green)),
+        );
+        frame.render_widget(prompt, prompt_area);
+        content_area
+    } else {
+        area
+    };
+
+    let [list_area, detail_area] =
+        Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)]).areas(area);
+
+    let profile = &app.ui.bot_profile;
+    let loaded = profile.loaded_at.is_some();
+
+    // All values, one row per field and language
+    let items: Vec<ListItem> = profile
+        .rows()
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let selected = i == app.ui.profile_selected_row;
+            let value = if loaded {
+                profile.value(row)
+            } else {
+                "?".to_string()
+            };
+            let value = if profile.error(row).is_some() {
+                Span::styled("✗ error", Style::default().fg(Color::Red))
+            } else if value.is_empty() {
+                Span::styled("(not set)", Style::default().fg(Color::DarkGray))
+            } else {
+                Span::raw(truncate(&value, 48))
+            };
+            let label_style = if selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Cyan)
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(if selected { "→ " } else { "  " }),
+                Span::styled(format!("{:<28}", row.label()), label_style),
+                value,
+            ]))
+        })
+        .collect();
+
+    let title = if loaded {
+        "Bot Profile (Enter edit, l add language, r reload)"
+    } else {
+        "Bot Profile (press r to load)"
+    };
+    frame.render_widget(
+        List::new(items).block(Block::bordered().title(title)),
+        list_area,
+    );
+
+    // Selected value in full, what users see, and getMe
+    let mut details = vec![];
+    if let Some(row) = app.selected_profile_row() {
+        details.push(Line::from(Span::styled(
+            row.label(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )));
+        details.push(Line::from(""));
+        if let Some(error) = profile.error(&row) {
+            details.push(Line::from(Span::styled(
+                format!("failed to load: {error}"),
+                Style::default().fg(Color::Red),
+            )));
+        } else {
+            let value = profile.value(&row);
+            details.push(Line::from(if value.is_empty() {
+                "(not set)".to_string()
+            } else {
+                value
+            }));
+        }
+
+        if let ProfileRow::Text {
+            field,
+            language_code,
+        } = &row
+            && !language_code.is_empty()
+        {
+            details.push(Line::from(""));
+            details.push(Line::from(Span::styled(
+                format!("Users with language {language_code} see:"),
+                Style::default().fg(Color::Green),
+            )));
+            details.push(Line::from(
+                profile
+                    .effective_text(*field, language_code)
+                    .unwrap_or("(nothing)")
+                    .to_string(),
+            ));
+        }
+    }
+
+    if let Some(bot) = &profile.bot {
+        details.push(Line::from(""));
+        details.push(Line::from(Span::styled(
+            "getMe:",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )));
+        details.extend(get_me_lines(bot));
+    }
+
+    if let Some(result) = &app.ui.profile_result {
+        let color = if result.starts_with('✓') {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        details.push(Line::from(""));
+        details.push(Line::from(Span::styled(
+            result.as_str(),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    let paragraph = Paragraph::new(details)
+        .block(Block::bordered().title("Details"))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, detail_area);
+}
+
+/// Summarizes the `getMe` result, shared with the dashboard.
+pub fn get_me_lines(bot: &User) -> Vec<Line<'static>> {
+    let flag = |value: Option<bool>| match value {
+        Some(true) => "yes",
+        Some(false) => "no",
+        None => "?",
+    };
+    vec![
+        Line::from(format!(
+            "  @{} (ID {})",
+            bot.username.as_deref().unwrap_or("?"),
+            bot.id
+        )),
+        Line::from(format!(
+            "  Joins groups: {}  Reads all group messages: {}  Inline: {}",
+            flag(bot.can_join_groups),
+            flag(bot.can_read_all_group_messages),
+            flag(bot.supports_inline_queries)
+        )),
+    ]
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    let single_line = text.replace('\n', " ");
+    if single_line.chars().count() > max_chars {
+        let truncated: String = single_line.chars().take(max_chars).collect();
+        format!("{truncated}…")
+    } else {
+        single_line
+    }
+}
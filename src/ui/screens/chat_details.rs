@@ -0,0 +1,262 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Tabs, Wrap},
+};
+
+use crate::app::{App, ChatTab};
+use crate::telegram::ChatMember;
+
+/// Renders the tab bar of the chat view and returns the area below it.
+pub fn render_tabs(frame: &mut Frame, area: Rect, app: &App) -> Rect {
+    let [tabs_area, content_area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+
+    let selected = match app.ui.chat_tab {
+        ChatTab::Messages => 0,
+        ChatTab::Details => 1,
+        ChatTab::Topics => 2,
+    };
+    let mut titles = vec![" Messages ", " Details "];
+    if app
+        .get_selected_chat()
+        .is_some_and(|c| c.chat.chat_type == "supergroup")
+    {
+        titles.push(" Topics ");
+    }
+    let tabs = Tabs::new(titles)
+        .select(selected)
+        .style(Style::default().fg(Color::DarkGray))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+        )
+        .divider("Tab ⇄");
+
+    frame.render_widget(tabs, tabs_area);
+    content_area
+}
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(selected_chat) = app.get_selected_chat() else {
+        let empty_message = Paragraph::new(vec![
+            Line::from(""),
+            Line::from("No chat selected."),
+            Line::from(""),
+            Line::from("Press Esc to go back to Discovery."),
+        ])
+        .block(Block::bordered().title("Chat Details"));
+        frame.render_widget(empty_message, area);
+        return;
+    };
+
+    let inspection = app
+        .ui
+        .chat_inspection
+        .as_ref()
+        .filter(|inspection| inspection.chat_id == selected_chat.chat.id);
+
+    let Some(inspection) = inspection else {
+        let loading = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(format!(
+                "No details loaded for {}.",
+                selected_chat.chat.display_name()
+            )),
+            Line::from(""),
+            Line::from("Press 'r' to query getChat, getChatAdministrators,"),
+            Line::from("getChatMemberCount and getChatMember for the bot."),
+        ])
+        .block(Block::bordered().title("Chat Details"));
+        frame.render_widget(loading, area);
+        return;
+    };
+
+    let [info_area, rights_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(area);
+
+    // Chat information (getChat + getChatMemberCount)
+    let mut info = vec![Line::from("")];
+    match &inspection.chat {
+        Ok(chat) => {
+            info.push(Line::from(format!("Chat ID: {}", chat.id)));
+            info.push(Line::from(format!("Type: {}", chat.chat_type)));
+            if let Some(title) = &chat.title {
+                info.push(Line::from(format!("Title: {title}")));
+            }
+            if let Some(username) = &chat.username {
+                info.push(Line::from(format!("Username: @{username}")));
+            }
+            match &inspection.member_count {
+                Ok(count) => info.push(Line::from(format!("Members: {count}"))),
+                Err(e) => info.push(error_line("Members", e)),
+            }
+            info.push(Line::from(format!(
+                "Forum: {}",
+                if chat.is_forum { "yes" } else { "no" }
+            )));
+            info.push(Line::from(format!(
+                "Slow mode: {}",
+                chat.slow_mode_delay
+                    .filter(|d| *d > 0)
+                    .map(|d| format!("{d}s"))
+                    .unwrap_or_else(|| "off".to_string())
+            )));
+            if let Some(linked) = chat.linked_chat_id {
+                info.push(Line::from(format!("Linked chat: {linked}")));
+            }
+            if chat.has_protected_content {
+                info.push(Line::from("Protected content: yes"));
+            }
+            if let Some(description) = &chat.description {
+                info.push(Line::from(""));
+                info.push(section("Description:", Color::Green));
+                info.push(Line::from(format!("  {description}")));
+            }
+            if let Some(pinned) = &chat.pinned_message {
+                info.push(Line::from(""));
+                info.push(section("Pinned message:", Color::Green));
+                info.push(Line::from(format!(
+                    "  #{} {}",
+                    pinned.message_id,
+                    pinned.text.as_deref().unwrap_or("[no text]")
+                )));
+            }
+            if let Some(permissions) = &chat.permissions {
+                info.push(Line::from(""));
+                info.push(section("Member permissions:", Color::Yellow));
+                let typed = [
+                    ("can_send_messages", permissions.can_send_messages),
+                    ("can_send_polls", permissions.can_send_polls),
+                    (
+                        "can_send_other_messages",
+                        permissions.can_send_other_messages,
+                    ),
+                    (
+                        "can_add_web_page_previews",
+                        permissions.can_add_web_page_previews,
+                    ),
+                    ("can_change_info", permissions.can_change_info),
+                    ("can_invite_users", permissions.can_invite_users),
+                    ("can_pin_messages", permissions.can_pin_messages),
+                    ("can_manage_topics", permissions.can_manage_topics),
+                ];
+                let mut extra: Vec<(&str, Option<bool>)> = permissions
+                    .other
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_bool()))
+                    .collect();
+                extra.sort();
+                for (name, value) in typed.into_iter().chain(extra) {
+                    if let Some(granted) = value {
+                        info.push(right_line(name, granted));
+                    }
+                }
+            }
+        }
+        Err(e) => info.push(error_line("getChat", e)),
+    }
+
+    let info_paragraph = Paragraph::new(info)
+        .block(Block::bordered().title(format!(
+            "Chat Details - {} (r to refresh | Tab for messages)",
+            selected_chat.chat.display_name()
+        )))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(info_paragraph, info_area);
+
+    // Bot membership, rights and administrators
+    let mut rights = vec![Line::from("")];
+    let notes = inspection.notes();
+    if !notes.is_empty() {
+        rights.push(section("Diagnosis:", Color::Magenta));
+        for note in notes {
+            let color = if note.starts_with('✓') {
+                Color::Green
+            } else if note.starts_with('⚠') {
+                Color::Yellow
+            } else {
+                Color::Red
+            };
+            rights.push(Line::from(Span::styled(
+                format!("  {note}"),
+                Style::default().fg(color),
+            )));
+        }
+        rights.push(Line::from(""));
+    }
+
+    match &inspection.bot_member {
+        Ok(bot) => {
+            rights.push(section(&format!("Bot status: {}", bot.status), Color::Cyan));
+            for (name, granted) in bot.rights() {
+                rights.push(right_line(name, granted));
+            }
+        }
+        Err(e) => rights.push(error_line("getChatMember", e)),
+    }
+
+    rights.push(Line::from(""));
+    match &inspection.administrators {
+        Ok(admins) => {
+            rights.push(section(
+                &format!("Administrators ({}):", admins.len()),
+                Color::Cyan,
+            ));
+            for admin in admins {
+                rights.push(Line::from(admin_summary(admin)));
+            }
+        }
+        Err(e) => rights.push(error_line("Administrators", e)),
+    }
+
+    let rights_paragraph = Paragraph::new(rights)
+        .block(Block::bordered().title("Bot Rights & Administrators"))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(rights_paragraph, rights_area);
+}
+
+fn section(title: &str, color: Color) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn right_line(name: &str, granted: bool) -> Line<'static> {
+    let (mark, color) = if granted {
+        ("✓", Color::Green)
+    } else {
+        ("✗", Color::Red)
+    };
+    Line::from(vec![
+        Span::styled(format!("  {mark} "), Style::default().fg(color)),
+        Span::raw(name.to_string()),
+    ])
+}
+
+fn error_line(label: &str, error: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("{label}: ✗ {error}"),
+        Style::default().fg(Color::Red),
+    ))
+}
+
+fn admin_summary(admin: &ChatMember) -> String {
+    let name = admin
+        .user
+        .username
+        .as_ref()
+        .map(|u| format!("@{u}"))
+        .unwrap_or_else(|| admin.user.first_name.clone());
+    let bot = if admin.user.is_bot { " [bot]" } else { "" };
+    let title = admin
+        .custom_title
+        .as_ref()
+        .map(|t| format!(" \"{t}\""))
+        .unwrap_or_default();
+    format!("  • {name}{bot} ({}){title}", admin.status)
+}
@@ -0,0 +1,249 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Cell, Paragraph, Row, Table, Wrap},
+};
+
+use crate::app::{App, CommandsInput, display_language};
+use crate::telegram::BotCommand;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    // Prompt of a pending edit/import/apply input
+    let area = if let Some(mode) = app.ui.commands_input_mode {
+        let [content_area, prompt_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(area);
+        let (content, color) = if mode == CommandsInput::ConfirmApply {
+            (
+                format!(
+                    "{} command list(s) will be replaced in Telegram",
+                    app.ui.commands.pending_changes().len()
+                ),
+                Color::Red,
+            )
+        } else {
+            (app.ui.commands_input.clone(), Color::Green)
+        };
+        let prompt = Paragraph::new(content).block(
+            Block::bordered()
+                .title(mode.prompt())
+                .border_style(Style::default().fg(color)),
+        );
+        frame.render_widget(prompt, prompt_area);
+        content_area
+    } else {
+        area
+    };
+
+    let matrix = &app.ui.commands;
+    let matrix_height = matrix.scopes.len() as u16 + 4;
+    let [matrix_area, lower_area] =
+        Layout::vertical([Constraint::Length(matrix_height), Constraint::Min(0)]).areas(area);
+    let [cell_area, diff_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(lower_area);
+
+    // Scope × language matrix with the effective source of empty cells
+    let header = Row::new(
+        std::iter::once(Cell::from("Scope")).chain(
+            matrix
+                .languages
+                .iter()
+                .map(|language| Cell::from(display_language(language).to_string())),
+        ),
+    )
+    .style(
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let rows = matrix.scopes.iter().enumerate().map(|(row, scope)| {
+        let cells = matrix
+            .languages
+            .iter()
+            .enumerate()
+            .map(|(column, language)| {
+                let selected = row == app.ui.commands_selected_scope
+                    && column == app.ui.commands_selected_language;
+                let draft = matrix.draft(scope, language);
+                let error = matrix.error(scope, language);
+                let text = match (matrix.cell(scope, language, true), draft) {
+                    (None, _) if error.is_some() => "✗ error".to_string(),
+                    (None, _) => "?".to_string(),
+                    (Some(commands), _) if !commands.is_empty() => {
+                        format!("{} cmds", commands.len())
+                    }
+                    (Some(_), _) => match matrix.effective(scope, language, true) {
+                        Some(effective) => format!(
+                            "→ {} [{}]",
+                            effective.scope.label(),
+                            display_language(&effective.language_code)
+                        ),
+                        None => "none".to_string(),
+                    },
+                };
+                let text = if draft.is_some() {
+                    format!("*{text}")
+                } else {
+                    text
+                };
+                let mut style = Style::default();
+                if draft.is_some() {
+                    style = style.fg(Color::Yellow);
+                } else if error.is_some() {
+                    style = style.fg(Color::Red);
+                }
+                if selected {
+                    style = style.add_modifier(Modifier::REVERSED);
+                }
+                Cell::from(text).style(style)
+            });
+        Row::new(std::iter::once(Cell::from(scope.label())).chain(cells))
+    });
+
+    let mut widths = vec![Constraint::Length(30)];
+    widths.extend(matrix.languages.iter().map(|_| Constraint::Min(14)));
+    let table = Table::new(rows, widths).header(header).block(
+        Block::bordered().title("Commands per scope × language (* = draft, → = falls back to)"),
+    );
+    frame.render_widget(table, matrix_area);
+
+    // Selected cell: stored list, draft and what users actually see
+    let mut details = vec![];
+    if let Some((scope, language)) = app.selected_commands_cell() {
+        details.push(section(&format!(
+            "{} [{}]",
+            scope.label(),
+            display_language(&language)
+        )));
+        details.push(Line::from(""));
+        details.push(Line::from(Span::styled(
+            "Stored in Telegram:",
+            Style::default().fg(Color::Green),
+        )));
+        match (
+            matrix.loaded(&scope, &language),
+            matrix.error(&scope, &language),
+        ) {
+            (None, Some(error)) => details.push(Line::from(Span::styled(
+                format!("  failed to load: {error}"),
+                Style::default().fg(Color::Red),
+            ))),
+            (None, None) => details.push(Line::from("  not loaded - press r")),
+            (Some(commands), _) => push_commands(&mut details, commands),
+        }
+        if let Some(draft) = matrix.draft(&scope, &language) {
+            details.push(Line::from(""));
+            details.push(Line::from(Span::styled(
+                "Draft:",
+                Style::default().fg(Color::Yellow),
+            )));
+            push_commands(&mut details, draft);
+        }
+        details.push(Line::from(""));
+        match matrix.effective(&scope, &language, true) {
+            Some(effective) => {
+                details.push(Line::from(Span::styled(
+                    format!(
+                        "Effective (from {} [{}]):",
+                        effective.scope.label(),
+                        display_language(&effective.language_code)
+                    ),
+                    Style::default().fg(Color::Cyan),
+                )));
+                push_commands(&mut details, effective.commands);
+            }
+            None => details.push(Line::from(Span::styled(
+                "Effective: no commands - the menu button is hidden",
+                Style::default().fg(Color::DarkGray),
+            ))),
+        }
+    }
+    details.push(Line::from(""));
+    details.push(Line::from(Span::styled(
+        "Keys: ←↑↓→ select  r load  Enter edit  l language  a apply  u discard  x export  i import",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let details_paragraph = Paragraph::new(details)
+        .block(Block::bordered().title("Selected Cell"))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(details_paragraph, cell_area);
+
+    // Diff of all drafts against the stored lists
+    let pending = matrix.pending_changes();
+    let mut diff = vec![];
+    if pending.is_empty() {
+        diff.push(Line::from(""));
+        diff.push(Line::from("No pending changes."));
+        diff.push(Line::from("Edit a cell (Enter) or import a JSON file (i)."));
+    }
+    for change in &pending {
+        let action = if change.commands.is_empty() {
+            "deleteMyCommands"
+        } else {
+            "setMyCommands"
+        };
+        diff.push(section(&format!(
+            "{} [{}] - {action}",
+            change.scope.label(),
+            display_language(&change.language_code)
+        )));
+        if change.changes.is_empty() {
+            diff.push(Line::from("  order changed"));
+        }
+        for item in &change.changes {
+            let text = item.to_string();
+            let color = match text.chars().next() {
+                Some('+') => Color::Green,
+                Some('-') => Color::Red,
+                _ => Color::Yellow,
+            };
+            diff.push(Line::from(Span::styled(
+                format!("  {text}"),
+                Style::default().fg(color),
+            )));
+        }
+    }
+
+    if let Some(result) = &app.ui.commands_result {
+        let color = if result.starts_with('✓') {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        diff.push(Line::from(""));
+        diff.push(Line::from(Span::styled(
+            result.as_str(),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    let diff_paragraph = Paragraph::new(diff)
+        .block(Block::bordered().title(format!("Pending Changes ({})", pending.len())))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(diff_paragraph, diff_area);
+}
+
+fn section(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn push_commands(lines: &mut Vec<Line<'static>>, commands: &[BotCommand]) {
+    if commands.is_empty() {
+        lines.push(Line::from("  (empty)"));
+    }
+    for command in commands {
+        lines.push(Line::from(format!(
+            "  /{} - {}",
+            command.command, command.description
+        )));
+    }
+}
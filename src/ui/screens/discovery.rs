@@ -50,6 +50,12 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                 "  "
             };
 
+            // Chats the bot was removed from are dimmed
+            let color = if chat.bot_removed() {
+                Color::DarkGray
+            } else {
+                color
+            };
             let style = if i == app.ui.selected_chat_index {
                 Style::default().fg(color).add_modifier(Modifier::BOLD)
             } else {
@@ -63,12 +69,21 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                 chat.chat.chat_type
             );
 
-            ListItem::new(Line::from(Span::styled(content, style)))
+            let mut spans = vec![Span::styled(content, style)];
+            if let Some(status) = chat.bot_status() {
+                spans.push(Span::raw(" "));
+                spans.push(Span::styled(
+                    format!("[{status}]"),
+                    Style::default().fg(bot_status_color(status)),
+                ));
+            }
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
     let chat_list = List::new(chat_items).block(
-        Block::bordered().title("Chats (↑/↓ to navigate | Enter to view messages | e to export)"),
+        Block::bordered()
+            .title("Chats (↑/↓ to navigate | Enter to view messages & details | e to export)"),
     );
 
     frame.render_widget(chat_list, list_area);
@@ -88,6 +103,13 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
             Line::from(format!("Type: {}", selected_chat.chat.chat_type)),
         ];
 
+        if let Some(old_chat_id) = selected_chat.migrated_from_chat_id {
+            details.push(Line::from(Span::styled(
+                format!("Upgraded from group: {old_chat_id} (sends to it fail)"),
+                Style::default().fg(Color::Yellow),
+            )));
+        }
+
         if let Some(username) = &selected_chat.chat.username {
             details.push(Line::from(format!("Username: @{username}")));
         }
@@ -117,11 +139,38 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         )));
         details.push(Line::from(format!(
             "Last Activity: {}",
-            chrono::DateTime::from_timestamp(selected_chat.last_seen, 0)
-                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-                .unwrap_or_else(|| "Unknown".to_string())
+            format_timestamp(selected_chat.last_seen)
         )));
 
+        if let Some(status) = selected_chat.bot_status() {
+            details.push(Line::from(""));
+            details.push(Line::from(vec![
+                Span::styled(
+                    "Bot Status: ",
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(status, Style::default().fg(bot_status_color(status))),
+            ]));
+            if let Some(removed_at) = selected_chat.bot_removed_at() {
+                details.push(Line::from(Span::styled(
+                    format!("Bot removed at: {}", format_timestamp(removed_at)),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            // Most recent changes first
+            for change in selected_chat.bot_status_history.iter().rev().take(5) {
+                details.push(Line::from(format!(
+                    "  {}  {} → {} by {}",
+                    format_timestamp(change.date),
+                    change.old_status,
+                    change.new_status,
+                    change.changed_by
+                )));
+            }
+        }
+
         if !selected_chat.topics.is_empty() {
             details.push(Line::from(""));
             details.push(Line::from(Span::styled(
@@ -131,9 +180,10 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                     .add_modifier(Modifier::BOLD),
             )));
             for topic in &selected_chat.topics {
-                let topic_name = topic.name.as_deref().unwrap_or("Unknown Topic");
+                let topic_name = topic.display_name();
+                let closed = if topic.is_closed { " [closed]" } else { "" };
                 details.push(Line::from(format!(
-                    "  • Thread ID: {} - {} ({} messages)",
+                    "  • Thread ID: {} - {} ({} messages){closed}",
                     topic.thread_id, topic_name, topic.message_count
                 )));
             }
@@ -154,3 +204,19 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         frame.render_widget(details_paragraph, details_area);
     }
 }
+
+/// Colors a bot membership status: red when removed, yellow when restricted.
+fn bot_status_color(status: &str) -> Color {
+    match status {
+        "creator" | "administrator" => Color::Green,
+        "member" => Color::Cyan,
+        "restricted" => Color::Yellow,
+        _ => Color::Red,
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
@@ -3,17 +3,24 @@ use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
-    widgets::{Block, List, ListItem, Paragraph},
+    widgets::{Block, List, ListItem, Paragraph, Wrap},
 };
 
 use crate::app::App;
+use crate::telegram::diagnostics::{CheckItem, CheckLevel};
+use crate::telegram::profile::ProfileField;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
-    let [stats_area, menu_area] = Layout::vertical([
+    let [stats_area, lower_area] = Layout::vertical([
         Constraint::Length(6), // Stats box
-        Constraint::Min(0),    // Navigation menu
+        Constraint::Min(0),    // Navigation menu + diagnostics
     ])
     .areas(area);
+    let [menu_area, side_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(lower_area);
+    let [profile_area, diagnostics_area] =
+        Layout::vertical([Constraint::Length(9), Constraint::Min(0)]).areas(side_area);
 
     // Welcome section
     let chats = app.telegram.get_discovered_chats();
@@ -114,6 +121,42 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
             ),
             Span::raw("- Webhook Management  - Configure webhooks and polling mode"),
         ])),
+        ListItem::new(Line::from(vec![
+            Span::styled(
+                "  6 ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("- Bot Commands        - Command lists per scope and language"),
+        ])),
+        ListItem::new(Line::from(vec![
+            Span::styled(
+                "  7 ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("- Bot Profile         - Name, descriptions, menu button, admin rights"),
+        ])),
+        ListItem::new(Line::from(vec![
+            Span::styled(
+                "  8 ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("- Users               - Everyone seen in updates, with name history"),
+        ])),
+        ListItem::new(Line::from(vec![
+            Span::styled(
+                "  9 ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("- Schema Drift        - Fields unknown to the targeted Bot API version"),
+        ])),
         ListItem::new(""),
         ListItem::new(Line::from(vec![Span::styled(
             "Monitoring:",
@@ -148,4 +191,132 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         .white();
 
     frame.render_widget(menu, menu_area);
+
+    render_profile(frame, profile_area, app);
+    render_diagnostics(frame, diagnostics_area, app);
+}
+
+/// Renders the getMe output next to the main Bot Profile values.
+fn render_profile(frame: &mut Frame, area: Rect, app: &App) {
+    let profile = &app.ui.bot_profile;
+    let Some(bot) = &profile.bot else {
+        let hint = Paragraph::new(vec![
+            Line::from(""),
+            Line::from("Press 'p' to load getMe and the bot profile"),
+            Line::from("(name, descriptions, menu button, admin rights)."),
+        ])
+        .block(Block::bordered().title("Bot (p to load)"));
+        frame.render_widget(hint, area);
+        return;
+    };
+
+    let value = |text: Option<&str>| {
+        text.filter(|t| !t.is_empty())
+            .unwrap_or("(not set)")
+            .replace('\n', " ")
+    };
+    let mut lines = super::bot_profile::get_me_lines(bot);
+    lines.push(Line::from(format!(
+        "  Name: {}",
+        value(profile.text(ProfileField::Name, ""))
+    )));
+    lines.push(Line::from(format!(
+        "  Short description: {}",
+        value(profile.text(ProfileField::ShortDescription, ""))
+    )));
+    lines.push(Line::from(format!(
+        "  Description: {}",
+        value(profile.text(ProfileField::Description, ""))
+    )));
+    lines.push(Line::from(format!(
+        "  Menu button: {}  Admin rights: {} groups / {} channels",
+        profile
+            .menu_button
+            .as_ref()
+            .map(|b| b.describe())
+            .unwrap_or_else(|| "?".to_string()),
+        profile
+            .group_rights
+            .as_ref()
+            .map_or(0, |r| r.granted().len()),
+        profile
+            .channel_rights
+            .as_ref()
+            .map_or(0, |r| r.granted().len()),
+    )));
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::bordered().title("Bot (p to reload, 7 to edit)"))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(paragraph, area);
+}
+
+/// Renders the per-chat permission and privacy-mode checklist.
+fn render_diagnostics(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(diagnostics) = &app.ui.diagnostics else {
+        let hint = Paragraph::new(vec![
+            Line::from(""),
+            Line::from("Bot doesn't see messages? Press 'd' to check:"),
+            Line::from(""),
+            Line::from("  • privacy mode and group settings (getMe)"),
+            Line::from("  • webhook vs. polling (getWebhookInfo)"),
+            Line::from("  • the bot's status in every discovered chat (getChatMember)"),
+        ])
+        .block(Block::bordered().title("Diagnostics (d to run)"));
+        frame.render_widget(hint, area);
+        return;
+    };
+
+    let mut lines = vec![Line::from(Span::styled(
+        "Bot & update delivery:",
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    lines.extend(diagnostics.general.iter().map(check_line));
+
+    for chat in &diagnostics.chats {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} ", chat.worst_level().symbol()),
+                Style::default().fg(level_color(chat.worst_level())),
+            ),
+            Span::styled(
+                format!("{} ({}, {})", chat.chat_name, chat.chat_type, chat.chat_id),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        lines.extend(chat.checks.iter().map(check_line));
+    }
+
+    let checked_at = chrono::DateTime::from_timestamp(diagnostics.generated_at, 0)
+        .map(|dt| {
+            dt.with_timezone(&chrono::Local)
+                .format("%H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default();
+    let paragraph = Paragraph::new(lines)
+        .block(Block::bordered().title(format!("Diagnostics ({checked_at}, d to re-run)")))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+fn check_line(check: &CheckItem) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            format!("  {} ", check.level.symbol()),
+            Style::default().fg(level_color(check.level)),
+        ),
+        Span::raw(check.message.clone()),
+    ])
+}
+
+fn level_color(level: CheckLevel) -> Color {
+    match level {
+        CheckLevel::Ok => Color::Green,
+        CheckLevel::Warning => Color::Yellow,
+        CheckLevel::Error => Color::Red,
+    }
 }
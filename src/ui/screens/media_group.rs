@@ -0,0 +1,182 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::{App, TestMessageMode};
+use crate::telegram::{InputMediaKind, MEDIA_GROUP_MAX_ITEMS, MEDIA_GROUP_MIN_ITEMS};
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5), // Target info
+            Constraint::Min(6),    // Album items
+            Constraint::Length(3), // Item input
+            Constraint::Length(9), // Info section
+        ])
+        .split(area);
+
+    // Target info (shares the mode and topic of the Test Message screen)
+    let mut target_lines = vec![Line::from(Span::styled(
+        "Target Chat:",
+        Style::default().fg(Color::Cyan),
+    ))];
+    match app.ui.test_message_mode {
+        TestMessageMode::SelectedChat => match app.get_selected_chat() {
+            Some(chat) => {
+                target_lines.push(Line::from(format!(
+                    "  {} (Chat ID: {})",
+                    chat.chat.display_name(),
+                    chat.chat.id
+                )));
+                if !chat.topics.is_empty() {
+                    let topic = app
+                        .test_message_thread_id()
+                        .and_then(|thread_id| chat.topics.iter().find(|t| t.thread_id == thread_id))
+                        .map_or("General (no topic)".to_string(), |topic| {
+                            format!("# {} (thread {})", topic.display_name(), topic.thread_id)
+                        });
+                    target_lines.push(Line::from(vec![
+                        Span::raw("  Topic: "),
+                        Span::styled(topic, Style::default().fg(Color::Yellow)),
+                        Span::styled(
+                            "  (set on the Test Message screen)",
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                    ]));
+                }
+            }
+            None => target_lines.push(Line::from(Span::styled(
+                "  No chat selected - select one in Discovery (1)",
+                Style::default().fg(Color::Red),
+            ))),
+        },
+        TestMessageMode::ManualChatId => {
+            if app.ui.manual_chat_id_input.is_empty() {
+                target_lines.push(Line::from(Span::styled(
+                    "  No manual chat ID entered - set one on the Test Message screen (m)",
+                    Style::default().fg(Color::Red),
+                )));
+            } else {
+                target_lines.push(Line::from(format!(
+                    "  Chat ID: {}",
+                    app.ui.manual_chat_id_input
+                )));
+            }
+        }
+    }
+
+    let target = Paragraph::new(target_lines).block(Block::bordered().title("Target"));
+
+    frame.render_widget(target, chunks[0]);
+
+    // Album items
+    let items: Vec<ListItem> = if app.ui.media_group_items.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "  No items yet - type a file_id, URL or path below and press Enter",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        app.ui
+            .media_group_items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{:>2}. ", i + 1),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        format!("[{}] ", item.kind.as_str()),
+                        Style::default().fg(kind_color(item.kind)),
+                    ),
+                    Span::raw(item.source.describe()),
+                ];
+                if let Some(caption) = &item.caption {
+                    spans.push(Span::styled(
+                        format!("  \"{caption}\""),
+                        Style::default().fg(Color::Green),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect()
+    };
+
+    let item_list = List::new(items).block(Block::bordered().title(format!(
+        "Album Items ({}/{MEDIA_GROUP_MAX_ITEMS})",
+        app.ui.media_group_items.len()
+    )));
+
+    frame.render_widget(item_list, chunks[1]);
+
+    // Item input
+    let input = Paragraph::new(app.ui.media_group_input.as_str())
+        .block(
+            Block::bordered()
+                .title(format!(
+                    "New {} item «  (file_id | URL | path [ | caption])",
+                    app.ui.media_group_kind.as_str()
+                ))
+                .border_style(Style::default().fg(Color::Green)),
+        )
+        .style(Style::default().fg(Color::White));
+
+    frame.render_widget(input, chunks[2]);
+
+    // Info section
+    let mut info_lines = vec![];
+
+    if let Some(result) = &app.ui.media_group_result {
+        let color = if result.starts_with("✓") {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        info_lines.push(Line::from(Span::styled(
+            result.as_str(),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    info_lines.push(Line::from(vec![
+        Span::styled("  Enter", Style::default().fg(Color::Yellow)),
+        Span::raw(" - Add item (send the album when the input is empty)"),
+    ]));
+    info_lines.push(Line::from(vec![
+        Span::styled("  Tab", Style::default().fg(Color::Yellow)),
+        Span::raw(" - Cycle media type (photo → video → audio → document)"),
+    ]));
+    info_lines.push(Line::from(vec![
+        Span::styled("  Del", Style::default().fg(Color::Yellow)),
+        Span::raw(" - Remove last item"),
+    ]));
+    info_lines.push(Line::from(vec![
+        Span::styled("  Esc", Style::default().fg(Color::Yellow)),
+        Span::raw(" - Go back"),
+    ]));
+    info_lines.push(Line::from(format!(
+        "Rules: {MEDIA_GROUP_MIN_ITEMS}-{MEDIA_GROUP_MAX_ITEMS} items; photos and videos can be mixed, \
+         audio and documents only with their own kind."
+    )));
+
+    let info = Paragraph::new(info_lines)
+        .block(Block::bordered().title("Info"))
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(info, chunks[3]);
+}
+
+fn kind_color(kind: InputMediaKind) -> Color {
+    match kind {
+        InputMediaKind::Photo => Color::Cyan,
+        InputMediaKind::Video => Color::Magenta,
+        InputMediaKind::Audio => Color::Yellow,
+        InputMediaKind::Document => Color::Blue,
+    }
+}
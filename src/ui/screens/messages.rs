@@ -6,7 +6,13 @@ use ratatui::{
     widgets::{Block, List, ListItem, Paragraph, Wrap},
 };
 
-use crate::app::App;
+use crate::app::telegram_manager::TelegramManager;
+use crate::app::{App, MessagePanel};
+use crate::telegram::content::{MessageContent, MessageEntity, parse_entities, utf16_length};
+use crate::telegram::files::format_file_size;
+use crate::telegram::history::{DiffChunk, diff_entities, diff_text};
+use crate::telegram::types::{ChannelPost, Message};
+use crate::ui::entities::styled_lines;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let selected_chat = app.get_selected_chat();
@@ -27,7 +33,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     }
 
     let chat = selected_chat.unwrap();
-    let messages = app.telegram.get_messages_for_chat(chat.chat.id);
+    let messages = app.telegram.get_message_groups_for_chat(chat.chat.id);
 
     if messages.is_empty() {
         let empty_message = Paragraph::new(vec![
@@ -48,6 +54,36 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         return;
     }
 
+    // Prompt of a pending edit/forward/copy/delete action
+    let area = if let Some(action) = app.ui.message_action {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3)])
+            .split(area);
+
+        let (content, color) = if action.is_destructive() {
+            let count = messages
+                .get(app.ui.selected_message_index)
+                .map(|group| group.len())
+                .unwrap_or(0);
+            (
+                format!("{count} message(s) will be removed from the chat"),
+                Color::Red,
+            )
+        } else {
+            (app.ui.message_action_input.clone(), Color::Green)
+        };
+        let prompt = Paragraph::new(content).block(
+            Block::bordered()
+                .title(action.prompt())
+                .border_style(Style::default().fg(color)),
+        );
+        frame.render_widget(prompt, rows[1]);
+        rows[0]
+    } else {
+        area
+    };
+
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
@@ -57,17 +93,27 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let message_items: Vec<ListItem> = messages
         .iter()
         .enumerate()
-        .map(|(i, update)| {
+        .map(|(i, group)| {
+            let update = group[0];
             let indicator = if i == app.ui.selected_message_index {
                 "→ "
             } else {
                 "  "
             };
 
+            let outgoing = TelegramManager::is_outgoing(update);
+            let deleted = update
+                .message_key()
+                .is_some_and(|(chat_id, message_id)| app.telegram.is_deleted(chat_id, message_id));
+
             let style = if i == app.ui.selected_message_index {
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD)
+            } else if deleted {
+                Style::default().fg(Color::DarkGray)
+            } else if outgoing {
+                Style::default().fg(Color::Magenta)
             } else {
                 Style::default().fg(Color::White)
             };
@@ -84,23 +130,13 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                     .as_ref()
                     .map(|u| u.username.clone().unwrap_or_else(|| u.first_name.clone()))
                     .unwrap_or_else(|| "Unknown".to_string());
-                let text = message.text.as_deref().unwrap_or("[No text]");
-                let preview = if text.len() > 30 {
-                    format!("{}...", &text[..30])
-                } else {
-                    text.to_string()
-                };
+                let preview = preview(message.content().summary());
                 (ts, sender_name, preview)
             } else if let Some(channel_post) = &update.channel_post {
                 let ts = chrono::DateTime::from_timestamp(channel_post.date, 0)
                     .map(|dt| dt.format("%H:%M:%S").to_string())
                     .unwrap_or_else(|| "Unknown".to_string());
-                let text = channel_post.text.as_deref().unwrap_or("[No text]");
-                let preview = if text.len() > 30 {
-                    format!("{}...", &text[..30])
-                } else {
-                    text.to_string()
-                };
+                let preview = preview(channel_post.content().summary());
                 (ts, "Channel".to_string(), preview)
             } else if let Some(edited_message) = &update.edited_message {
                 let ts = chrono::DateTime::from_timestamp(edited_message.date, 0)
@@ -111,12 +147,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                     .as_ref()
                     .map(|u| u.username.clone().unwrap_or_else(|| u.first_name.clone()))
                     .unwrap_or_else(|| "Edited".to_string());
-                let text = edited_message.text.as_deref().unwrap_or("[No text]");
-                let preview = if text.len() > 30 {
-                    format!("{}...", &text[..30])
-                } else {
-                    text.to_string()
-                };
+                let preview = preview(edited_message.content().summary());
                 (ts, format!("{sender_name} (edited)"), preview)
             } else {
                 // For other update types, show the type and update ID
@@ -127,18 +158,69 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                 )
             };
 
-            let content = format!("{indicator}{timestamp} | {sender} | {text_preview}");
+            let edits = update
+                .message_key()
+                .map(|(chat_id, message_id)| app.telegram.edit_count(chat_id, message_id))
+                .unwrap_or(0);
+            let mut edited = if edits > 0 {
+                format!(" ✎{edits}")
+            } else {
+                String::new()
+            };
+            if deleted {
+                edited.push_str(" [deleted]");
+            }
+            let sender = if outgoing {
+                format!("⇢ {sender} (outgoing)")
+            } else {
+                sender
+            };
+
+            let content = if group.len() > 1 {
+                let caption = group
+                    .iter()
+                    .find_map(|u| u.message.as_ref().and_then(Message::caption))
+                    .or_else(|| {
+                        group
+                            .iter()
+                            .find_map(|u| u.channel_post.as_ref().and_then(ChannelPost::caption))
+                    })
+                    .map(|c| format!(" \"{c}\""))
+                    .unwrap_or_default();
+                format!(
+                    "{indicator}{timestamp} | {sender} | 🖼 album ({} items){caption}{edited}",
+                    group.len()
+                )
+            } else {
+                format!("{indicator}{timestamp} | {sender} | {text_preview}{edited}")
+            };
             ListItem::new(Line::from(Span::styled(content, style)))
         })
         .collect();
 
     let message_list = List::new(message_items)
         .block(Block::bordered().title(
-            "Messages (↑/↓ to navigate | m to send message | e to export | Esc to go back)",
+            "Messages (↑/↓ to navigate | m to send message | e to export | f for files | Esc to go back)",
         ));
 
     frame.render_widget(message_list, chunks[0]);
 
+    match app.ui.message_panel {
+        MessagePanel::EditHistory => {
+            render_edit_history(frame, chunks[1], app);
+            return;
+        }
+        MessagePanel::Conversation => {
+            render_conversation(frame, chunks[1], app);
+            return;
+        }
+        MessagePanel::Entities => {
+            render_entities(frame, chunks[1], app);
+            return;
+        }
+        MessagePanel::Details => {}
+    }
+
     // Message details
     if let Some(selected_message) = app.get_selected_message_for_current_chat() {
         let mut details = vec![
@@ -154,16 +236,31 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
 
         // Show update type at the top
         let update_type = selected_message.get_update_type();
+        let direction = if TelegramManager::is_outgoing(selected_message) {
+            " (outgoing, sent by the bot)"
+        } else {
+            ""
+        };
         details.push(Line::from(Span::styled(
-            format!("Type: {update_type}"),
+            format!("Type: {update_type}{direction}"),
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )));
-        details.push(Line::from(format!(
-            "Update ID: {}",
-            selected_message.update_id
-        )));
+        if !TelegramManager::is_outgoing(selected_message) {
+            details.push(Line::from(format!(
+                "Update ID: {}",
+                selected_message.update_id
+            )));
+        }
+        if let Some((chat_id, message_id)) = selected_message.message_key()
+            && app.telegram.is_deleted(chat_id, message_id)
+        {
+            details.push(Line::from(Span::styled(
+                "Deleted by the bot",
+                Style::default().fg(Color::Red),
+            )));
+        }
         details.push(Line::from(""));
 
         if let Some(message) = &selected_message.message {
@@ -198,14 +295,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                 details.push(Line::from(format!("  Is Bot: {}", from.is_bot)));
             }
 
-            if let Some(text) = &message.text {
-                details.push(Line::from(""));
-                details.push(Line::from(Span::styled(
-                    "Text:",
-                    Style::default().fg(Color::Green),
-                )));
-                details.push(Line::from(format!("  {text}")));
-            }
+            push_content(&mut details, message.content(), &message.entities());
         } else if let Some(channel_post) = &selected_message.channel_post {
             details.push(Line::from(format!(
                 "Message ID: {}",
@@ -219,14 +309,11 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                     .unwrap_or_else(|| "Unknown".to_string())
             )));
 
-            if let Some(text) = &channel_post.text {
-                details.push(Line::from(""));
-                details.push(Line::from(Span::styled(
-                    "Text:",
-                    Style::default().fg(Color::Green),
-                )));
-                details.push(Line::from(format!("  {text}")));
-            }
+            push_content(
+                &mut details,
+                channel_post.content(),
+                &channel_post.entities(),
+            );
         } else if let Some(edited_message) = &selected_message.edited_message {
             details.push(Line::from(format!(
                 "Message ID: {}",
@@ -262,14 +349,11 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                 details.push(Line::from(format!("  Is Bot: {}", from.is_bot)));
             }
 
-            if let Some(text) = &edited_message.text {
-                details.push(Line::from(""));
-                details.push(Line::from(Span::styled(
-                    "Text:",
-                    Style::default().fg(Color::Green),
-                )));
-                details.push(Line::from(format!("  {text}")));
-            }
+            push_content(
+                &mut details,
+                edited_message.content(),
+                &edited_message.entities(),
+            );
         } else {
             // For other update types, show basic info and the raw data
             details.push(Line::from(Span::styled(
@@ -282,6 +366,71 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
             ));
         }
 
+        // Album members
+        let album = messages
+            .get(app.ui.selected_message_index)
+            .filter(|group| group.len() > 1);
+        if let Some(album) = album {
+            details.push(Line::from(""));
+            details.push(Line::from(Span::styled(
+                format!(
+                    "Album {} ({} items):",
+                    selected_message.media_group_id().unwrap_or("unknown"),
+                    album.len()
+                ),
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            for update in album {
+                let (message_id, content) = if let Some(message) = &update.message {
+                    (message.message_id, message.content())
+                } else if let Some(channel_post) = &update.channel_post {
+                    (channel_post.message_id, channel_post.content())
+                } else {
+                    continue;
+                };
+                details.push(Line::from(format!(
+                    "  • #{message_id} {}",
+                    content.summary()
+                )));
+            }
+        }
+
+        // Files resolved with getFile
+        if app.ui.file_inspection_update_id == Some(selected_message.update_id) {
+            details.push(Line::from(""));
+            details.push(Line::from(Span::styled(
+                format!("Files ({}):", app.ui.file_inspections.len()),
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            for inspection in &app.ui.file_inspections {
+                let size = inspection
+                    .file_size
+                    .map(format_file_size)
+                    .unwrap_or_else(|| "unknown size".to_string());
+                details.push(Line::from(format!(
+                    "  • {} ({size}, {})",
+                    inspection.reference.location,
+                    inspection.mime_type.as_deref().unwrap_or("unknown type")
+                )));
+                if let Some(path) = &inspection.file_path {
+                    details.push(Line::from(format!("    path: {path}")));
+                }
+                let color = if inspection.outcome.starts_with('✓') {
+                    Color::Green
+                } else {
+                    Color::Red
+                };
+                details.push(Line::from(Span::styled(
+                    format!("    {}", inspection.outcome),
+                    Style::default().fg(color),
+                )));
+            }
+        }
+
         details.push(Line::from(""));
         details.push(Line::from(Span::styled(
             "Actions:",
@@ -289,6 +438,27 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         )));
         details.push(Line::from("  m - Send test message to this chat"));
         details.push(Line::from("  e - Export this message as JSON"));
+        details.push(Line::from("  f - Inspect and download attached files"));
+        details.push(Line::from("  v - Edit history with diffs"));
+        details.push(Line::from("  r - Reply thread (conversation tree)"));
+        details.push(Line::from("  t - Edit text    k - Edit inline keyboard"));
+        details.push(Line::from("  w - Forward      c - Copy"));
+        details.push(Line::from(
+            "  d - Delete (album: all items, asks to confirm)",
+        ));
+
+        if let Some(result) = &app.ui.message_action_result {
+            let color = if result.starts_with('✓') {
+                Color::Green
+            } else {
+                Color::Red
+            };
+            details.push(Line::from(""));
+            details.push(Line::from(Span::styled(
+                result.as_str(),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            )));
+        }
 
         let details_paragraph = Paragraph::new(details)
             .block(Block::bordered().title("Details"))
@@ -297,3 +467,379 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         frame.render_widget(details_paragraph, chunks[1]);
     }
 }
+
+/// Renders every revision of the selected message, each diffed against the previous one.
+fn render_edit_history(frame: &mut Frame, area: Rect, app: &App) {
+    let mut lines = vec![Line::from("")];
+    let revisions = app.selected_message_history().unwrap_or_default();
+
+    if revisions.is_empty() {
+        lines.push(Line::from("No revisions recorded for this message."));
+    } else if revisions[0].is_edit() {
+        lines.push(Line::from(Span::styled(
+            "Original not captured (sent before monitoring started)",
+            Style::default().fg(Color::DarkGray),
+        )));
+        lines.push(Line::from(""));
+    }
+
+    let mut edit_number = 0;
+    for (i, revision) in revisions.iter().enumerate() {
+        let (label, timestamp) = match revision.edit_date {
+            Some(edit_date) => {
+                edit_number += 1;
+                (format!("Edit #{edit_number}"), edit_date)
+            }
+            None => ("Original".to_string(), revision.date),
+        };
+        let time = chrono::DateTime::from_timestamp(timestamp, 0)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{label}  "),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(time),
+            Span::styled(
+                format!("  (update {})", revision.update_id),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+
+        let text = revision.text.as_deref().unwrap_or("");
+        let Some(previous) = i.checked_sub(1).map(|p| &revisions[p]) else {
+            lines.extend(text.lines().map(|line| Line::from(format!("  {line}"))));
+            lines.push(Line::from(""));
+            continue;
+        };
+
+        let previous_text = previous.text.as_deref().unwrap_or("");
+        let (removed, added) = diff_entities(&previous.entities, &revision.entities);
+        if previous_text == text && removed.is_empty() && added.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  (text and entities unchanged)",
+                Style::default().fg(Color::DarkGray),
+            )));
+        } else {
+            lines.extend(diff_lines(&diff_text(previous_text, text)));
+            for entity in removed {
+                lines.push(Line::from(Span::styled(
+                    format!("  - entity {entity}"),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            for entity in added {
+                lines.push(Line::from(Span::styled(
+                    format!("  + entity {entity}"),
+                    Style::default().fg(Color::Green),
+                )));
+            }
+        }
+        lines.push(Line::from(""));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::bordered().title(format!(
+            "Edit History ({} revisions) - v to close",
+            revisions.len()
+        )))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+/// Lists the entities of the selected message with the UTF-16 range Telegram
+/// sent and the UTF-8 byte range it maps to.
+///
+/// When an entity can't be located, or its offsets would cover different text
+/// if they were counted in code points, both readings are shown: that's what
+/// an entity built without UTF-16 looks like.
+fn render_entities(frame: &mut Frame, area: Rect, app: &App) {
+    let update = app.get_selected_message_for_current_chat();
+    let other = update.and_then(|update| update.message_other());
+    let text = update
+        .and_then(|update| update.message_text())
+        .or_else(|| update.and_then(|update| update.message_caption()))
+        .unwrap_or("");
+    let entities = other.map(parse_entities).unwrap_or_default();
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(format!(
+            "Text: {} UTF-16 units, {} bytes, {} code points",
+            utf16_length(text),
+            text.len(),
+            text.chars().count()
+        )),
+        Line::from(""),
+    ];
+    if entities.is_empty() {
+        lines.push(Line::from("This message has no entities."));
+    } else {
+        push_styled(&mut lines, text, &entities);
+        lines.push(Line::from(""));
+    }
+
+    for (i, entity) in entities.iter().enumerate() {
+        let utf16 = entity.utf16_range();
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("#{} {}", i + 1, entity.kind.as_str()),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                "  UTF-16 {}..{} (offset {}, length {})",
+                utf16.start, utf16.end, entity.offset, entity.length
+            )),
+        ]));
+        let located = entity.byte_range(text);
+        match &located {
+            Ok(bytes) => {
+                lines.push(Line::from(format!(
+                    "  UTF-8 {}..{}  {:?}",
+                    bytes.start,
+                    bytes.end,
+                    &text[bytes.clone()]
+                )));
+            }
+            Err(error) => {
+                lines.push(Line::from(Span::styled(
+                    format!("  UTF-8 -: {error}"),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+        }
+        if let Some(code_points) = entity.code_point_range(text)
+            && located.as_ref().ok() != Some(&code_points)
+        {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  As code points {}..{}: {:?}",
+                    utf16.start, utf16.end, &text[code_points]
+                ),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        if let Some(url) = &entity.url {
+            lines.push(Line::from(format!("  URL: {url}")));
+        }
+        if let Some(language) = &entity.language {
+            lines.push(Line::from(format!("  Language: {language}")));
+        }
+        if let Some(custom_emoji_id) = &entity.custom_emoji_id {
+            lines.push(Line::from(format!("  Custom emoji ID: {custom_emoji_id}")));
+        }
+        if let Some(user) = &entity.user {
+            lines.push(Line::from(format!(
+                "  User: {} ({})",
+                user.first_name, user.id
+            )));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::bordered().title(format!("Entities ({}) - n to close", entities.len())))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+/// Renders the reply thread of the selected message as a tree.
+///
+/// The selected message is bold, its parent yellow and its direct replies
+/// green; messages sent by the bot are marked as outgoing.
+fn render_conversation(frame: &mut Frame, area: Rect, app: &App) {
+    let selected = app
+        .get_selected_message_for_current_chat()
+        .and_then(|update| update.message_key());
+    let thread = app.selected_message_thread();
+    let selected_node = selected
+        .and_then(|(chat_id, message_id)| app.telegram.get_conversation_node(chat_id, message_id));
+
+    let mut lines = vec![Line::from("")];
+    let Some(node) = selected_node else {
+        lines.push(Line::from("This message is not part of a conversation."));
+        lines.push(Line::from(""));
+        lines.push(Line::from(
+            "Replies are linked through reply_to_message and forum topics.",
+        ));
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title("Conversation - r to close")),
+            area,
+        );
+        return;
+    };
+
+    for row in &thread {
+        let message = row.node;
+        let style = if message.message_id == node.message_id {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else if Some(message.message_id) == node.reply_to {
+            Style::default().fg(Color::Yellow)
+        } else if message.reply_to == Some(node.message_id) {
+            Style::default().fg(Color::Green)
+        } else if message.placeholder {
+            Style::default().fg(Color::DarkGray)
+        } else {
+            Style::default()
+        };
+        let branch = if row.depth == 0 {
+            String::new()
+        } else {
+            format!("{}└ ", "  ".repeat(row.depth - 1))
+        };
+        let text = message.text.as_deref().unwrap_or("[No text]");
+        let preview: String = text.chars().take(40).collect();
+        let ellipsis = if text.chars().count() > 40 { "..." } else { "" };
+
+        let mut spans = vec![
+            Span::raw(branch),
+            Span::styled(
+                format!(
+                    "#{} {}: {preview}{ellipsis}",
+                    message.message_id, message.sender
+                ),
+                style,
+            ),
+        ];
+        if message.outgoing {
+            spans.push(Span::styled(
+                "  [outgoing]",
+                Style::default().fg(Color::Magenta),
+            ));
+        }
+        if message.placeholder {
+            spans.push(Span::styled(
+                "  [not received]",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(spans));
+        if let Some(quote) = &message.quote {
+            lines.push(Line::from(Span::styled(
+                format!("{}  ❝{quote}❞", "  ".repeat(row.depth)),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    lines.push(Line::from(""));
+    if let Some(thread_id) = node.thread_id {
+        lines.push(Line::from(format!("Topic thread ID: {thread_id}")));
+    }
+    lines.push(Line::from(match node.reply_to {
+        Some(parent) => format!("Replies to: #{parent}"),
+        None => "Replies to: -".to_string(),
+    }));
+    lines.push(Line::from(format!("Replies: {}", node.replies.len())));
+    lines.push(Line::from(Span::styled(
+        "Selected: cyan  Parent: yellow  Replies: green",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::bordered().title(format!(
+            "Conversation ({} messages) - r to close",
+            thread.len()
+        )))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+/// Turns diff chunks into indented lines: removed words in red and struck
+/// through, added words in green.
+fn diff_lines(chunks: &[DiffChunk]) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut spans = vec![Span::raw("  ")];
+    for chunk in chunks {
+        let (text, style) = match chunk {
+            DiffChunk::Same(text) => (text, Style::default()),
+            DiffChunk::Removed(text) => (
+                text,
+                Style::default()
+                    .fg(Color::Red)
+                    .add_modifier(Modifier::CROSSED_OUT),
+            ),
+            DiffChunk::Added(text) => (
+                text,
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        };
+        for (n, part) in text.split('\n').enumerate() {
+            if n > 0 {
+                lines.push(Line::from(std::mem::replace(
+                    &mut spans,
+                    vec![Span::raw("  ")],
+                )));
+            }
+            if !part.is_empty() {
+                spans.push(Span::styled(part.to_string(), style));
+            }
+        }
+    }
+    lines.push(Line::from(spans));
+    lines
+}
+
+/// Returns the kind of media attached to a message based on its extra fields.
+/// Cuts a list preview to 30 characters.
+fn preview(text: String) -> String {
+    if text.chars().count() > 30 {
+        format!("{}...", text.chars().take(30).collect::<String>())
+    } else {
+        text
+    }
+}
+
+/// Adds the text, or a summary of the media or service message, and its entities.
+fn push_content(details: &mut Vec<Line>, content: MessageContent, entities: &[MessageEntity]) {
+    details.push(Line::from(""));
+    match content {
+        MessageContent::Text(text) => {
+            details.push(Line::from(Span::styled(
+                "Text:",
+                Style::default().fg(Color::Green),
+            )));
+            push_styled(details, &text, entities);
+        }
+        content => {
+            details.push(Line::from(Span::styled(
+                "Content:",
+                Style::default().fg(Color::Green),
+            )));
+            details.push(Line::from(format!("  {}", content.summary())));
+            if let Some(caption) = content.caption()
+                && !entities.is_empty()
+            {
+                push_styled(details, caption, entities);
+            }
+        }
+    }
+    if !entities.is_empty() {
+        let kinds: Vec<&str> = entities.iter().map(|e| e.kind.as_str()).collect();
+        details.push(Line::from(Span::styled(
+            format!("  Entities: {} (n for ranges)", kinds.join(", ")),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+}
+
+/// Adds a text styled by its entities, indented.
+fn push_styled(details: &mut Vec<Line>, text: &str, entities: &[MessageEntity]) {
+    details.extend(
+        styled_lines(text, entities, Style::default())
+            .into_iter()
+            .map(|line| {
+                let mut spans = vec![Span::raw("  ")];
+                spans.extend(line.spans);
+                Line::from(spans)
+            }),
+    );
+}
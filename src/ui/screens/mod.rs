@@ -1,9 +1,16 @@
 pub mod analytics;
+pub mod bot_profile;
+pub mod chat_details;
+pub mod commands;
 pub mod discovery;
 pub mod home;
+pub mod media_group;
 pub mod messages;
 pub mod monitor;
 pub mod raw_json;
+pub mod schema_drift;
 pub mod test_message;
 pub mod token_input;
+pub mod topics;
+pub mod users;
 pub mod webhook_management;
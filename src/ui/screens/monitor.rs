@@ -8,6 +8,7 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::ui::entities::styled_lines;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let chunks = Layout::default()
@@ -77,7 +78,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                     .map(|s| format!("from {s}"))
                     .unwrap_or_default();
 
-                let line = Line::from(vec![
+                let mut spans = vec![
                     Span::styled(
                         format!("[{time_str}] "),
                         Style::default().fg(Color::DarkGray),
@@ -90,8 +91,16 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                     ),
                     Span::styled(sender_str, Style::default().fg(Color::Yellow)),
                     Span::raw(": "),
-                    Span::raw(&msg.text),
-                ]);
+                ];
+                // Multi-line texts are shown on one line
+                let text_lines = styled_lines(&msg.text, &msg.entities, Style::default());
+                for (i, text_line) in text_lines.into_iter().enumerate() {
+                    if i > 0 {
+                        spans.push(Span::styled(" ↵ ", Style::default().fg(Color::DarkGray)));
+                    }
+                    spans.extend(text_line.spans);
+                }
+                let line = Line::from(spans);
 
                 ListItem::new(line)
             })
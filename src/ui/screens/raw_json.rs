@@ -6,10 +6,17 @@ use ratatui::{
     widgets::{Block, List, ListItem, Paragraph, Wrap},
 };
 use serde_json;
+use std::collections::HashMap;
 
-use crate::app::App;
+use crate::app::{App, RawJsonSource};
+use crate::telegram::wire::indent_json;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    if app.ui.raw_json_source == RawJsonSource::ApiResponses {
+        render_api_responses(frame, area, app);
+        return;
+    }
+
     if app.telegram.raw_updates.is_empty() {
         let empty_message = Paragraph::new(vec![
             Line::from(""),
@@ -24,6 +31,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
             Line::from(""),
             Line::from("This view shows the complete Telegram API response,"),
             Line::from("including all chat IDs, topic IDs, and other details."),
+            Line::from("Press 'a' for the responses of all API calls."),
         ])
         .block(Block::bordered().title("Raw JSON Updates"));
 
@@ -36,38 +44,69 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
         .split(area);
 
+    // Count album sizes so that album items can be displayed as one unit
+    let mut album_sizes: HashMap<&str, usize> = HashMap::new();
+    for update in &app.telegram.raw_updates {
+        if let Some(group_id) = update.media_group_id() {
+            *album_sizes.entry(group_id).or_insert(0) += 1;
+        }
+    }
+
     // Update list
-    let update_items: Vec<ListItem> = app
-        .telegram
-        .raw_updates
-        .iter()
-        .enumerate()
-        .rev() // Show newest first
-        .map(|(i, update)| {
-            let indicator = if i == app.ui.selected_update_index {
-                "→ "
-            } else {
-                "  "
-            };
+    let mut update_items: Vec<ListItem> = Vec::new();
+    let mut current_album: Option<&str> = None;
+    for (i, update) in app.telegram.raw_updates.iter().enumerate().rev() {
+        // Show newest first
+        let group_id = update.media_group_id();
+        if group_id.is_some() && group_id != current_album {
+            let size = group_id.and_then(|id| album_sizes.get(id)).unwrap_or(&1);
+            update_items.push(ListItem::new(Line::from(Span::styled(
+                format!("┌ Album {} ({size} items)", group_id.unwrap_or_default()),
+                Style::default().fg(Color::Magenta),
+            ))));
+        }
+        current_album = group_id;
 
-            let style = if i == app.ui.selected_update_index {
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(Color::White)
-            };
+        let indicator = if i == app.ui.selected_update_index {
+            "→ "
+        } else {
+            "  "
+        };
+        let album_prefix = if group_id.is_some() { "│ " } else { "" };
 
-            let update_type = update.get_update_type();
+        let style = if i == app.ui.selected_update_index {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
 
-            let content = format!("{}Update {} ({})", indicator, update.update_id, update_type);
+        let update_type = update.get_update_type();
 
-            ListItem::new(Line::from(Span::styled(content, style)))
-        })
-        .collect();
+        // Who and where, from the typed payload
+        let kind = update.kind();
+        let mut origin = String::new();
+        if let Some(sender) = kind.sender() {
+            origin.push_str(&format!(" {}", sender.display_name()));
+        }
+        if let Some(chat) = kind.chat() {
+            origin.push_str(&format!(" in {}", chat.display_name()));
+        }
+        if kind.is_unparsed() {
+            origin.push_str(" ⚠ unparsed");
+        }
+
+        let content = format!(
+            "{album_prefix}{indicator}Update {} ({update_type}){origin}",
+            update.update_id
+        );
+
+        update_items.push(ListItem::new(Line::from(Span::styled(content, style))));
+    }
 
-    let update_list =
-        List::new(update_items).block(Block::bordered().title("Updates (↑/↓ to navigate)"));
+    let update_list = List::new(update_items)
+        .block(Block::bordered().title("Updates (↑/↓ navigate | a API responses)"));
 
     frame.render_widget(update_list, chunks[0]);
 
@@ -76,19 +115,146 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         .telegram
         .get_selected_update(app.ui.selected_update_index)
     {
-        let json_str = match serde_json::to_string_pretty(selected_update.as_ref()) {
-            Ok(json) => json,
-            Err(e) => format!("Error serializing JSON: {e}"),
+        let verbatim = app.telegram.verbatim_update(selected_update.update_id);
+        let (json_str, view) = match verbatim {
+            Some(raw) if !app.ui.raw_json_normalized => (indent_json(raw), "Verbatim"),
+            _ => {
+                let json = match serde_json::to_string_pretty(selected_update.as_ref()) {
+                    Ok(json) => json,
+                    Err(e) => format!("Error serializing JSON: {e}"),
+                };
+                let view = if verbatim.is_some() {
+                    "Normalized"
+                } else {
+                    "Normalized, not captured verbatim"
+                };
+                (json, view)
+            }
         };
 
         // Split into lines for display
         let lines: Vec<Line> = json_str.lines().map(Line::from).collect();
 
+        let title = match selected_update.media_group_id() {
+            Some(group_id) => {
+                let album: Vec<i64> = app
+                    .telegram
+                    .raw_updates
+                    .iter()
+                    .filter(|u| u.media_group_id() == Some(group_id))
+                    .map(|u| u.update_id)
+                    .collect();
+                let position = album
+                    .iter()
+                    .position(|id| *id == selected_update.update_id)
+                    .map(|p| p + 1)
+                    .unwrap_or(1);
+                format!(
+                    "{view} JSON - Album {group_id} item {position}/{} (v toggle | e export)",
+                    album.len()
+                )
+            }
+            None => format!("{view} JSON (v toggle | e export)"),
+        };
+
         let json_paragraph = Paragraph::new(lines)
-            .block(Block::bordered().title("JSON Details (Press 'e' to export)"))
+            .block(Block::bordered().title(title))
             .wrap(Wrap { trim: false })
             .green();
 
         frame.render_widget(json_paragraph, chunks[1]);
     }
 }
+
+/// Lists the responses of all API calls, newest first, with the selected body.
+fn render_api_responses(frame: &mut Frame, area: Rect, app: &App) {
+    let captures = app.telegram.wire_captures();
+    if captures.is_empty() {
+        let empty_message = Paragraph::new(vec![
+            Line::from(""),
+            Line::from("No API responses captured yet."),
+            Line::from(""),
+            Line::from("The body of every Bot API response is kept here exactly as received."),
+            Line::from("getUpdates polls without updates are left out."),
+            Line::from("Press 'a' to go back to the updates."),
+        ])
+        .block(Block::bordered().title("API Responses"));
+        frame.render_widget(empty_message, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .split(area);
+
+    let items: Vec<ListItem> = captures
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, capture)| {
+            let selected = i == app.ui.selected_response_index;
+            let time = chrono::DateTime::from_timestamp(capture.timestamp, 0)
+                .map(|dt| {
+                    dt.with_timezone(&chrono::Local)
+                        .format("%H:%M:%S")
+                        .to_string()
+                })
+                .unwrap_or_else(|| "Unknown".to_string());
+            let color = if capture.status == 200 {
+                Color::White
+            } else {
+                Color::Red
+            };
+            let style = if selected {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(color)
+            };
+            ListItem::new(Line::from(Span::styled(
+                format!(
+                    "{}{time} {} ({}, {} bytes)",
+                    if selected { "→ " } else { "  " },
+                    capture.method,
+                    capture.status,
+                    capture.body.len()
+                ),
+                style,
+            )))
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(Block::bordered().title(format!(
+            "API Responses ({}) (↑/↓ navigate | a updates)",
+            captures.len()
+        ))),
+        chunks[0],
+    );
+
+    let Some(capture) = captures.get(app.ui.selected_response_index) else {
+        return;
+    };
+    let (json_str, view) = if app.ui.raw_json_normalized {
+        let json = serde_json::from_str::<serde_json::Value>(&capture.body)
+            .and_then(|value| serde_json::to_string_pretty(&value))
+            .unwrap_or_else(|_| capture.body.to_string());
+        (json, "Normalized")
+    } else {
+        (indent_json(&capture.body), "Verbatim")
+    };
+    let lines: Vec<Line> = json_str.lines().map(Line::from).collect();
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(Block::bordered().title(format!(
+                "{view} {} response (v toggle | e export)",
+                capture.method
+            )))
+            .wrap(Wrap { trim: false })
+            .green(),
+        chunks[1],
+    );
+}
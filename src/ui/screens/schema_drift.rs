@@ -0,0 +1,101 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::App;
+use crate::telegram::drift::KNOWN_API_VERSION;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let entries = app.telegram.get_schema_drift().entries();
+
+    if entries.is_empty() {
+        let message = vec![
+            Line::from(""),
+            Line::from(format!(
+                "No fields unknown to Bot API {KNOWN_API_VERSION} seen yet."
+            )),
+            Line::from(""),
+            Line::from("Every update is checked for fields and update types this tool doesn't"),
+            Line::from("know. New ones show up here with the first payload they were seen in."),
+            Line::from("Press 'F5' to start monitoring for updates."),
+        ];
+        frame.render_widget(
+            Paragraph::new(message).block(Block::bordered().title("Schema Drift")),
+            area,
+        );
+        return;
+    }
+
+    let [list_area, details_area] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(area);
+
+    let items: Vec<ListItem> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let selected = i == app.ui.selected_drift_index;
+            let style = if selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Yellow)
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(if selected { "→ " } else { "  " }),
+                Span::styled(entry.path(), style),
+                Span::styled(
+                    format!("  ×{}", entry.occurrences),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(Block::bordered().title(format!(
+            "Unknown to Bot API {KNOWN_API_VERSION} ({}) - ↑/↓ navigate | e export",
+            entries.len()
+        ))),
+        list_area,
+    );
+
+    let Some(entry) = entries.get(app.ui.selected_drift_index) else {
+        return;
+    };
+    let first_seen = chrono::DateTime::from_timestamp(entry.first_seen, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let mut details = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            entry.path(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(format!("First seen: {first_seen}")),
+        Line::from(format!("First update ID: {}", entry.first_update_id)),
+        Line::from(format!("Seen in: {} object(s)", entry.occurrences)),
+        Line::from(""),
+        Line::from(Span::styled(
+            "First sample:",
+            Style::default().fg(Color::Green),
+        )),
+    ];
+    let sample = serde_json::to_string_pretty(&entry.sample).unwrap_or_default();
+    details.extend(sample.lines().map(|line| Line::from(format!("  {line}"))));
+
+    frame.render_widget(
+        Paragraph::new(details)
+            .block(Block::bordered().title("Details"))
+            .wrap(Wrap { trim: false }),
+        details_area,
+    );
+}
@@ -13,7 +13,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Mode selector
-            Constraint::Length(6), // Target info
+            Constraint::Length(7), // Target info
             Constraint::Length(5), // Message input
             Constraint::Min(0),    // Info section (no separate help section)
         ])
@@ -53,15 +53,43 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let target_info = match app.ui.test_message_mode {
         TestMessageMode::SelectedChat => {
             if let Some(chat) = app.get_selected_chat() {
-                vec![
+                let mut lines = vec![
                     Line::from(""),
                     Line::from(Span::styled(
                         "Target Chat:",
                         Style::default().fg(Color::Cyan),
                     )),
                     Line::from(format!("  Name: {}", chat.chat.display_name())),
-                    Line::from(format!("  Chat ID: {}", chat.chat.id)),
-                ]
+                    Line::from(match chat.migrated_from_chat_id {
+                        Some(old_chat_id) => {
+                            format!("  Chat ID: {} (upgraded from {old_chat_id})", chat.chat.id)
+                        }
+                        None => format!("  Chat ID: {}", chat.chat.id),
+                    }),
+                ];
+                if !chat.topics.is_empty() {
+                    let topic = match app
+                        .test_message_thread_id()
+                        .and_then(|thread_id| chat.topics.iter().find(|t| t.thread_id == thread_id))
+                    {
+                        Some(topic) => format!(
+                            "# {} (thread {}){}",
+                            topic.display_name(),
+                            topic.thread_id,
+                            if topic.is_closed { " [closed]" } else { "" }
+                        ),
+                        None => "General (no topic)".to_string(),
+                    };
+                    lines.push(Line::from(vec![
+                        Span::raw("  Topic: "),
+                        Span::styled(topic, Style::default().fg(Color::Yellow)),
+                        Span::styled(
+                            format!("  (↑/↓, {} known)", chat.topics.len()),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                    ]));
+                }
+                lines
             } else {
                 vec![
                     Line::from(""),
@@ -176,6 +204,10 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                 Span::styled("  Enter", Style::default().fg(Color::Yellow)),
                 Span::raw(" - Send message"),
             ]));
+            info_lines.push(Line::from(vec![
+                Span::styled("  ↑/↓", Style::default().fg(Color::Yellow)),
+                Span::raw(" - Select forum topic"),
+            ]));
             info_lines.push(Line::from(vec![
                 Span::styled("  Tab", Style::default().fg(Color::Yellow)),
                 Span::raw(" - Switch to Manual Chat ID mode"),
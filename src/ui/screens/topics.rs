@@ -0,0 +1,182 @@
+use chrono::DateTime;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::App;
+use crate::telegram::TopicInfo;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(chat) = app.get_selected_chat() else {
+        let empty_message = Paragraph::new(vec![
+            Line::from(""),
+            Line::from("No chat selected."),
+            Line::from(""),
+            Line::from("Press Esc to go back to Discovery."),
+        ])
+        .block(Block::bordered().title("Topics"));
+        frame.render_widget(empty_message, area);
+        return;
+    };
+
+    // Prompt of a pending topic action
+    let area = if let Some(action) = app.ui.topic_action {
+        let [content_area, prompt_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(area);
+        let (content, color) = if action.needs_input() {
+            (app.ui.topic_action_input.clone(), Color::Green)
+        } else {
+            (format!("Topic: {}", selected_topic_label(app)), Color::Red)
+        };
+        let prompt = Paragraph::new(content).block(
+            Block::bordered()
+                .title(action.prompt())
+                .border_style(Style::default().fg(color)),
+        );
+        frame.render_widget(prompt, prompt_area);
+        content_area
+    } else {
+        area
+    };
+
+    let [list_area, detail_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(area);
+
+    // General topic first, then every known topic
+    let general = ListItem::new(Line::from(vec![
+        Span::raw(indicator(app.ui.selected_topic_index == 0)),
+        Span::styled("# General", topic_style(app.ui.selected_topic_index == 0)),
+    ]));
+    let items: Vec<ListItem> = std::iter::once(general)
+        .chain(chat.topics.iter().enumerate().map(|(i, topic)| {
+            let selected = app.ui.selected_topic_index == i + 1;
+            let mut spans = vec![
+                Span::raw(indicator(selected)),
+                Span::styled(topic_name(topic), topic_style(selected)),
+                Span::styled(
+                    format!("  ({} msgs)", topic.message_count),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ];
+            if topic.is_closed {
+                spans.push(Span::styled("  [closed]", Style::default().fg(Color::Red)));
+            }
+            ListItem::new(Line::from(spans))
+        }))
+        .collect();
+
+    frame.render_widget(
+        List::new(items)
+            .block(Block::bordered().title(format!("Topics - {}", chat.chat.display_name()))),
+        list_area,
+    );
+
+    // Selected topic, icon stickers and the last result
+    let mut details = vec![];
+    let selected = app
+        .selected_topic_thread_id()
+        .and_then(|thread_id| chat.topics.iter().find(|t| t.thread_id == thread_id));
+    match selected {
+        Some(topic) => {
+            details.push(section(&topic_name(topic)));
+            details.push(Line::from(format!("  Thread ID: {}", topic.thread_id)));
+            details.push(Line::from(format!("  Messages: {}", topic.message_count)));
+            if let Some(time) = DateTime::from_timestamp(topic.last_seen, 0) {
+                details.push(Line::from(format!(
+                    "  Last seen: {}",
+                    time.format("%Y-%m-%d %H:%M:%S")
+                )));
+            }
+            if let Some(color) = topic.icon_color {
+                details.push(Line::from(format!("  Icon color: #{color:06X}")));
+            }
+            if let Some(icon) = &topic.icon_custom_emoji_id {
+                details.push(Line::from(format!("  Icon emoji ID: {icon}")));
+            }
+            details.push(Line::from(format!(
+                "  State: {}",
+                if topic.is_closed { "closed" } else { "open" }
+            )));
+        }
+        None => {
+            details.push(section("General"));
+            details.push(Line::from(
+                "  Messages without a thread ID; can be renamed, closed and hidden",
+            ));
+        }
+    }
+
+    if !app.ui.topic_icon_stickers.is_empty() {
+        details.push(Line::from(""));
+        details.push(section("Available icons (custom_emoji_id):"));
+        for sticker in &app.ui.topic_icon_stickers {
+            details.push(Line::from(format!(
+                "  {} {}",
+                sticker.emoji.as_deref().unwrap_or("?"),
+                sticker.custom_emoji_id.as_deref().unwrap_or("-")
+            )));
+        }
+    }
+
+    if let Some(result) = &app.ui.topic_action_result {
+        let color = if result.starts_with('✓') {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        details.push(Line::from(""));
+        details.push(Line::from(Span::styled(
+            result.as_str(),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    details.push(Line::from(""));
+    details.push(Line::from(Span::styled(
+        "Keys: ↑↓ select  n new  e edit  s send  c close  o reopen  d delete  x/u hide/show General  i icons",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let paragraph = Paragraph::new(details)
+        .block(Block::bordered().title("Topic"))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, detail_area);
+}
+
+fn selected_topic_label(app: &App) -> String {
+    match app.selected_topic_thread_id() {
+        Some(thread_id) => format!("#{thread_id}"),
+        None => "General".to_string(),
+    }
+}
+
+fn topic_name(topic: &TopicInfo) -> String {
+    format!("# {}", topic.display_name())
+}
+
+fn topic_style(selected: bool) -> Style {
+    if selected {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Cyan)
+    }
+}
+
+fn indicator(selected: bool) -> &'static str {
+    if selected { "→ " } else { "  " }
+}
+
+fn section(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
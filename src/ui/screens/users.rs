@@ -0,0 +1,189 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::App;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let [search_area, content_area] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+    // Search box; green while typing
+    let (search_text, border_color) = if app.ui.users_search_active {
+        (format!("{}_", app.ui.users_search), Color::Green)
+    } else if app.ui.users_search.is_empty() {
+        ("press / to search".to_string(), Color::Gray)
+    } else {
+        (app.ui.users_search.clone(), Color::Gray)
+    };
+    frame.render_widget(
+        Paragraph::new(search_text).block(
+            Block::bordered()
+                .title("Search ID, name or @username (Enter keep, Esc clear)")
+                .border_style(Style::default().fg(border_color)),
+        ),
+        search_area,
+    );
+
+    let users = app.filtered_users();
+    let total = app.telegram.get_known_users().len();
+
+    if users.is_empty() {
+        let message = if total == 0 {
+            vec![
+                Line::from(""),
+                Line::from("No users seen yet."),
+                Line::from(""),
+                Line::from("Users are collected from message senders, replied-to messages,"),
+                Line::from("callback and inline queries and chat member updates."),
+                Line::from("Press 'F5' to start monitoring for updates."),
+            ]
+        } else {
+            vec![
+                Line::from(""),
+                Line::from(format!("No user matches \"{}\".", app.ui.users_search)),
+            ]
+        };
+        frame.render_widget(
+            Paragraph::new(message).block(Block::bordered().title("Users")),
+            content_area,
+        );
+        return;
+    }
+
+    let [list_area, details_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(content_area);
+
+    // User list
+    let items: Vec<ListItem> = users
+        .iter()
+        .enumerate()
+        .map(|(i, known)| {
+            let selected = i == app.ui.selected_user_index;
+            let color = if known.user.is_bot {
+                Color::Magenta
+            } else {
+                Color::Green
+            };
+            let style = if selected {
+                Style::default().fg(color).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(color)
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(if selected { "→ " } else { "  " }),
+                Span::styled(known.user.display_name(), style),
+                Span::styled(
+                    format!("  {}", known.user.id),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]))
+        })
+        .collect();
+
+    let title = if users.len() == total {
+        format!("Users ({total}) - ↑/↓ navigate | / search | e export")
+    } else {
+        format!(
+            "Users ({} of {total}) - ↑/↓ navigate | / search | e export",
+            users.len()
+        )
+    };
+    frame.render_widget(
+        List::new(items).block(Block::bordered().title(title)),
+        list_area,
+    );
+
+    // Details of the selected user
+    let Some(known) = users.get(app.ui.selected_user_index) else {
+        return;
+    };
+    let user = &known.user;
+    let flag = |value: Option<bool>| match value {
+        Some(true) => "yes",
+        Some(false) => "no",
+        None => "?",
+    };
+
+    let mut details = vec![
+        Line::from(""),
+        section("User Details:"),
+        Line::from(""),
+        Line::from(format!("User ID: {}", user.id)),
+        Line::from(format!("Name: {}", user.full_name())),
+    ];
+    if let Some(username) = &user.username {
+        details.push(Line::from(format!("Username: @{username}")));
+    }
+    details.push(Line::from(format!(
+        "Bot: {}  Premium: {}  Language: {}",
+        if user.is_bot { "yes" } else { "no" },
+        flag(user.is_premium),
+        user.language_code.as_deref().unwrap_or("?")
+    )));
+    details.push(Line::from(format!(
+        "First seen: {}",
+        format_timestamp(known.first_seen)
+    )));
+    details.push(Line::from(format!(
+        "Last seen: {}",
+        format_timestamp(known.last_seen)
+    )));
+    details.push(Line::from(format!("Updates sent: {}", known.update_count)));
+
+    if known.name_history.len() > 1 {
+        details.push(Line::from(""));
+        details.push(section("Name History:"));
+        for name in &known.name_history {
+            let username = name
+                .username
+                .as_ref()
+                .map(|u| format!(" @{u}"))
+                .unwrap_or_default();
+            details.push(Line::from(format!(
+                "  {}  {}{username}",
+                format_timestamp(name.since),
+                name.full_name
+            )));
+        }
+    }
+
+    details.push(Line::from(""));
+    details.push(section(&format!("Chats ({}):", known.chat_ids.len())));
+    let chats = app.get_discovered_chats();
+    for chat_id in &known.chat_ids {
+        let name = chats
+            .iter()
+            .find(|c| c.chat.id == *chat_id)
+            .map(|c| c.chat.display_name())
+            .unwrap_or_else(|| "not discovered".to_string());
+        details.push(Line::from(format!("  • {chat_id} - {name}")));
+    }
+
+    frame.render_widget(
+        Paragraph::new(details)
+            .block(Block::bordered().title("Details"))
+            .wrap(Wrap { trim: false }),
+        details_area,
+    );
+}
+
+fn section(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
@@ -21,6 +21,11 @@ fn test_discovery_workflow() {
                 first_name: "Alice".to_string(),
                 last_name: None,
                 username: Some("alice".to_string()),
+                can_join_groups: None,
+                can_read_all_group_messages: None,
+                supports_inline_queries: None,
+                language_code: None,
+                is_premium: None,
             }),
             chat: Chat {
                 id: 100,
@@ -123,6 +128,11 @@ fn create_test_message(chat_id: i64, chat_type: &str, message_id: i64) -> Messag
             first_name: "User".to_string(),
             last_name: None,
             username: None,
+            can_join_groups: None,
+            can_read_all_group_messages: None,
+            supports_inline_queries: None,
+            language_code: None,
+            is_premium: None,
         }),
         chat: Chat {
             id: chat_id,
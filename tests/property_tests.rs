@@ -76,6 +76,8 @@ proptest! {
             last_seen,
             message_count,
             topics: vec![],
+            bot_status_history: vec![],
+            migrated_from_chat_id: None,
         };
 
         let json = serde_json::to_string(&chat).unwrap();
@@ -108,6 +110,8 @@ proptest! {
                 last_seen: 1000,
                 message_count: count,
                 topics: vec![],
+                bot_status_history: vec![],
+                migrated_from_chat_id: None,
             }
         }).collect();
 
@@ -43,6 +43,7 @@ fn test_complete_storage_workflow() {
             topics: vec![],
         }],
         analytics: Default::default(),
+        file_base_url: None,
     };
 
     manager.save(&full_data).unwrap();